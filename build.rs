@@ -10,7 +10,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     /*
         let master_lex_rules_id_map = CTParserBuilder::new()
             .yacckind(YaccKind::Grmtools)
-            .recoverer(RecoveryKind::None)
+            .recoverer(RecoveryKind::CPCTPlus)
             .error_on_conflicts(true)
             .visibility(Visibility::Public)
             .process_file(
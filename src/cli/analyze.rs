@@ -0,0 +1,102 @@
+use crate::{
+    cli::{
+        load_input, render_errors,
+        lints::{allowed_lints, lint_args},
+        templates::{template_args, template_definations},
+        validate_input_file, SubCommand, RESULT_NAME,
+    },
+    internals::{
+        canonization::to_ast::{
+            call_graph::call_graph,
+            lints::{lint_branches, lint_program},
+            namespace::Namespace,
+            partial_ast::phase2::program::type_program,
+            resolve::resolve_program,
+        },
+        errors::term_errors::HumanReadable,
+        parser::span::Spanner,
+    },
+};
+use clap::{App, Arg, ArgMatches};
+
+#[derive(Default)]
+pub struct Analyze;
+
+impl SubCommand for Analyze {
+    fn build(&self) -> App<'static, 'static> {
+        let app = App::new(self.name())
+            .version("0.0.1")
+            .about("reports the inferred type & bounds of every output")
+            .set_term_width(80)
+            .arg(
+                Arg::with_name("input")
+                    .short("i")
+                    .long("input")
+                    .index(1)
+                    .takes_value(true)
+                    .env("FOXHOLE_INPUT_FILE")
+                    .value_name("FOXHOLE_INPUT_FILE")
+                    .next_line_help(true)
+                    .help("input source file, `-` reads from stdin")
+                    .required(true)
+                    .validator(|arg| match arg.as_str() {
+                        "-" => Ok(()),
+                        _ => validate_input_file(arg),
+                    }),
+            );
+        lint_args(template_args(app))
+    }
+
+    fn name(&self) -> &'static str {
+        "analyze"
+    }
+
+    fn exec(&self, args: &ArgMatches<'_>) -> Result<(), String> {
+        let program = load_input(args.value_of("input").unwrap())?;
+        let mut namespace = match Namespace::from_program::<HumanReadable>(&program) {
+            Ok(x) => x,
+            Err(e) => return Err(render_errors(e)),
+        };
+        let resolutions = match resolve_program::<HumanReadable>(&program, &namespace) {
+            Ok(x) => x,
+            Err(e) => return Err(render_errors(e)),
+        };
+        let calls = match call_graph::<HumanReadable>(&program, &resolutions) {
+            Ok(x) => x,
+            Err(e) => return Err(render_errors(e)),
+        };
+        let allowed = allowed_lints(args);
+        eprint!("{}", render_errors(lint_program::<HumanReadable>(&program, &resolutions, &calls, &allowed)));
+        let mut t_def = template_definations(args)?;
+        let outputs = match type_program::<HumanReadable>(&program, &mut t_def, &mut namespace) {
+            Ok(x) => {
+                eprint!("{}", render_errors(lint_branches::<HumanReadable>(&x.impossible, &allowed)));
+                x.outputs
+            }
+            Err(e) => return Err(render_errors(e)),
+        };
+
+        let names = outputs
+            .iter()
+            .map(|o| o.name.as_ref().map(|n| n.get_span()).unwrap_or(RESULT_NAME))
+            .collect::<Vec<&str>>();
+        let width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+        for (name, output) in names.iter().zip(outputs.iter()) {
+            println!("{:width$}  {}", name, output.expr.kind, width = width);
+        }
+
+        // where every template used got its value
+        let templates = t_def
+            .resolved()
+            .map(|(name, def)| (format!("${{{}}}", name), def))
+            .collect::<Vec<_>>();
+        let width = templates.iter().map(|(n, _)| n.len()).max().unwrap_or(0);
+        if !templates.is_empty() {
+            println!();
+        }
+        for (name, def) in templates {
+            println!("{:width$} = {}  ({})", name, def.value, def.source, width = width);
+        }
+        Ok(())
+    }
+}
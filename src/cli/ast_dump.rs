@@ -4,7 +4,10 @@ use crate::{
     cli::{validate_input_file, SubCommand},
     internals::{
         errors::term_errors::HumanReadable,
-        parser::generated::{parse_code, serialize_ast},
+        parser::{
+            generated::{parse_named, serialize_ast},
+            source_map::SourceName,
+        },
     },
 };
 use clap::{App, Arg, ArgMatches};
@@ -53,7 +56,7 @@ impl SubCommand for AstDump {
         let input = args.value_of("input").unwrap();
 
         // load code, parse, and serialize
-        let data = match read_to_string(&input) {
+        let data = match read_to_string(input) {
             Ok(x) => x,
             Err(e) => {
                 return Err(format!(
@@ -62,7 +65,7 @@ impl SubCommand for AstDump {
                 ))
             }
         };
-        let ast = match parse_code::<HumanReadable>(&data) {
+        let ast = match parse_named::<HumanReadable>(SourceName::Path(input.into()), &data) {
             Ok(x) => x,
             Err(e) => {
                 let mut s = String::new();
@@ -74,7 +77,7 @@ impl SubCommand for AstDump {
         };
         let json = serialize_ast(&ast)?;
         let output = args.value_of("output").unwrap();
-        match write(&output, &json) {
+        match write(output, &json) {
             Ok(()) => Ok(()),
             Err(e) => Err(format!(
                 "failed to write output to file:'{:?}' error:'{:?}'",
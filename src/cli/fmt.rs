@@ -0,0 +1,90 @@
+use std::fs::{read_to_string, write};
+
+use crate::{
+    cli::{validate_input_file, SubCommand},
+    internals::{
+        errors::term_errors::HumanReadable, formatter::format_code,
+        parser::source_map::SourceName,
+    },
+};
+use clap::{App, Arg, ArgMatches};
+
+#[derive(Default)]
+pub struct Fmt;
+
+impl SubCommand for Fmt {
+    fn build(&self) -> App<'static, 'static> {
+        App::new(self.name())
+            .version("0.0.1")
+            .about("rewrites source files in the canonical style")
+            .set_term_width(80)
+            .arg(
+                Arg::with_name("input")
+                    .index(1)
+                    .takes_value(true)
+                    .multiple(true)
+                    .value_name("FILE")
+                    .next_line_help(true)
+                    .help("source files to format")
+                    .required(true)
+                    .validator(validate_input_file),
+            )
+            .arg(
+                Arg::with_name("check")
+                    .long("check")
+                    .takes_value(false)
+                    .next_line_help(true)
+                    .help("don't write anything, fail if any file is not formatted"),
+            )
+    }
+
+    fn name(&self) -> &'static str {
+        "fmt"
+    }
+
+    fn exec(&self, args: &ArgMatches<'_>) -> Result<(), String> {
+        let check = args.is_present("check");
+        let mut unformatted = Vec::new();
+        for input in args.values_of("input").unwrap() {
+            let data = match read_to_string(input) {
+                Ok(x) => x,
+                Err(e) => {
+                    return Err(format!(
+                        "failed to read input:'{:?}' error:'{:?}'",
+                        input, e
+                    ))
+                }
+            };
+            let formatted = match format_code::<HumanReadable>(SourceName::Path(input.into()), &data) {
+                Ok(x) => x,
+                Err(e) => {
+                    let mut s = format!("cannot format {}\n", input);
+                    for err in e {
+                        s.push_str(&format!("{}\n", err));
+                    }
+                    return Err(s);
+                }
+            };
+            if formatted == data {
+                continue;
+            }
+            if check {
+                unformatted.push(input);
+            } else if let Err(e) = write(input, &formatted) {
+                return Err(format!(
+                    "failed to write output to file:'{:?}' error:'{:?}'",
+                    input, e
+                ));
+            }
+        }
+        if unformatted.is_empty() {
+            Ok(())
+        } else {
+            let mut s = String::new();
+            for input in unformatted {
+                s.push_str(&format!("not formatted: {}\n", input));
+            }
+            Err(s)
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::collections::BTreeSet;
+
+use crate::internals::canonization::to_ast::lints::Lint;
+use clap::{App, Arg, ArgMatches};
+
+/// adds the arguments which silence lints
+pub fn lint_args(app: App<'static, 'static>) -> App<'static, 'static> {
+    app.arg(
+        Arg::with_name("allow")
+            .short("A")
+            .long("allow")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .value_name("LINT")
+            .next_line_help(true)
+            .help("silences a lint: unused-variable, unused-function, unused-parameter, shadowed-binding, impossible-branch")
+            .validator(|arg| Lint::parse(&arg).map(|_| ())),
+    )
+}
+
+/// every lint the arguments silence
+pub fn allowed_lints(args: &ArgMatches<'_>) -> BTreeSet<Lint> {
+    args.values_of("allow")
+        .into_iter()
+        .flatten()
+        .filter_map(|name| Lint::parse(name).ok())
+        .collect()
+}
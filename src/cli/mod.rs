@@ -1,13 +1,41 @@
+use std::{
+    io::{stdin, Read},
+    path::Path,
+};
+
 use clap::App;
 
+use crate::internals::{
+    errors::term_errors::HumanReadable,
+    parser::loader::{load_program, load_program_from_stdin, Program},
+};
+
+mod analyze;
+use self::analyze::Analyze;
+
 mod ast_dump;
 use self::ast_dump::AstDump;
 
+mod fmt;
+use self::fmt::Fmt;
+
+mod lints;
+
+mod sweep;
+use self::sweep::Sweep;
+
+mod templates;
+
 mod traits;
 pub use self::traits::SubCommand;
 
 pub fn run() -> Result<(), String> {
-    let v: Vec<Box<dyn SubCommand>> = vec![Box::new(AstDump::default())];
+    let v: Vec<Box<dyn SubCommand>> = vec![
+        Box::new(AstDump),
+        Box::new(Analyze),
+        Box::new(Fmt),
+        Box::new(Sweep),
+    ];
     let mut app: App<'static, 'static> = App::new("foxhole");
 
     for item in v.iter() {
@@ -19,16 +47,16 @@ pub fn run() -> Result<(), String> {
         let (name, args) = args.subcommand();
         if name == item.name() {
             let args = match args {
-                Option::None => return Err(format!("no arguments passed")),
+                Option::None => return Err("no arguments passed".to_string()),
                 Option::Some(args) => args,
             };
             return item.exec(args);
         }
     }
-    Err(format!("unrecongized command"))
+    Err("unrecongized command".to_string())
 }
 
-const EOL: &'static str = {
+const EOL: &str = {
     #[cfg(target_family = "unix")]
     {
         "\n"
@@ -67,3 +95,28 @@ pub fn validate_input_file(arg: String) -> Result<(), String> {
         }
     }
 }
+
+/// name shown for the trailing expression of a program
+const RESULT_NAME: &str = "<result>";
+
+/// loads the program rooted at `input`, `-` reads it from stdin
+fn load_input(input: &str) -> Result<Program, String> {
+    let program = if input == "-" {
+        let mut data = String::new();
+        if let Err(e) = stdin().read_to_string(&mut data) {
+            return Err(format!("failed to read stdin error:'{:?}'", e));
+        }
+        load_program_from_stdin::<HumanReadable>(&data)
+    } else {
+        load_program::<HumanReadable>(Path::new(input))
+    };
+    program.map_err(render_errors)
+}
+
+fn render_errors(errors: Vec<HumanReadable>) -> String {
+    let mut s = String::new();
+    for err in errors {
+        s.push_str(&format!("{}\n", err));
+    }
+    s
+}
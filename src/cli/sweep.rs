@@ -0,0 +1,167 @@
+use crate::{
+    cli::{
+        load_input, render_errors,
+        lints::{allowed_lints, lint_args},
+        templates::{template_args, template_definations},
+        validate_input_file, SubCommand, RESULT_NAME,
+    },
+    internals::{
+        canonization::to_ast::{
+            call_graph::call_graph,
+            lints::lint_program,
+            namespace::Namespace,
+            resolve::resolve_program,
+            partial_ast::{analysis::lower_outputs, phase2::program::type_declarations},
+            sweep::{combinations, Sweep as TemplateSweep},
+            template_stuff::TemplateSource,
+        },
+        errors::term_errors::HumanReadable,
+        parser::span::Spanner,
+    },
+};
+use clap::{App, Arg, ArgMatches};
+
+/// cell shown for an output which failed to canonize
+const ERROR_CELL: &str = "error";
+
+#[derive(Default)]
+pub struct Sweep;
+
+impl SubCommand for Sweep {
+    fn build(&self) -> App<'static, 'static> {
+        let app = App::new(self.name())
+            .version("0.0.1")
+            .about("reports every output for each combination of swept templates")
+            .set_term_width(80)
+            .arg(
+                Arg::with_name("input")
+                    .index(1)
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .next_line_help(true)
+                    .help("input source file, `-` reads from stdin")
+                    .required(true)
+                    .validator(|arg| match arg.as_str() {
+                        "-" => Ok(()),
+                        _ => validate_input_file(arg),
+                    }),
+            )
+            .arg(
+                Arg::with_name("sweep")
+                    .short("s")
+                    .long("sweep")
+                    .takes_value(true)
+                    .multiple(true)
+                    .number_of_values(1)
+                    .required(true)
+                    .value_name("NAME=VALUES")
+                    .next_line_help(true)
+                    .help("values of a template, a range `3..=8` (including 8) or a list `1,3,5`")
+                    .validator(|arg| TemplateSweep::parse(&arg).map(|_| ())),
+            );
+        lint_args(template_args(app))
+    }
+
+    fn name(&self) -> &'static str {
+        "sweep"
+    }
+
+    fn exec(&self, args: &ArgMatches<'_>) -> Result<(), String> {
+        let sweeps = args
+            .values_of("sweep")
+            .unwrap()
+            .map(TemplateSweep::parse)
+            .collect::<Result<Vec<TemplateSweep>, String>>()?;
+        for (i, sweep) in sweeps.iter().enumerate() {
+            if sweeps[..i].iter().any(|s| s.name == sweep.name) {
+                return Err(format!("template `{}` is swept more than once", sweep.name));
+            }
+        }
+
+        let program = load_input(args.value_of("input").unwrap())?;
+        let namespace = match Namespace::from_program::<HumanReadable>(&program) {
+            Ok(x) => x,
+            Err(e) => return Err(render_errors(e)),
+        };
+        let resolutions = match resolve_program::<HumanReadable>(&program, &namespace) {
+            Ok(x) => x,
+            Err(e) => return Err(render_errors(e)),
+        };
+        let calls = match call_graph::<HumanReadable>(&program, &resolutions) {
+            Ok(x) => x,
+            Err(e) => return Err(render_errors(e)),
+        };
+        let allowed = allowed_lints(args);
+        eprint!("{}", render_errors(lint_program::<HumanReadable>(&program, &resolutions, &calls, &allowed)));
+        let body = &program.root().body;
+
+        let mut header = sweeps.iter().map(|s| s.name.clone()).collect::<Vec<String>>();
+        let mut rows = Vec::new();
+        let mut failures = String::new();
+        // the environment & `--defines` files are read once, each row only adds its sweeps
+        let defined = template_definations(args)?;
+        for combination in combinations(&sweeps) {
+            let mut t_def = defined.clone();
+            for (sweep, value) in sweeps.iter().zip(combination.iter()) {
+                t_def.define(sweep.name.clone(), *value, TemplateSource::Sweep);
+            }
+            // a failed declaration is reported once, and fails the outputs using it
+            let mut namespace = namespace.clone();
+            let mut errors = type_declarations::<HumanReadable>(&program, &mut t_def, &mut namespace)
+                .err()
+                .unwrap_or_default();
+            let outputs = lower_outputs::<HumanReadable>(body, &mut t_def, &mut namespace);
+            if rows.is_empty() {
+                header.extend(outputs.iter().map(|(name, _)| {
+                    name.as_ref().map(|n| n.get_span()).unwrap_or(RESULT_NAME).to_string()
+                }));
+            }
+
+            let mut row = combination.iter().map(usize::to_string).collect::<Vec<String>>();
+            for (_, output) in outputs {
+                match output {
+                    Ok(expr) => row.push(expr.kind.to_string()),
+                    Err(e) => {
+                        row.push(ERROR_CELL.to_string());
+                        errors.push(e);
+                    }
+                };
+            }
+            if !errors.is_empty() {
+                let at = sweeps
+                    .iter()
+                    .zip(combination.iter())
+                    .map(|(s, v)| format!("{}={}", s.name, v))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                failures.push_str(&format!("with {}:\n{}", at, render_errors(errors)));
+            }
+            rows.push(row);
+        }
+
+        let widths = (0..header.len())
+            .map(|col| {
+                rows.iter()
+                    .map(|row| row[col].len())
+                    .chain(Some(header[col].len()))
+                    .max()
+                    .unwrap()
+            })
+            .collect::<Vec<usize>>();
+        for row in Some(&header).into_iter().chain(rows.iter()) {
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<String>>()
+                .join("  ");
+            println!("{}", line.trim_end());
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+}
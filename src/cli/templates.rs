@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use crate::internals::canonization::to_ast::template_stuff::{
+    TemplateDefinations, TemplateSource, ENV_PREFIX,
+};
+use clap::{App, Arg, ArgMatches};
+
+/// adds the arguments which give templates their values
+pub fn template_args(app: App<'static, 'static>) -> App<'static, 'static> {
+    app.arg(
+        Arg::with_name("define")
+            .short("D")
+            .long("define")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .value_name("NAME=VALUE")
+            .next_line_help(true)
+            .help("gives a template a value, overriding every other source")
+            .validator(|arg| TemplateDefinations::parse_define(&arg).map(|_| ())),
+    )
+    .arg(
+        Arg::with_name("defines")
+            .long("defines")
+            .takes_value(true)
+            .multiple(true)
+            .number_of_values(1)
+            .value_name("FILE")
+            .next_line_help(true)
+            .help("json object of template values, `{\"NAME\": 4}`, later files win"),
+    )
+    .arg(
+        Arg::with_name("env")
+            .long("env")
+            .takes_value(false)
+            .next_line_help(true)
+            .help("reads template values from `FOXHOLE_<NAME>` environment variables"),
+    )
+}
+
+/// collects template values from every source the arguments name.
+///
+/// the environment is overridden by files, files by `--define`
+pub fn template_definations(args: &ArgMatches<'_>) -> Result<TemplateDefinations, String> {
+    let mut t_def = TemplateDefinations::default();
+    if args.is_present("env") {
+        t_def.load_env(ENV_PREFIX, std::env::vars());
+    }
+    for path in args.values_of("defines").into_iter().flatten() {
+        t_def.load_file(Path::new(path))?;
+    }
+    for define in args.values_of("define").into_iter().flatten() {
+        let (name, value) = TemplateDefinations::parse_define(define)?;
+        t_def.define(name, value, TemplateSource::Cli);
+    }
+    Ok(t_def)
+}
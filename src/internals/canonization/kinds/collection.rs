@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 #[allow(unused_imports)]
@@ -34,6 +36,27 @@ impl Collection {
             size: Integer::new(max, min, con),
         }
     }
+
+    /// The smallest `Collection` which contains every value of both
+    /// `self` & `other`.
+    ///
+    /// returns `None` if their interiors are of different types.
+    pub fn union<O>(&self, other: &O) -> Option<Self>
+    where
+        O: CollectionTrait,
+    {
+        let interior = self.interior.union(other.get_interior())?;
+        Some(Self {
+            interior,
+            size: self.size.union(other.len()),
+        })
+    }
+}
+
+impl fmt::Display for Collection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vec<{}> len {}", self.interior, self.size)
+    }
 }
 
 /*
@@ -43,28 +66,28 @@ impl Collection {
 
 impl AsRef<Collection> for Collection {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a Self {
+    fn as_ref(&self) -> &Self {
         self
     }
 }
 
 impl AsMut<Collection> for Collection {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut Self {
+    fn as_mut(&mut self) -> &mut Self {
         self
     }
 }
 
 impl AsRef<Prim> for Collection {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a Prim {
+    fn as_ref(&self) -> &Prim {
         &self.interior
     }
 }
 
 impl AsMut<Prim> for Collection {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut Prim {
+    fn as_mut(&mut self) -> &mut Prim {
         &mut self.interior
     }
 }
@@ -86,12 +109,12 @@ pub trait CollectionTrait: AsRef<Collection> + PrimativeTrait {
     }
 
     /// return the interior type representation
-    fn get_interior<'a>(&'a self) -> &'a Prim {
+    fn get_interior(&self) -> &Prim {
         <Self as AsRef<Prim>>::as_ref(self)
     }
 
     /// returns the size of the collection
-    fn len<'a>(&'a self) -> &'a Integer {
+    fn len(&self) -> &Integer {
         &<Self as AsRef<Collection>>::as_ref(self).size
     }
 }
@@ -103,7 +126,7 @@ pub trait CollectionMutTrait:
     AsMut<Collection> + CollectionTrait + PrimativeTrait + PrimativeMutTrait
 {
     /// return the mutable interior length if it exists
-    fn mut_len<'a>(&'a mut self) -> &'a mut Integer {
+    fn mut_len(&mut self) -> &mut Integer {
         &mut <Self as AsMut<Collection>>::as_mut(self).size
     }
 }
@@ -17,33 +17,33 @@ pub struct Compositional {
 
 impl AsRef<Compositional> for Compositional {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a Self {
+    fn as_ref(&self) -> &Self {
         self
     }
 }
 
 impl AsMut<Compositional> for Compositional {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut Self {
+    fn as_mut(&mut self) -> &mut Self {
         self
     }
 }
 
 /// Non-mutable things you can do with a compositional function
 pub trait CompositionalTrait: AsRef<Compositional> {
-    fn get_identity<'a>(&'a self) -> &'a Function {
+    fn get_identity(&self) -> &Function {
         &self.as_ref().identity
     }
 
-    fn get_primative<'a>(&'a self) -> &'a Function {
+    fn get_primative(&self) -> &Function {
         &self.as_ref().primative
     }
 
-    fn get_collection<'a>(&'a self) -> &'a Function {
+    fn get_collection(&self) -> &Function {
         &self.as_ref().collection
     }
 
-    fn get_return<'a>(&'a self) -> &'a TypeData {
+    fn get_return(&self) -> &TypeData {
         self.as_ref().primative.get_return()
     }
 }
@@ -56,15 +56,14 @@ impl From<&CompositionalFunction> for Compositional {
     ///       is of the correct type.
     ///       It generates internal arguments based on the assumption
     ///       the return type is correct.
-    /// NOTE:
-    ///       This doesn't like collections
     /// TODO:
     ///       Type Checking must be performed after namespace validation.
     fn from(arg: &CompositionalFunction) -> Self {
         let (ret, coll) = match TypeData::from(arg.ret.as_ref()) {
             TypeData::Prim(x) => {
-                let prim = TypeData::Prim(x.clone());
-                let coll = TypeData::from(Collection::new(x, None, None, None));
+                let prim = TypeData::Prim(x);
+                // a collection of any length may be folded
+                let coll = TypeData::from(Collection::new(x, None, 0, None));
                 (prim, coll)
             }
             anything_else => {
@@ -17,13 +17,13 @@ macro_rules! implement_index {
             impl std::ops::Index<$Kind> for $TypeName {
                 type Output = $OutputName;
                 #[inline(always)]
-                fn index<'a>(&'a self, arg: $Kind) -> &'a Self::Output {
+                fn index(&self, arg: $Kind) -> &Self::Output {
                     &self.$field_name[arg as usize]
                 }
             }
             impl std::ops::IndexMut<$Kind> for $TypeName {
                 #[inline(always)]
-                fn index_mut<'a>(&'a mut self, arg: $Kind) -> &'a mut Self::Output {
+                fn index_mut(&mut self, arg: $Kind) -> &mut Self::Output {
                     &mut self.$field_name[arg as usize]
                 }
             }
@@ -74,14 +74,14 @@ implement_index! {
 
 impl AsRef<Function> for Function {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a Function {
+    fn as_ref(&self) -> &Function {
         self
     }
 }
 
 impl AsMut<Function> for Function {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut Function {
+    fn as_mut(&mut self) -> &mut Function {
         self
     }
 }
@@ -94,7 +94,7 @@ pub trait FunctionTrait: AsRef<Function> + std::ops::Index<usize, Output = TypeD
     }
 
     /// fetches the functions return kind
-    fn get_return<'a>(&'a self) -> &'a TypeData {
+    fn get_return(&self) -> &TypeData {
         &self.as_ref().ret
     }
 }
@@ -103,7 +103,7 @@ impl FunctionTrait for Function {}
 
 pub trait FunctionMutTrait: AsMut<Function> + FunctionTrait + std::ops::IndexMut<usize> {
     /// get return argument, but mutable
-    fn get_mut_return<'a>(&'a mut self) -> &'a mut TypeData {
+    fn get_mut_return(&mut self) -> &mut TypeData {
         &mut self.as_mut().ret
     }
 }
@@ -1,4 +1,6 @@
-use crate::internals::parser::ast::op::Op;
+use std::fmt;
+
+use crate::internals::parser::ast::op::{Op,UnaryOp};
 
 use serde::{Deserialize, Serialize};
 
@@ -23,19 +25,18 @@ where
     let l_const = l.get_constant();
     let r_const = r.get_constant();
 
-    if l_const.is_some() && r_const.is_some() {
+    if let (Option::Some(l_val), Option::Some(r_val)) = (l_const, r_const) {
         // simpliest case, both values are constant
-        Ok(Boolean::new_constant((operation)(l_const.unwrap(),r_const.unwrap())))
+        Ok(Boolean::new_constant((operation)(l_val, r_val)))
     } else if ( l_const.is_some() && r.has_minimum() && r.has_maximum() ) ||
         ( r_const.is_some() && l.has_minimum() && l.has_maximum()) {
         // one value is constant
         // AND
         // the ther value is bounded
     
-        let (con_val, min, max) = if l_const.is_some() {
-            (l_const.unwrap(), r.get_minimum().unwrap(), r.get_maximum().unwrap())
-        } else {
-            (r_const.unwrap(), l.get_minimum().unwrap(), l.get_maximum().unwrap())
+        let (con_val, min, max) = match l_const {
+            Option::Some(l_val) => (l_val, r.get_minimum().unwrap(), r.get_maximum().unwrap()),
+            Option::None => (r_const.unwrap(), l.get_minimum().unwrap(), l.get_maximum().unwrap()),
         };
 
         let new_min = (operation)(con_val,min);
@@ -43,7 +44,7 @@ where
 
         if new_min == new_max {
             Ok(Boolean::new_constant(new_min))
-        } else if new_min < new_max {
+        } else if !new_min & new_max {
             Ok(Boolean::new(new_max, new_min, None))
         } else {
             Ok(Boolean::new(new_min, new_max, None))
@@ -58,6 +59,27 @@ where
 }
 
 
+/// Propigates type information of a unary (op x) expression
+/// for boolean operations
+pub fn unary_operations<B>(arg: &B, op: UnaryOp) -> Result<Boolean,()>
+where
+    B: BooleanTrait,
+{
+    match op {
+        UnaryOp::Not => {
+            if let Option::Some(x) = arg.get_constant() {
+                return Ok(Boolean::new_constant(!x));
+            }
+            // `!` maps `false..=true` back onto `false..=true`
+            Ok(Boolean::new(
+                arg.get_minimum().map(|x| !x),
+                arg.get_maximum().map(|x| !x),
+                None,
+            ))
+        }
+        UnaryOp::Neg => Err(()),
+    }
+}
 
 // returns a function to compute the operation
 //
@@ -96,10 +118,10 @@ fn op_gen(op: Op) -> Result<&'static (dyn Fn(bool,bool)->bool+'static),()> {
 
 
 impl AsRef<Boolean> for Boolean { 
-    fn as_ref<'a>(&'a self) -> &'a Self { self }
+    fn as_ref(&self) -> &Self { self }
 } 
 impl AsMut<Boolean> for Boolean {
-    fn as_mut<'a>(&'a mut self) -> &'a mut Self { self } 
+    fn as_mut(&mut self) -> &mut Self { self } 
 }
 
 impl Boolean {
@@ -111,6 +133,31 @@ impl Boolean {
         Boolean::new(true, false, None)
     }
 
+    /// The smallest `Boolean` which contains every value of both `self` & `other`.
+    pub fn union<O>(&self, other: &O) -> Self
+    where
+        O: BooleanTrait,
+    {
+        match (self.get_constant(), other.get_constant()) {
+            (Option::Some(l), Option::Some(r)) if l == r => Boolean::new_constant(l),
+            _ => Boolean::all_vals(),
+        }
+    }
+
+    /// The largest `Boolean` contained by both `self` & `other`.
+    ///
+    /// returns `None` if they share no value.
+    pub fn intersect<O>(&self, other: &O) -> Option<Self>
+    where
+        O: BooleanTrait,
+    {
+        match (self.get_constant(), other.get_constant()) {
+            (Option::Some(l), Option::Some(r)) if l != r => None,
+            (Option::Some(x), _) | (_, Option::Some(x)) => Some(Boolean::new_constant(x)),
+            _ => Some(Boolean::all_vals()),
+        }
+    }
+
     pub fn new<Max, Min, Const>(max: Max, min: Min, con: Const) -> Self
     where
         Max: Into<Option<bool>>,
@@ -126,24 +173,33 @@ impl Boolean {
     }
 }
 
+impl fmt::Display for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get_constant() {
+            Option::Some(con) => write!(f, "{}", con),
+            Option::None => write!(f, "false..=true"),
+        }
+    }
+}
+
 pub trait BooleanTrait: AsRef<Boolean> {
 
     fn get_maximum(&self) -> Option<bool> {
-        self.as_ref().maximum.clone()
+        self.as_ref().maximum
     }
     fn has_maximum(&self) -> bool {
         self.get_maximum().is_some()
     }
 
     fn get_minimum(&self) -> Option<bool> {
-        self.as_ref().minimum.clone()
+        self.as_ref().minimum
     }
     fn has_minimum(&self) -> bool {
         self.get_maximum().is_some()
     }
 
     fn get_constant(&self) -> Option<bool> {
-        self.as_ref().constant.clone()
+        self.as_ref().constant
     }
     fn has_constant(&self) -> bool {
         self.get_maximum().is_some()
@@ -180,7 +236,7 @@ pub trait BooleanMutTrait: AsMut<Boolean> + BooleanTrait {
                 // we have a new minimum value, we need to
                 // see how it is related to maximum
                 match self.get_maximum() {
-                    Option::Some(max) if max < new_min => {
+                    Option::Some(max) if !max & new_min => {
                         // maximum is now invalidated
                         // meaning the maximum range is now "endless"
                         self.as_mut().maximum = None;
@@ -191,7 +247,7 @@ pub trait BooleanMutTrait: AsMut<Boolean> + BooleanTrait {
                         // meaning we infer the value is constant
                         self.as_mut().constant = Some(new_min);
                     }
-                    Option::Some(max) if new_min < max => {
+                    Option::Some(max) if !new_min & max => {
                         // the ordering is correct, but they aren't equal
                         self.as_mut().constant = None;
                     }
@@ -217,7 +273,7 @@ pub trait BooleanMutTrait: AsMut<Boolean> + BooleanTrait {
                 // we have a new maximum value, we need to
                 // see how it is related to minimum
                 match self.get_minimum() {
-                    Option::Some(min) if min > new_max => {
+                    Option::Some(min) if min & !new_max => {
                         // minimum is now invalidated
                         // meaning the minimum range is now "endless"
                         self.as_mut().minimum = None;
@@ -228,7 +284,7 @@ pub trait BooleanMutTrait: AsMut<Boolean> + BooleanTrait {
                         // meaning we infer the value is constant
                         self.as_mut().constant = Some(new_max);
                     }
-                    Option::Some(min) if min < new_max => {
+                    Option::Some(min) if !min & new_max => {
                         // the othering is correct, but they aren't
                         // equal so the constant is invalidated
                         self.as_mut().constant = None;
@@ -282,3 +338,20 @@ fn test_boolean_propigation() {
         }
     }
 }
+
+#[test]
+fn test_boolean_not_propigation() {
+    let tests: Vec<(Boolean,UnaryOp,Result<Boolean,()>)> = vec![
+        (Boolean::new_constant(true), UnaryOp::Not, Ok(Boolean::new_constant(false))),
+        (Boolean::new_constant(false), UnaryOp::Not, Ok(Boolean::new_constant(true))),
+        (Boolean::all_vals(), UnaryOp::Not, Ok(Boolean::all_vals())),
+        (Boolean::new_constant(false), UnaryOp::Neg, Err(())),
+    ];
+
+    for (arg, op, result) in tests {
+        let output = unary_operations(&arg, op);
+        if output != result {
+            panic!("found:{:?} expected:{:?} for ( {:?} {:?} )", output, result, op, arg);
+        }
+    }
+}
@@ -1,7 +1,7 @@
-use std::ops::RangeInclusive;
+use std::{fmt, ops::RangeInclusive};
 use crate::internals::{
-    parser::{ ast::op::Op, span::Spanner},
-    canonization::kinds::primative::boolean::{Boolean,BooleanTrait},
+    parser::{ ast::op::{Op,UnaryOp}, span::Spanner},
+    canonization::kinds::primative::{boolean::{Boolean,BooleanTrait}, OpError},
 };
 
 use serde::{Deserialize, Serialize};
@@ -66,44 +66,328 @@ where
     }
 }
 
-pub fn trinary_iii_op<L,R>(l: &L, op: Op, r: &R) -> Result<Integer,()>
+/// Propigates type information of a trinary (x op y) expression
+/// for integer operations.
+///
+/// Constants are folded with checked arithmetic, a result which doesn't
+/// fit within an `i64` is an `OpError::Overflow`. A range which may
+/// overflow is unbounded on the side which overflows.
+pub fn trinary_iii_op<L,R>(l: &L, op: Op, r: &R) -> Result<Integer,OpError>
 where
     L: IntegerTrait,
     R: IntegerTrait,
 {
 
+    let kind = op;
     let op = int_op_gen(op)?;
 
+    // `x / 0`, `x % 0` and `0 ** -n` have no value
+    match (kind, l.get_constant(), r.get_constant()) {
+        (Op::DIV, _, Option::Some(0)) | (Op::MOD, _, Option::Some(0)) => return Err(OpError::Invalid),
+        (Op::POW, Option::Some(0), Option::Some(exp)) if exp < 0 => return Err(OpError::Invalid),
+        _ => { }
+    };
+
     let con = l.get_constant().into_iter().zip(r.get_constant()).map(|(l,r)| op(l,r)).next();
-    if let Option::Some(x) = con.clone() {
-        return Ok(Integer::new_constant(x));
+    match con {
+        Option::Some(Option::Some(x)) => return Ok(Integer::new_constant(x)),
+        Option::Some(Option::None) => return Err(OpError::Overflow),
+        Option::None => { }
+    };
+
+    match kind {
+        Op::ADD | Op::SUB | Op::MUL | Op::DIV => interval_bounds(l, kind, r),
+        Op::MOD => Ok(rem_bounds(l, r)),
+        _ => pow_bounds(l, r),
+    }
+}
+
+/// An end of a range, widened so no operation upon two `i64` overflows.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum End {
+    NegInf,
+    Finite(i128),
+    PosInf,
+}
+
+impl End {
+    fn lower<I: IntegerTrait>(arg: &I) -> Self {
+        arg.get_minimum().map_or(End::NegInf, |x| End::Finite(x as i128))
+    }
+
+    fn upper<I: IntegerTrait>(arg: &I) -> Self {
+        arg.get_maximum().map_or(End::PosInf, |x| End::Finite(x as i128))
+    }
+
+    /// `None` if this end lies outside of `i64`, so that side is unbounded
+    fn get(self) -> Option<i64> {
+        match self {
+            End::Finite(x) if i64::MIN as i128 <= x && x <= i64::MAX as i128 => Some(x as i64),
+            _ => None,
+        }
+    }
+
+    fn signum(self) -> i128 {
+        match self {
+            End::NegInf => -1,
+            End::Finite(x) => x.signum(),
+            End::PosInf => 1,
+        }
+    }
+
+    fn infinite(sign: i128) -> Self {
+        if sign < 0 { End::NegInf } else { End::PosInf }
+    }
+
+    fn neg(self) -> Self {
+        match self {
+            End::NegInf => End::PosInf,
+            End::Finite(x) => End::Finite(-x),
+            End::PosInf => End::NegInf,
+        }
+    }
+
+    // never called with opposing infinities
+    fn add(self, other: Self) -> Self {
+        match (self, other) {
+            (End::Finite(l), End::Finite(r)) => End::Finite(l + r),
+            (End::Finite(_), inf) | (inf, _) => inf,
+        }
+    }
+
+    // zero times anything is zero, the range `[0,0] * [1,..]` is only `0`
+    fn mul(self, other: Self) -> Self {
+        match (self, other) {
+            (End::Finite(l), End::Finite(r)) => End::Finite(l * r),
+            (l, r) if l.signum() == 0 || r.signum() == 0 => End::Finite(0),
+            (l, r) => End::infinite(l.signum() * r.signum()),
+        }
+    }
+
+    // `other` is never zero. A finite number divided by an infinite one
+    // truncates to zero, as does an infinite one in the limit (dividing by
+    // an even larger number), the other corners supply the infinite bound.
+    fn div(self, other: Self) -> Self {
+        match (self, other) {
+            (End::Finite(l), End::Finite(r)) => End::Finite(l / r),
+            (End::Finite(_), _) => End::Finite(0),
+            (l, End::Finite(r)) => End::infinite(l.signum() * r.signum()),
+            (_, _) => End::Finite(0),
+        }
+    }
+}
+
+/// bounds of `l + r`, `l - r`, `l * r` & `l / r`.
+///
+/// Each operation is monotonic in either argument while the other is
+/// fixed (for division, while the divisor keeps its sign), so the
+/// extremes are found within the corners of the two ranges. Division
+/// is split into its negative & positive divisors, a divisor of zero
+/// has no value so it contributes nothing.
+fn interval_bounds<L,R>(l: &L, op: Op, r: &R) -> Result<Integer,OpError>
+where
+    L: IntegerTrait,
+    R: IntegerTrait,
+{
+    let (l_min, l_max) = (End::lower(l), End::upper(l));
+    let (r_min, r_max) = (End::lower(r), End::upper(r));
+    let (min, max) = match op {
+        Op::ADD => (l_min.add(r_min), l_max.add(r_max)),
+        Op::SUB => (l_min.add(r_max.neg()), l_max.add(r_min.neg())),
+        Op::MUL => corners(&[l_min, l_max], &[r_min, r_max], End::mul),
+        _ => {
+            let mut divisors = Vec::with_capacity(2);
+            if r_min < End::Finite(0) {
+                divisors.push((r_min, r_max.min(End::Finite(-1))));
+            }
+            if r_max > End::Finite(0) {
+                divisors.push((r_min.max(End::Finite(1)), r_max));
+            }
+            if divisors.is_empty() {
+                return Err(OpError::Invalid);
+            }
+            divisors.iter()
+                .map(|&(min, max)| corners(&[l_min, l_max], &[min, max], End::div))
+                .fold((End::PosInf, End::NegInf), |(lo, hi), (min, max)| (lo.min(min), hi.max(max)))
+        }
+    };
+    if max < End::Finite(i64::MIN as i128) || min > End::Finite(i64::MAX as i128) {
+        return Err(OpError::Overflow);
+    }
+    match (min.get(), max.get()) {
+        (Option::Some(min), Option::Some(max)) if min == max => Ok(Integer::new_constant(min)),
+        (min, max) => Ok(Integer::new(max, min, None)),
+    }
+}
+
+/// `(min,max)` of `op` applied to every pair of ends
+fn corners(l: &[End], r: &[End], op: fn(End,End) -> End) -> (End, End) {
+    let values = l.iter().flat_map(|&l| r.iter().map(move |&r| op(l, r)));
+    let min = values.clone().min().unwrap();
+    let max = values.max().unwrap();
+    (min, max)
+}
+
+/// bounds of `l % r`.
+///
+/// The remainder has the sign of `l`, is never further from zero
+/// than `l`, and is always closer to zero than the largest `r`.
+fn rem_bounds<L,R>(l: &L, r: &R) -> Integer
+where
+    L: IntegerTrait,
+    R: IntegerTrait,
+{
+    // `None` here means the divisor is unbounded
+    let largest_remainder = r.get_bounds()
+        .map(|(max,min)| max.checked_abs().unwrap_or(i64::MAX).max(min.checked_abs().unwrap_or(i64::MAX)) - 1);
+
+    // when every divisor is larger than every dividend the remainder is the dividend
+    let smallest_divisor = match r.get_bounds() {
+        Option::Some((_,min)) if min > 0 => Some(min),
+        Option::Some((max,_)) if max < 0 => Some(max.checked_abs().unwrap_or(i64::MAX)),
+        _ => None,
+    };
+    if let (Option::Some(div), Option::Some((max,min))) = (smallest_divisor, l.get_bounds()) {
+        if min > -div && max < div {
+            return Integer::new(max, min, None);
+        }
+    }
+
+    let max = match (l.get_maximum(), largest_remainder) {
+        (Option::Some(max), _) if max <= 0 => Some(0),
+        (Option::Some(max), Option::Some(rem)) => Some(max.min(rem)),
+        (Option::Some(max), Option::None) => Some(max),
+        (Option::None, rem) => rem,
+    };
+    let min = match (l.get_minimum(), largest_remainder) {
+        (Option::Some(min), _) if min >= 0 => Some(0),
+        (Option::Some(min), Option::Some(rem)) => Some(min.max(-rem)),
+        (Option::Some(min), Option::None) => Some(min),
+        (Option::None, rem) => rem.map(|rem| -rem),
+    };
+    Integer::new(max, min, None)
+}
+
+/// bounds of `l ** r`.
+///
+/// `b ** e` is monotonic in `b` on either side of zero, and its
+/// magnitude is monotonic in `e` with the sign alternating on
+/// parity. So the extremes are found within the ends of each range,
+/// zero, one, negative one, and the neighbours of the exponent's ends.
+/// When every one of those overflows, so does every other value.
+fn pow_bounds<L,R>(l: &L, r: &R) -> Result<Integer,OpError>
+where
+    L: IntegerTrait,
+    R: IntegerTrait,
+{
+    let ((l_max,l_min),(r_max,r_min)) = match (l.get_bounds(), r.get_bounds()) {
+        (Option::Some(l), Option::Some(r)) => (l,r),
+        _ => return Ok(Integer::new(None,None,None)),
+    };
+    let bases = [l_min, l_max, -1, 0, 1];
+    let exps = [r_min, r_min.saturating_add(1), r_max.saturating_sub(1), r_max, 0];
+
+    let mut max: Option<Option<i64>> = None;
+    let mut min: Option<Option<i64>> = None;
+    let mut fits = false;
+    for base in bases.iter().filter(|b| l_min <= **b && **b <= l_max) {
+        for exp in exps.iter().filter(|e| r_min <= **e && **e <= r_max) {
+            if *base == 0 && *exp < 0 {
+                // no value exists for this case
+                continue;
+            }
+            match int_pow(*base, *exp) {
+                Option::Some(x) => {
+                    fits = true;
+                    max = Some(max.map_or(Some(x), |m| m.map(|m| m.max(x))));
+                    min = Some(min.map_or(Some(x), |m| m.map(|m| m.min(x))));
+                }
+                // overflowed, so that side of the range is unbounded
+                Option::None if *base < 0 && exp % 2 != 0 => min = Some(None),
+                Option::None => max = Some(None),
+            };
+        }
+    }
+    match (max, min) {
+        (Option::Some(_), _) | (_, Option::Some(_)) if !fits => Err(OpError::Overflow),
+        (Option::Some(max), Option::Some(min)) => Ok(Integer::new(max, min, None)),
+        (Option::Some(max), Option::None) => Ok(Integer::new(max, None, None)),
+        (Option::None, Option::Some(min)) => Ok(Integer::new(None, min, None)),
+        // every combination was `0 ** -n`
+        (Option::None, Option::None) => Err(OpError::Invalid),
     }
+}
 
-    let max = l.get_maximum().into_iter().zip(r.get_maximum()).map(|(l,r)| op(l,r)).next();
-    let min = l.get_minimum().into_iter().zip(r.get_minimum()).map(|(l,r)| op(l,r)).next();
-    // check if order changed
-    let (max,min) = if max > min { (max,min) } else { (min,max) };
-    Ok(Integer::new(max, min, None))
+/// `base ** exp` for integers.
+///
+/// negative exponents truncate towards zero like division does,
+/// returns `None` on overflow or `0 ** -n`.
+fn int_pow(base: i64, exp: i64) -> Option<i64> {
+    match (base, exp) {
+        (0, exp) if exp < 0 => None,
+        (1, _) => Some(1),
+        (-1, exp) if exp % 2 == 0 => Some(1),
+        (-1, _) => Some(-1),
+        (_, exp) if exp < 0 => Some(0),
+        (0, 0) => Some(1),
+        (0, _) => Some(0),
+        (_, exp) if exp > u32::MAX as i64 => None,
+        (base, exp) => base.checked_pow(exp as u32),
+    }
 }
 
-fn int_op_gen(op: Op) -> Result<&'static (dyn Fn(i64,i64)->i64+'static),()> {
+/// Propigates type information of a unary (op x) expression
+/// for integer operations.
+///
+/// Negation flips the range, so the old maximum becomes the
+/// new minimum (and vice versa).
+pub fn unary_i_op<I>(arg: &I, op: UnaryOp) -> Result<Integer,OpError>
+where
+    I: IntegerTrait,
+{
+    match op {
+        UnaryOp::Neg => {
+            // `-i64::MIN` cannot be represented, so that bound is lost
+            let neg = |x: Option<i64>| x.and_then(i64::checked_neg);
+            match (arg.get_constant(), neg(arg.get_constant())) {
+                (_, Option::Some(x)) => return Ok(Integer::new_constant(x)),
+                (Option::Some(_), Option::None) => return Err(OpError::Overflow),
+                _ => { }
+            };
+            Ok(Integer::new(neg(arg.get_minimum()), neg(arg.get_maximum()), None))
+        }
+        UnaryOp::Not => Err(OpError::Invalid),
+    }
+}
+
+/// the operation upon two constants, `None` if the result overflows
+fn int_op_gen(op: Op) -> Result<&'static (dyn Fn(i64,i64)->Option<i64>+'static),()> {
     match op {
         Op::ADD => {
-            fn add(l: i64, r: i64) -> i64 { l + r }
+            fn add(l: i64, r: i64) -> Option<i64> { l.checked_add(r) }
             Ok(&add)
         }
         Op::SUB => {
-            fn sub(l: i64, r: i64) -> i64 { l - r }
+            fn sub(l: i64, r: i64) -> Option<i64> { l.checked_sub(r) }
             Ok(&sub)
         }
         Op::MUL => {
-            fn mul(l: i64, r: i64) -> i64 { l * r }
+            fn mul(l: i64, r: i64) -> Option<i64> { l.checked_mul(r) }
             Ok(&mul)
         }
         Op::DIV => {
-            fn div(l: i64, r: i64) -> i64 { l / r }
+            fn div(l: i64, r: i64) -> Option<i64> { l.checked_div(r) }
             Ok(&div)
         }
+        Op::MOD => {
+            // `i64::MIN % -1` is `0`, it just can't be computed with `%`
+            fn rem(l: i64, r: i64) -> Option<i64> { Some(l.wrapping_rem(r)) }
+            Ok(&rem)
+        }
+        Op::POW => {
+            fn pow(l: i64, r: i64) -> Option<i64> { int_pow(l, r) }
+            Ok(&pow)
+        }
         _ => {
             Err(())
         }
@@ -144,14 +428,14 @@ fn bool_op_gen(op: Op) -> Result<&'static (dyn Fn(i64,i64)->bool+'static),()> {
 
 impl AsRef<Integer> for Integer {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a Integer {
+    fn as_ref(&self) -> &Integer {
         self
     }
 }
 
 impl AsMut<Integer> for Integer {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut Integer {
+    fn as_mut(&mut self) -> &mut Integer {
         self
     }
 }
@@ -167,7 +451,7 @@ impl Integer {
     where
         S: Spanner,
     {
-        i64::from_str_radix(arg.get_span(), 10)
+        arg.get_span().parse::<i64>()
             .ok()
             .map(Self::new_constant)
     }
@@ -182,6 +466,38 @@ impl Integer {
         }
     }
 
+    /// The smallest `Integer` which contains every value of both `self` & `other`.
+    pub fn union<O>(&self, other: &O) -> Self
+    where
+        O: IntegerTrait,
+    {
+        let max = self.get_maximum().into_iter().zip(other.get_maximum()).map(|(l,r)| l.max(r)).next();
+        let min = self.get_minimum().into_iter().zip(other.get_minimum()).map(|(l,r)| l.min(r)).next();
+        Self::new(max, min, None)
+    }
+
+    /// The largest `Integer` contained by both `self` & `other`.
+    ///
+    /// returns `None` if they share no value.
+    pub fn intersect<O>(&self, other: &O) -> Option<Self>
+    where
+        O: IntegerTrait,
+    {
+        let max = match (self.get_maximum(), other.get_maximum()) {
+            (Option::Some(l), Option::Some(r)) => Some(l.min(r)),
+            (l, r) => l.or(r),
+        };
+        let min = match (self.get_minimum(), other.get_minimum()) {
+            (Option::Some(l), Option::Some(r)) => Some(l.max(r)),
+            (l, r) => l.or(r),
+        };
+        match (max, min) {
+            (Option::Some(max), Option::Some(min)) if max < min => None,
+            (Option::Some(max), Option::Some(min)) if max == min => Some(Self::new_constant(max)),
+            (max, min) => Some(Self::new(max, min, None)),
+        }
+    }
+
     /// Create new instant of `Integer` with -possibly- known bounds.
     pub fn new<Max, Min, Const>(max: Max, min: Min, con: Const) -> Self
     where
@@ -201,11 +517,24 @@ impl Integer {
     }
 }
 
+impl fmt::Display for Integer {
+    /// formats the integer the way a range would be written in rust
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.get_constant(), self.get_minimum(), self.get_maximum()) {
+            (Option::Some(con), _, _) => write!(f, "{}", con),
+            (Option::None, Option::Some(min), Option::Some(max)) => write!(f, "{}..={}", min, max),
+            (Option::None, Option::Some(min), Option::None) => write!(f, "{}..", min),
+            (Option::None, Option::None, Option::Some(max)) => write!(f, "..={}", max),
+            (Option::None, Option::None, Option::None) => write!(f, ".."),
+        }
+    }
+}
+
 /// IntegerTrait defines operations upon the Integer TypeClass.
 pub trait IntegerTrait: AsRef<Integer> {
     /// returns the maximum value this integer may contain.
     fn get_maximum(&self) -> Option<i64> {
-        self.as_ref().maximum.clone()
+        self.as_ref().maximum
     }
 
     /// returns if a known maximum value exists
@@ -215,7 +544,7 @@ pub trait IntegerTrait: AsRef<Integer> {
 
     /// returns the minimum value this integer may contain
     fn get_minimum(&self) -> Option<i64> {
-        self.as_ref().minimum.clone()
+        self.as_ref().minimum
     }
 
     /// returns if a minimum value is known
@@ -225,7 +554,7 @@ pub trait IntegerTrait: AsRef<Integer> {
 
     /// returns the constant value, if it exists
     fn get_constant(&self) -> Option<i64> {
-        self.as_ref().constant.clone()
+        self.as_ref().constant
     }
 
     fn is_constant(&self) -> bool {
@@ -261,9 +590,9 @@ pub trait IntegerTrait: AsRef<Integer> {
 
     /// returns the total range
     fn get_range(&self) -> RangeInclusive<i64> {
-        let max = self.get_maximum().unwrap_or_else(|| i64::MAX);
-        let min = self.get_minimum().unwrap_or_else(|| i64::MIN);
-        debug_assert!(max > min);
+        let max = self.get_maximum().unwrap_or(i64::MAX);
+        let min = self.get_minimum().unwrap_or(i64::MIN);
+        debug_assert!(max >= min);
         RangeInclusive::new(min, max)
     }
 }
@@ -461,3 +790,193 @@ fn non_trivial_integer_properites() {
     assert!(int.has_minimum());
     assert_eq!(Option::Some(8i64), int.get_minimum());
 }
+
+#[test]
+fn test_integer_negation() {
+    let tests: Vec<(Integer,Result<Integer,OpError>)> = vec![
+        (Integer::new_constant(5), Ok(Integer::new_constant(-5))),
+        (Integer::new(6, 1, None), Ok(Integer::new(-1, -6, None))),
+        (Integer::new(None, 1, None), Ok(Integer::new(-1, None, None))),
+        (Integer::new(4, -2, None), Ok(Integer::new(2, -4, None))),
+        (Integer::new(0, i64::MIN, None), Ok(Integer::new(None, 0, None))),
+    ];
+
+    for (arg, result) in tests {
+        let output = unary_i_op(&arg, UnaryOp::Neg);
+        if output != result {
+            panic!("found:{:?} expected:{:?} for ( -{:?} )", output, result, arg);
+        }
+    }
+    assert_eq!(unary_i_op(&Integer::new_constant(1), UnaryOp::Not), Err(OpError::Invalid));
+    assert_eq!(unary_i_op(&Integer::new_constant(i64::MIN), UnaryOp::Neg), Err(OpError::Overflow));
+}
+
+#[test]
+fn test_mod_pow_bounds() {
+    let tests: Vec<(Integer,Op,Integer,Result<Integer,OpError>)> = vec![
+        (Integer::new(6, 1, None), Op::MOD, Integer::new_constant(4), Ok(Integer::new(3, 0, None))),
+        (Integer::new(3, 1, None), Op::MOD, Integer::new_constant(4), Ok(Integer::new(3, 1, None))),
+        (Integer::new(6, -6, None), Op::MOD, Integer::new(3, 2, None), Ok(Integer::new(2, -2, None))),
+        (Integer::new_constant(7), Op::MOD, Integer::new_constant(0), Err(OpError::Invalid)),
+        (Integer::new(6, 1, None), Op::POW, Integer::new_constant(2), Ok(Integer::new(36, 1, None))),
+        (Integer::new(2, -3, None), Op::POW, Integer::new(3, 2, None), Ok(Integer::new(9, -27, None))),
+        (Integer::new_constant(2), Op::POW, Integer::new_constant(-1), Ok(Integer::new_constant(0))),
+        (Integer::new_constant(0), Op::POW, Integer::new_constant(-1), Err(OpError::Invalid)),
+        (Integer::new(3, 2, None), Op::POW, Integer::new(100, 0, None), Ok(Integer::new(None, 1, None))),
+    ];
+    for (l, op, r, result) in tests {
+        let output = trinary_iii_op(&l, op, &r);
+        if output != result {
+            panic!("found:{:?} expected:{:?} for ( {:?} {:?} {:?} )", output, result, l, op, r);
+        }
+    }
+
+    // every value which can be produced must lie within the bounds
+    for op in [Op::MOD, Op::POW].iter() {
+        for l_min in -5i64..=5 {
+            for l_max in l_min..=5 {
+                for r_min in -4i64..=4 {
+                    for r_max in r_min..=4 {
+                        let l = Integer::new(l_max, l_min, None);
+                        let r = Integer::new(r_max, r_min, None);
+                        let bounds = match trinary_iii_op(&l, *op, &r) {
+                            Ok(bounds) => bounds.get_range(),
+                            Err(_) => continue,
+                        };
+                        for x in l_min..=l_max {
+                            for y in r_min..=r_max {
+                                let value = match op {
+                                    Op::MOD if y == 0 => continue,
+                                    Op::MOD => x % y,
+                                    _ => match int_pow(x, y) {
+                                        Option::Some(v) => v,
+                                        Option::None => continue,
+                                    },
+                                };
+                                assert!(bounds.contains(&value),
+                                    "{} {:?} {} = {} not within {:?}", x, op, y, value, bounds);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn interval_arithmetic_matches_enumeration() {
+    // bounds must be exactly the smallest & largest value produced
+    for op in [Op::ADD, Op::SUB, Op::MUL, Op::DIV].iter() {
+        for l_min in -5i64..=5 {
+            for l_max in l_min..=5 {
+                for r_min in -4i64..=4 {
+                    for r_max in r_min..=4 {
+                        let l = Integer::new(l_max, l_min, None);
+                        let r = Integer::new(r_max, r_min, None);
+                        let values = (l_min..=l_max)
+                            .flat_map(|x| (r_min..=r_max).map(move |y| (x, y)))
+                            .filter_map(|(x, y)| match op {
+                                Op::ADD => Some(x + y),
+                                Op::SUB => Some(x - y),
+                                Op::MUL => Some(x * y),
+                                _ => x.checked_div(y),
+                            })
+                            .collect::<Vec<i64>>();
+                        let expected = match (values.iter().max(), values.iter().min()) {
+                            (Option::Some(max), Option::Some(min)) if max == min => Ok(Integer::new_constant(*max)),
+                            (Option::Some(max), Option::Some(min)) => Ok(Integer::new(*max, *min, None)),
+                            _ => Err(OpError::Invalid),
+                        };
+                        let output = trinary_iii_op(&l, *op, &r);
+                        assert_eq!(output, expected, "{:?} {:?} {:?}", l, op, r);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn interval_arithmetic_with_unbounded_ranges() {
+    let tests: Vec<(Integer,Op,Integer,Result<Integer,OpError>)> = vec![
+        (Integer::new(6, 1, None), Op::SUB, Integer::new(6, 1, None), Ok(Integer::new(5, -5, None))),
+        (Integer::new(None, 1, None), Op::SUB, Integer::new(6, 1, None), Ok(Integer::new(None, -5, None))),
+        (Integer::new(None, 1, None), Op::ADD, Integer::new(None, None, None), Ok(Integer::new(None, None, None))),
+        (Integer::new(None, 1, None), Op::MUL, Integer::new(-1, -2, None), Ok(Integer::new(-1, None, None))),
+        (Integer::new(None, 0, None), Op::MUL, Integer::new_constant(0), Ok(Integer::new_constant(0))),
+        (Integer::new(-1, None, None), Op::MUL, Integer::new(3, -3, None), Ok(Integer::new(None, None, None))),
+        (Integer::new(None, 1, None), Op::DIV, Integer::new(None, 2, None), Ok(Integer::new(None, 0, None))),
+        (Integer::new(10, -10, None), Op::DIV, Integer::new(None, None, None), Ok(Integer::new(10, -10, None))),
+        (Integer::new(10, 4, None), Op::DIV, Integer::new(None, 3, None), Ok(Integer::new(3, 0, None))),
+        (Integer::new(6, 1, None), Op::DIV, Integer::new(2, -2, None), Ok(Integer::new(6, -6, None))),
+        (Integer::new(6, 1, None), Op::DIV, Integer::new_constant(0), Err(OpError::Invalid)),
+        // bounds which don't fit within an `i64` are unbounded
+        (Integer::new(i64::MAX, 0, None), Op::ADD, Integer::new(1, 0, None), Ok(Integer::new(None, 0, None))),
+        (Integer::new(-1, i64::MIN, None), Op::DIV, Integer::new_constant(-1), Ok(Integer::new(None, 1, None))),
+    ];
+    for (l, op, r, result) in tests {
+        let output = trinary_iii_op(&l, op, &r);
+        if output != result {
+            panic!("found:{:?} expected:{:?} for ( {:?} {:?} {:?} )", output, result, l, op, r);
+        }
+    }
+
+    // values near the ends of a one sided range are within the bounds
+    let window = |int: &Integer| {
+        let min = int.get_minimum().unwrap_or(-40);
+        let max = int.get_maximum().unwrap_or(40);
+        min..=max
+    };
+    let ranges = [
+        Integer::new(None, -3, None), Integer::new(None, 2, None), Integer::new(4, None, None),
+        Integer::new(-2, None, None), Integer::new(None, None, None), Integer::new(3, -3, None),
+    ];
+    for op in [Op::ADD, Op::SUB, Op::MUL, Op::DIV].iter() {
+        for l in ranges.iter() {
+            for r in ranges.iter() {
+                let bounds = trinary_iii_op(l, *op, r).unwrap().get_range();
+                for x in window(l) {
+                    for y in window(r) {
+                        let value = match op {
+                            Op::ADD => x + y,
+                            Op::SUB => x - y,
+                            Op::MUL => x * y,
+                            _ if y == 0 => continue,
+                            _ => x / y,
+                        };
+                        assert!(bounds.contains(&value),
+                            "{} {:?} {} = {} not within {:?}", x, op, y, value, bounds);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn overflow_is_detected() {
+    let big = Integer::new_constant(i64::MAX);
+    let tests: Vec<(Integer,Op,Integer,Result<Integer,OpError>)> = vec![
+        // constants which overflow have no value
+        (big, Op::ADD, Integer::new_constant(1), Err(OpError::Overflow)),
+        (Integer::new_constant(i64::MIN), Op::SUB, Integer::new_constant(1), Err(OpError::Overflow)),
+        (big, Op::MUL, Integer::new_constant(2), Err(OpError::Overflow)),
+        (Integer::new_constant(i64::MIN), Op::DIV, Integer::new_constant(-1), Err(OpError::Overflow)),
+        (Integer::new_constant(10), Op::POW, Integer::new_constant(30), Err(OpError::Overflow)),
+        (big, Op::ADD, Integer::new_constant(-1), Ok(Integer::new_constant(i64::MAX - 1))),
+        // as do ranges where every value overflows
+        (Integer::new(i64::MAX, i64::MAX - 5, None), Op::ADD, Integer::new(10, 6, None), Err(OpError::Overflow)),
+        (Integer::new(20, 10, None), Op::POW, Integer::new(40, 30, None), Err(OpError::Overflow)),
+        // ranges which might overflow lose that bound
+        (Integer::new(i64::MAX, 0, None), Op::MUL, Integer::new(2, 1, None), Ok(Integer::new(None, 0, None))),
+        (Integer::new(i64::MAX, i64::MAX - 5, None), Op::ADD, Integer::new(10, 0, None), Ok(Integer::new(None, i64::MAX - 5, None))),
+        (Integer::new(20, 1, None), Op::POW, Integer::new(40, 1, None), Ok(Integer::new(None, 1, None))),
+    ];
+    for (l, op, r, result) in tests {
+        let output = trinary_iii_op(&l, op, &r);
+        if output != result {
+            panic!("found:{:?} expected:{:?} for ( {:?} {:?} {:?} )", output, result, l, op, r);
+        }
+    }
+}
@@ -1,16 +1,18 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-use crate::internals::parser::ast::op::Op;
+use crate::internals::parser::ast::op::{Op,UnaryOp};
 
 pub mod int;
 #[doc(no_inline)]
 pub use self::int::{Integer, IntegerMutTrait, IntegerTrait};
-use self::int::{trinary_iib_op,trinary_iii_op};
+use self::int::{trinary_iib_op,trinary_iii_op,unary_i_op};
 
 pub mod boolean;
 #[doc(no_inline)]
 pub use self::boolean::{Boolean, BooleanMutTrait, BooleanTrait};
-use self::boolean::{trinary_operations};
+use self::boolean::{trinary_operations,unary_operations};
 
 /// Prim is a basic representation of a primative value.
 ///
@@ -22,32 +24,104 @@ pub enum Prim {
     Bool(Boolean),
 }
 
+/// OpError is why an operation has no resulting type
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum OpError {
+    /// the operation is not defined for these types (or values)
+    Invalid,
+    /// every value the operation produces lies outside of an `int`
+    Overflow,
+}
+
+impl From<()> for OpError {
+    fn from(_: ()) -> Self {
+        OpError::Invalid
+    }
+}
+
 /// calculates the resulting type (and bounds) for a trinary operation
-pub fn trinary_op<L,R>(l: &L, op: Op, r: &R) -> Result<Prim,()>
+pub fn trinary_op<L,R>(l: &L, op: Op, r: &R) -> Result<Prim,OpError>
 where
     L: PrimativeTrait,
     R: PrimativeTrait,
 {
-    match (l.get_bool(), r.get_bool()) {
-        (Option::Some(l_bool), Option::Some(r_bool)) => {
-            return Ok(Prim::from(trinary_operations(l_bool, op, r_bool)?));
-        },
-        _ => { }
+    if let (Option::Some(l_bool), Option::Some(r_bool)) = (l.get_bool(), r.get_bool()) {
+        return Ok(Prim::from(trinary_operations(l_bool, op, r_bool)?));
     };
-    match (l.get_int(), r.get_int()) {
-        (Option::Some(l_int),Option::Some(r_int)) => {
-            match trinary_iii_op(l_int, op, r_int) {
-                Ok(i) => return Ok(Prim::from(i)),
-                _ => { }
-            };
-            match trinary_iii_op(l_int, op, r_int) {
-                Ok(b) => return Ok(Prim::from(b)),
-                _ => { }
-            }
-        },
-        _ => { }
+    if let (Option::Some(l_int),Option::Some(r_int)) = (l.get_int(), r.get_int()) {
+        match trinary_iii_op(l_int, op, r_int) {
+            Ok(i) => return Ok(Prim::from(i)),
+            Err(OpError::Overflow) => return Err(OpError::Overflow),
+            Err(OpError::Invalid) => { }
+        };
+        if let Ok(b) = trinary_iib_op(l_int, op, r_int) {
+            return Ok(Prim::from(b));
+        }
     };
-    Err(())
+    Err(OpError::Invalid)
+}
+
+/// calculates the resulting type (and bounds) for a unary operation
+pub fn unary_op<A>(arg: &A, op: UnaryOp) -> Result<Prim,OpError>
+where
+    A: PrimativeTrait,
+{
+    match (arg.get_int(), arg.get_bool()) {
+        (Option::Some(int), _) => Ok(Prim::from(unary_i_op(int, op)?)),
+        (_, Option::Some(b)) => Ok(Prim::from(unary_operations(b, op)?)),
+        _ => Err(OpError::Invalid),
+    }
+}
+
+/// calculates the resulting type (and bounds) of folding a collection
+/// with `op`, starting from `identity`. The collection's length lies
+/// within `len`, and each element is of type `element`.
+///
+/// A sum has a closed form, `identity + len * element`. Any other fold
+/// is expanded one element at a time, it must settle upon a fixed point
+/// if the collection may be long.
+pub fn fold_op<I,E>(identity: &I, op: Op, element: &E, len: &Integer) -> Result<Prim,OpError>
+where
+    I: PrimativeTrait,
+    E: PrimativeTrait,
+{
+    // the most elements which are expanded
+    const LIMIT: i64 = 64;
+
+    let min = len.get_minimum().unwrap_or(0).max(0);
+    let max = len.get_maximum();
+    if let (Option::Some(identity), Option::Some(element), Op::ADD) = (identity.get_int(), element.get_int(), op) {
+        let len = match max {
+            Option::Some(max) if max == min => Integer::new_constant(min),
+            max => Integer::new(max, min, None),
+        };
+        let sum = trinary_iii_op(&len, Op::MUL, element)?;
+        return Ok(Prim::from(trinary_iii_op(identity, Op::ADD, &sum)?));
+    }
+
+    let mut acc = *identity.as_ref();
+    let mut kind: Option<Prim> = None;
+    for n in 0..=LIMIT {
+        if n >= min {
+            kind = match kind {
+                Option::Some(kind) => Some(kind.union(&acc).ok_or(OpError::Invalid)?),
+                Option::None => Some(acc),
+            };
+        }
+        if max == Some(n) {
+            return kind.ok_or(OpError::Invalid);
+        }
+        let next = trinary_op(&acc, op, element)?;
+        if next == acc {
+            // every longer collection folds to the same value
+            return match kind {
+                Option::Some(kind) => kind.union(&acc).ok_or(OpError::Invalid),
+                Option::None => Ok(acc),
+            };
+        }
+        acc = next;
+    }
+    Err(OpError::Invalid)
 }
 
 impl Prim {
@@ -76,6 +150,29 @@ impl Prim {
     pub fn new_idk_int() -> Self {
         Self::from(Integer::new(None, None, None))
     }
+
+    /// The smallest `Prim` which contains every value of both `self` & `other`.
+    ///
+    /// returns `None` if one is an integer, and the other a boolean.
+    pub fn union<O>(&self, other: &O) -> Option<Self>
+    where
+        O: PrimativeTrait,
+    {
+        match (self, other.as_ref()) {
+            (Prim::Int(l), Prim::Int(r)) => Some(Prim::from(l.union(r))),
+            (Prim::Bool(l), Prim::Bool(r)) => Some(Prim::from(l.union(r))),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Prim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Prim::Int(int) => write!(f, "int {}", int),
+            Prim::Bool(b) => write!(f, "bool {}", b),
+        }
+    }
 }
 
 impl From<bool> for Prim {
@@ -104,14 +201,14 @@ impl From<Integer> for Prim {
 
 impl AsRef<Prim> for Prim {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a Prim {
+    fn as_ref(&self) -> &Prim {
         self
     }
 }
 
 impl AsMut<Prim> for Prim {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut Prim {
+    fn as_mut(&mut self) -> &mut Prim {
         self
     }
 }
@@ -129,17 +226,17 @@ pub trait PrimativeTrait: AsRef<Prim> {
     }
 
     /// return a readable view of the integer
-    fn get_int<'a>(&'a self) -> Option<&'a Integer> {
+    fn get_int(&self) -> Option<&Integer> {
         match self.as_ref() {
-            &Prim::Int(ref int) => Some(int),
+            Prim::Int(int) => Some(int),
             _ => None,
         }
     }
 
     /// return a readable view of the boolean
-    fn get_bool<'a>(&'a self) -> Option<&'a Boolean> {
+    fn get_bool(&self) -> Option<&Boolean> {
         match self.as_ref() {
-            &Prim::Bool(ref b) => Some(b),
+            Prim::Bool(b) => Some(b),
             _ => None
         }
     }
@@ -148,9 +245,9 @@ pub trait PrimativeTrait: AsRef<Prim> {
 /// For mutating the contents of this type
 pub trait PrimativeMutTrait: AsMut<Prim> + AsRef<Prim> + PrimativeTrait {
     /// return the mutable integer data
-    fn get_mut_int<'a>(&'a mut self) -> Option<&'a mut Integer> {
+    fn get_mut_int(&mut self) -> Option<&mut Integer> {
         match self.as_mut() {
-            &mut Prim::Int(ref mut int) => Some(int),
+            Prim::Int(int) => Some(int),
             _ => None,
         }
     }
@@ -172,7 +269,7 @@ pub trait PrimativeMutTrait: AsMut<Prim> + AsRef<Prim> + PrimativeTrait {
                 Option::None => {
                     panic!("type is a boolean, but mutable int doesn't exist?");
                 }
-                Option::Some(ref mut arg) => {
+                Option::Some(arg) => {
                     arg.set_constant(constant);
                 }
             };
@@ -183,3 +280,26 @@ pub trait PrimativeMutTrait: AsMut<Prim> + AsRef<Prim> + PrimativeTrait {
 impl PrimativeTrait for Prim {}
 
 impl PrimativeMutTrait for Prim {}
+
+#[test]
+fn folds_scale_with_their_length() {
+    let d6 = Prim::new_int_dynamic(6, 1, None);
+    // identity, operation, element, length & the folded result
+    type Fold = (Prim, Op, Prim, Integer, Result<Prim, OpError>);
+    let tests: Vec<Fold> = vec![
+        // a sum is bounded by the shortest & longest collections
+        (Prim::from(0), Op::ADD, d6, Integer::new(5, 3, None), Ok(Prim::new_int_dynamic(30, 3, None))),
+        (Prim::from(0), Op::ADD, d6, Integer::new_constant(3), Ok(Prim::new_int_dynamic(18, 3, None))),
+        (Prim::from(10), Op::ADD, Prim::from(-1), Integer::new(None, 0, None), Ok(Prim::new_int_dynamic(10, None, None))),
+        (Prim::from(1), Op::MUL, Prim::new_int_dynamic(2, 0, None), Integer::new(2, 0, None), Ok(Prim::new_int_dynamic(4, 0, None))),
+        // a fixed point bounds collections of any length
+        (Prim::from(true), Op::AND, Prim::new_boolean(), Integer::new(None, 0, None), Ok(Prim::new_boolean())),
+        (Prim::from(false), Op::OR, Prim::from(false), Integer::new(None, 0, None), Ok(Prim::from(false))),
+        (Prim::from(1), Op::MUL, d6, Integer::new(None, 0, None), Ok(Prim::new_int_dynamic(None, 1, None))),
+        (Prim::from(true), Op::XOR, Prim::from(true), Integer::new(None, 0, None), Err(OpError::Invalid)),
+        (Prim::from(0), Op::ADD, Prim::from(true), Integer::new_constant(2), Err(OpError::Invalid)),
+    ];
+    for (identity, op, element, len, expected) in tests {
+        assert_eq!(fold_op(&identity, op, &element, &len), expected, "{} {:?} {} over {}", identity, op, element, len);
+    }
+}
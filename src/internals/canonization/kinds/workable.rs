@@ -1,3 +1,5 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
 use super::{Collection, CollectionTrait, Compositional, CompositionalTrait, Function, FunctionTrait, Prim};
@@ -7,7 +9,9 @@ use crate::internals::parser::ast::func::FunctionDec;
 use crate::internals::parser::ast::kind::Kind as AstKind;
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[derive(Default)]
 pub enum TypeData {
+    #[default]
     None,
     Coll(Collection),
     Prim(Prim),
@@ -15,22 +19,29 @@ pub enum TypeData {
     Comp(Compositional),
 }
 
-impl Default for TypeData {
-    fn default() -> Self {
-        Self::None
+
+impl fmt::Display for TypeData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeData::None => write!(f, "unknown"),
+            TypeData::Coll(coll) => write!(f, "{}", coll),
+            TypeData::Prim(prim) => write!(f, "{}", prim),
+            TypeData::Func(_) => write!(f, "function"),
+            TypeData::Comp(_) => write!(f, "compositional function"),
+        }
     }
 }
 
 impl AsRef<TypeData> for TypeData {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a TypeData {
+    fn as_ref(&self) -> &TypeData {
         self
     }
 }
 
 impl AsMut<TypeData> for TypeData {
     #[inline(always)]
-    fn as_mut<'a>(&'a mut self) -> &'a mut TypeData {
+    fn as_mut(&mut self) -> &mut TypeData {
         self
     }
 }
@@ -71,10 +82,10 @@ impl From<AstKind> for TypeData {
             AstKind::Int => Self::Prim(Prim::new_idk_int()),
             AstKind::Bool => Self::Prim(Prim::new_boolean()),
             AstKind::CollOfInt => {
-                Self::Coll(Collection::new(Prim::new_idk_int(), None, None, None))
+                Self::Coll(Collection::new(Prim::new_idk_int(), None, 0, None))
             }
             AstKind::CollOfBool => {
-                Self::Coll(Collection::new(Prim::new_boolean(), None, None, None))
+                Self::Coll(Collection::new(Prim::new_boolean(), None, 0, None))
             }
         }
     }
@@ -103,8 +114,8 @@ impl<'temp> From<&'temp Box<AstKind>> for TypeData {
 impl<'temp> From<&'temp Box<Option<AstKind>>> for TypeData {
     fn from(arg: &'temp Box<Option<AstKind>>) -> Self {
         match arg.as_ref() {
-            &Option::None => Self::None,
-            &Option::Some(ref ast_kind) => Self::from(ast_kind),
+            Option::None => Self::None,
+            Option::Some(ast_kind) => Self::from(ast_kind),
         }
     }
 }
@@ -143,10 +154,7 @@ pub enum NonAbstractData<'a> {
 pub trait TypeDataTrait: AsRef<TypeData> + AsMut<TypeData> {
 
     fn is_none(&self) -> bool {
-        match self.as_ref() {
-            &TypeData::None => true,
-            _ => false,
-        }
+        matches!(self.as_ref(), TypeData::None)
     }
 
     /// asserts:
@@ -172,7 +180,7 @@ pub trait TypeDataTrait: AsRef<TypeData> + AsMut<TypeData> {
                     .map(|(s, o)| s == o),
             )
             .next()
-            .unwrap_or_else(|| false)
+            .unwrap_or(false)
     }
 
     fn is_coll(&self) -> bool {
@@ -205,38 +213,38 @@ pub trait TypeDataTrait: AsRef<TypeData> + AsMut<TypeData> {
     /// otherwise it returns the interior type.
     fn get_non_abstract_type<'a>(&'a self) -> Option<NonAbstractData<'a>> {
         match self.as_ref() {
-            &TypeData::None => None,
-            &TypeData::Prim(ref p) => Some(NonAbstractData::Prim(p)),
-            &TypeData::Coll(ref c) => Some(NonAbstractData::Coll(c)),
-            &TypeData::Func(ref f) => f.get_return().get_non_abstract_type(),
-            &TypeData::Comp(ref c) => c.get_return().get_non_abstract_type(),
+            TypeData::None => None,
+            TypeData::Prim(p) => Some(NonAbstractData::Prim(p)),
+            TypeData::Coll(c) => Some(NonAbstractData::Coll(c)),
+            TypeData::Func(f) => f.get_return().get_non_abstract_type(),
+            TypeData::Comp(c) => c.get_return().get_non_abstract_type(),
         }
     }
 
-    fn get_coll<'a>(&'a self) -> Option<&'a Collection> {
+    fn get_coll(&self) -> Option<&Collection> {
         match self.as_ref() {
-            &TypeData::Coll(ref coll) => Some(coll),
+            TypeData::Coll(coll) => Some(coll),
             _ => None,
         }
     }
 
-    fn get_prim<'a>(&'a self) -> Option<&'a Prim> {
+    fn get_prim(&self) -> Option<&Prim> {
         match self.as_ref() {
-            &TypeData::Prim(ref a) => Some(a),
+            TypeData::Prim(a) => Some(a),
             _ => None,
         }
     }
 
-    fn get_func<'a>(&'a self) -> Option<&'a Function> {
+    fn get_func(&self) -> Option<&Function> {
         match self.as_ref() {
-            &TypeData::Func(ref a) => Some(a),
+            TypeData::Func(a) => Some(a),
             _ => None,
         }
     }
 
-    fn get_comp<'a>(&'a self) -> Option<&'a Compositional> {
+    fn get_comp(&self) -> Option<&Compositional> {
         match self.as_ref() {
-            &TypeData::Comp(ref a) => Some(a),
+            TypeData::Comp(a) => Some(a),
             _ => None,
         }
     }
@@ -258,30 +266,30 @@ pub trait TypeDataTrait: AsRef<TypeData> + AsMut<TypeData> {
         let _ = std::mem::replace(self.as_mut(), TypeData::from(arg));
     }
 
-    fn get_mut_coll<'a>(&'a mut self) -> Option<&'a mut Collection> {
+    fn get_mut_coll(&mut self) -> Option<&mut Collection> {
         match self.as_mut() {
-            &mut TypeData::Coll(ref mut coll) => Some(coll),
+            TypeData::Coll(coll) => Some(coll),
             _ => None,
         }
     }
 
-    fn get_mut_prim<'a>(&'a mut self) -> Option<&'a mut Prim> {
+    fn get_mut_prim(&mut self) -> Option<&mut Prim> {
         match self.as_mut() {
-            &mut TypeData::Prim(ref mut a) => Some(a),
+            TypeData::Prim(a) => Some(a),
             _ => None,
         }
     }
 
-    fn get_mut_func<'a>(&'a mut self) -> Option<&'a mut Function> {
+    fn get_mut_func(&mut self) -> Option<&mut Function> {
         match self.as_mut() {
-            &mut TypeData::Func(ref mut a) => Some(a),
+            TypeData::Func(a) => Some(a),
             _ => None,
         }
     }
 
-    fn get_mut_comp<'a>(&'a mut self) -> Option<&'a mut Compositional> {
+    fn get_mut_comp(&mut self) -> Option<&mut Compositional> {
         match self.as_mut() {
-            &mut TypeData::Comp(ref mut a) => Some(a),
+            TypeData::Comp(a) => Some(a),
             _ => None,
         }
     }
@@ -0,0 +1,341 @@
+//! Call Graph
+//!
+//! Every function & compositional function of a program is a node, an
+//! edge runs from a caller to each function it invokes. A compositional
+//! function invokes the functions it is composed from. The statements
+//! outside of any function are the roots of the program, functions they
+//! can't reach are never evaluated.
+//!
+//! Evaluation expands every call, so recursion (direct or through other
+//! functions) is rejected, leaving the graph acyclic.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use petgraph::{
+    algo::{tarjan_scc, toposort},
+    graph::{DiGraph, NodeIndex},
+    visit::{Dfs, EdgeRef},
+};
+
+use crate::internals::{
+    canonization::to_ast::{
+        namespace::FuncType,
+        resolve::{Resolutions, Resolved},
+        validation_errors::ValidationErrors,
+    },
+    parser::{
+        ast::{
+            comparg::{CompositionalArg, CompositionalFunction},
+            expr::{Expr, Expression},
+            func::FunctionDec,
+            ident::Ident,
+            statement::{State, Statement},
+        },
+        loader::Program,
+        span::{Span, Spanner},
+    },
+};
+
+/// Which functions of a program invoke which others
+#[derive(Clone, Debug, Default)]
+pub struct CallGraph {
+    // edges are weighted by the first call site within the caller
+    graph: DiGraph<FuncType, Span>,
+    // keyed by the span of the declaration
+    nodes: BTreeMap<Span, NodeIndex>,
+    // functions invoked outside of any function
+    roots: BTreeSet<NodeIndex>,
+}
+
+impl CallGraph {
+    /// the underlying graph, an edge runs from a caller to its callee
+    pub fn graph(&self) -> &DiGraph<FuncType, Span> {
+        &self.graph
+    }
+
+    /// the node of a function within `graph`
+    pub fn node(&self, func: &FuncType) -> Option<NodeIndex> {
+        self.nodes.get(func.as_ref()).cloned()
+    }
+
+    /// every function invoked by `func`
+    pub fn callees<'a>(&'a self, func: &FuncType) -> impl Iterator<Item = &'a FuncType> + 'a {
+        self.node(func)
+            .into_iter()
+            .flat_map(move |node| self.graph.neighbors(node))
+            .map(move |callee| &self.graph[callee])
+    }
+
+    /// Every function, each after the functions it invokes.
+    ///
+    /// Evaluating in this order, a callee is always evaluated before
+    /// its callers.
+    pub fn evaluation_order(&self) -> Vec<&FuncType> {
+        // a graph with a cycle is never built
+        let mut order = toposort(&self.graph, None).unwrap_or_default();
+        order.reverse();
+        order.into_iter().map(|node| &self.graph[node]).collect()
+    }
+
+    /// can `func` be evaluated by the statements outside of any function
+    pub fn is_reachable(&self, func: &FuncType) -> bool {
+        match self.node(func) {
+            Option::Some(node) => self.reachable().contains(&node),
+            Option::None => false,
+        }
+    }
+
+    /// every function which is never evaluated, in declaration order
+    pub fn unreachable(&self) -> Vec<&FuncType> {
+        let reachable = self.reachable();
+        self.nodes
+            .values()
+            .filter(|node| !reachable.contains(node))
+            .map(|node| &self.graph[*node])
+            .collect()
+    }
+
+    fn reachable(&self) -> BTreeSet<NodeIndex> {
+        let mut reachable = BTreeSet::new();
+        for root in self.roots.iter() {
+            let mut dfs = Dfs::new(&self.graph, *root);
+            while let Option::Some(node) = dfs.next(&self.graph) {
+                reachable.insert(node);
+            }
+        }
+        reachable
+    }
+}
+
+/// Builds the call graph of `program`, every name it uses must be
+/// resolved (see `resolve_program`).
+///
+/// Each cycle of calls is reported once.
+pub fn call_graph<E>(program: &Program, resolutions: &Resolutions) -> Result<CallGraph, Vec<E>>
+where
+    E: ValidationErrors,
+{
+    let mut builder = Builder {
+        resolutions,
+        calls: CallGraph::default(),
+    };
+    for statement in program.top_level() {
+        builder.statement(statement, Option::None);
+    }
+    let calls = builder.calls;
+
+    let mut errors = Vec::new();
+    for component in tarjan_scc(&calls.graph) {
+        let func = match component.iter().min_by_key(|node| calls.graph[**node].get_clone()) {
+            Option::Some(func) => *func,
+            Option::None => continue,
+        };
+        let cycle = match cycle(&calls.graph, func, &component) {
+            Option::Some(cycle) => cycle,
+            Option::None => continue,
+        };
+        let names = cycle
+            .iter()
+            .map(|node| calls.graph[*node].get_name().get_span().to_string())
+            .collect::<Vec<String>>();
+        let call = match calls.graph.find_edge(cycle[0], cycle.get(1).cloned().unwrap_or(func)) {
+            Option::Some(edge) => &calls.graph[edge],
+            Option::None => continue,
+        };
+        errors.push(E::recursive_call(call, &calls.graph[func], &names));
+    }
+
+    if errors.is_empty() {
+        Ok(calls)
+    } else {
+        Err(errors)
+    }
+}
+
+/// the shortest cycle of calls from `func` back to itself within
+/// `component`, starting with `func`.
+fn cycle(graph: &DiGraph<FuncType, Span>, func: NodeIndex, component: &[NodeIndex]) -> Option<Vec<NodeIndex>> {
+    let mut previous: BTreeMap<NodeIndex, NodeIndex> = BTreeMap::new();
+    let mut queue = vec![func];
+    while !queue.is_empty() {
+        let mut next = Vec::new();
+        for node in queue {
+            for edge in graph.edges(node) {
+                let callee = edge.target();
+                if callee == func {
+                    let mut path = vec![node];
+                    while let Option::Some(prior) = previous.get(path.last().unwrap()) {
+                        path.push(*prior);
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                if component.contains(&callee) && !previous.contains_key(&callee) && callee != func {
+                    previous.insert(callee, node);
+                    next.push(callee);
+                }
+            }
+        }
+        queue = next;
+    }
+    None
+}
+
+struct Builder<'a> {
+    resolutions: &'a Resolutions,
+    calls: CallGraph,
+}
+
+impl<'a> Builder<'a> {
+    /// the statements of a body
+    fn statements(&mut self, statements: &[Statement], caller: Option<NodeIndex>) {
+        for statement in statements {
+            self.statement(statement, caller);
+        }
+    }
+
+    /// `caller` is `None` outside of any function
+    fn statement(&mut self, statement: &Statement, caller: Option<NodeIndex>) {
+        match statement.sttm.as_ref() {
+            State::Func(func) => self.func(func),
+            State::CompFunc(comp) => self.comp(comp),
+            State::Declaration(assign) => self.expr(&assign.expr, caller),
+            State::Termination(expr) => self.expr(expr, caller),
+            State::Analysis(analyze) => self.expr(&analyze.expr, caller),
+            State::Import(_) => {}
+        };
+    }
+
+    fn func(&mut self, func: &FunctionDec) {
+        let node = self.node(&FuncType::Normal(func.clone()));
+        self.statements(&func.body, Some(node));
+    }
+
+    fn comp(&mut self, comp: &CompositionalFunction) {
+        let node = self.node(&FuncType::Comp(comp.clone()));
+        for arg in [&comp.null_arg, &comp.single_arg, &comp.collection_arg].iter() {
+            if let CompositionalArg::Func(ident) = &arg.arg {
+                self.call(ident, Some(node));
+            }
+        }
+    }
+
+    fn expr(&mut self, e: &Expression, caller: Option<NodeIndex>) {
+        match e.kind.as_ref() {
+            Expr::Invoke(invoke) => {
+                self.call(&invoke.name, caller);
+                for arg in invoke.args.iter() {
+                    self.expr(arg, caller);
+                }
+            }
+            Expr::Op(op) => {
+                self.expr(&op.left, caller);
+                self.expr(&op.right, caller);
+            }
+            Expr::Unary(unary) => self.expr(&unary.expr, caller),
+            Expr::Parens(inner) => self.expr(inner, caller),
+            Expr::Cond(cond) => {
+                self.expr(&cond.condition, caller);
+                self.expr(&cond.true_case, caller);
+                self.expr(&cond.false_case, caller);
+            }
+            Expr::Match(m) => {
+                self.expr(&m.scrutinee, caller);
+                for arm in m.arms.iter() {
+                    self.expr(&arm.expr, caller);
+                }
+            }
+            Expr::Block(block) => self.statements(&block.body, caller),
+            Expr::Coll(coll) => {
+                for item in coll.items.iter() {
+                    self.expr(item, caller);
+                }
+            }
+            Expr::Index(index) => {
+                self.expr(&index.collection, caller);
+                self.expr(&index.index, caller);
+            }
+            Expr::Len(len) => self.expr(&len.collection, caller),
+            Expr::Var(_) | Expr::Num(_) | Expr::Dice(_) | Expr::Template(_) => {}
+        };
+    }
+
+    /// an identifier which names a function, invoked by `caller`
+    fn call(&mut self, ident: &Ident, caller: Option<NodeIndex>) {
+        let callee = match self.resolutions.get(ident) {
+            Option::Some(Resolved::Func(func)) => self.node(func),
+            _ => return,
+        };
+        match caller {
+            Option::Some(caller) => {
+                // only the first call site is kept
+                if self.calls.graph.find_edge(caller, callee).is_none() {
+                    self.calls.graph.add_edge(caller, callee, ident.get_clone());
+                }
+            }
+            Option::None => {
+                self.calls.roots.insert(callee);
+            }
+        };
+    }
+
+    fn node(&mut self, func: &FuncType) -> NodeIndex {
+        if let Option::Some(node) = self.calls.node(func) {
+            return node;
+        }
+        let node = self.calls.graph.add_node(func.clone());
+        self.calls.nodes.insert(func.get_clone(), node);
+        node
+    }
+}
+
+#[cfg(test)]
+fn graph_str(source: &str) -> Result<CallGraph, Vec<String>> {
+    use crate::internals::{
+        canonization::to_ast::{namespace::Namespace, resolve::resolve_program},
+        errors::term_errors::{render_for_test, HumanReadable},
+    };
+
+    let program = Program::from_str_for_test(source);
+    let ns = Namespace::from_program::<HumanReadable>(&program).map_err(render_for_test)?;
+    let resolutions = resolve_program::<HumanReadable>(&program, &ns).map_err(render_for_test)?;
+    call_graph::<HumanReadable>(&program, &resolutions).map_err(render_for_test)
+}
+
+#[test]
+fn callees_are_evaluated_first() {
+    let source = "fn hit(x: int) bool { x >= wound() }\nfn wound() int { 4 }\n\
+                  fn unused() int { wound() + { fn inner() int { 1 } inner() } }\n\
+                  cm count(0, score, +) int;\nfn score(x: int) int { if hit(x) { 1 } else { 0 } }\n\
+                  analyze hits = count([d6, d6]);";
+    let calls = graph_str(source).unwrap();
+    let name = |func: &FuncType| func.get_name().get_span().to_string();
+
+    let order = calls.evaluation_order().into_iter().map(name).collect::<Vec<_>>();
+    let position = |func: &str| order.iter().position(|x| x == func).unwrap();
+    assert_eq!(order.len(), 6);
+    assert!(position("wound") < position("hit") && position("hit") < position("score"));
+    assert!(position("score") < position("count") && position("inner") < position("unused"));
+
+    let unreachable = calls.unreachable().into_iter().map(name).collect::<Vec<_>>();
+    assert_eq!(unreachable, vec!["unused", "inner"]);
+    let count = calls.graph().raw_nodes().iter().map(|n| &n.weight).find(|f| name(f) == "count").unwrap();
+    assert!(calls.is_reachable(count));
+    assert_eq!(calls.callees(count).map(name).collect::<Vec<_>>(), vec!["score"]);
+}
+
+#[test]
+fn recursion_is_reported() {
+    let first_error = |source: &str| graph_str(source).err().unwrap().remove(0);
+
+    let err = first_error("fn f(x: int) int { if x > 0 { f(x - 1) } else { 0 } }\nf(3)");
+    assert!(err.contains("`f` calls itself") && err.contains("<anon>:1:31"), "{}", err);
+    let err = first_error("fn a() int { b() }\nfn b() int { c() + 1 }\nfn c() int { a() }\na()");
+    assert!(err.contains("`a` calls itself through `b`, `c`") && err.contains("<anon>:1:14"), "{}", err);
+    let err = first_error("fn f(x: int) int { x }\ncm total(0, g, +) int;\nfn g(x: int) int { total([x]) }");
+    assert!(err.contains("through `g`"), "{}", err);
+
+    // each cycle is reported once
+    let errors = graph_str("fn a() int { b() }\nfn b() int { a() }\nfn c() int { c() }").err().unwrap();
+    assert_eq!(errors.len(), 2);
+}
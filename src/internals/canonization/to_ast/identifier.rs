@@ -6,6 +6,7 @@ use crate::internals::parser::{
     ast::{
         ident::Ident,
         comparg::CompositionalFunction,
+        args::FunctionArg,
         assign::Assign,
         func::FunctionDec,
     },
@@ -68,6 +69,12 @@ impl From<&Box<Assign>> for Hash {
     }
 }
 
+impl From<&FunctionArg> for Hash {
+    fn from(x: &FunctionArg) -> Self {
+        Hash::from(&x.name)
+    }
+}
+
 impl From<&FunctionDec> for Hash {
     fn from(x: &FunctionDec) -> Self {
         Hash::from(&x.name)
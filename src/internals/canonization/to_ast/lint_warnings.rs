@@ -0,0 +1,23 @@
+use crate::internals::parser::span::Spanner;
+
+// Covers the warnings of the lint pass, see `lints`
+pub trait LintWarnings: Sized {
+    fn unused_variable<S>(var: &S, name: &str) -> Self
+    where
+        S: Spanner;
+    fn unused_function<S>(func: &S, name: &str) -> Self
+    where
+        S: Spanner;
+    fn unused_parameter<A,F>(arg: &A, name: &str, func: &F) -> Self
+    where
+        A: Spanner,
+        F: Spanner;
+    fn shadowed_binding<S,O>(binding: &S, name: &str, outer: &O) -> Self
+    where
+        S: Spanner,
+        O: Spanner;
+    fn impossible_branch<A,C>(arm: &A, condition: &C, outcome: bool) -> Self
+    where
+        A: Spanner,
+        C: Spanner;
+}
@@ -0,0 +1,345 @@
+//! Lints
+//!
+//! Warnings about a program which is valid, but likely not what was
+//! meant. Each lint has a stable name, so it can be silenced:
+//!
+//! * `unused-variable` a `let` or `const` which is never read.
+//! * `unused-function` a `fn` or `cm` which is never invoked (by the
+//!   statements outside of any function, or functions they invoke).
+//! * `unused-parameter` a function argument its body never reads.
+//! * `shadowed-binding` a variable or argument with the same name as
+//!   one declared by an enclosing scope.
+//! * `impossible-branch` an arm of an `if` which is never taken, as the
+//!   variables its condition compares can't hold a value it needs. These
+//!   are only known once the program is typed, see `lint_branches`.
+//!
+//! A name starting with `_` is never reported as unused, nor is one
+//! declared by an imported file (a library may declare more than any
+//! one program uses).
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::internals::{
+    canonization::to_ast::{
+        call_graph::CallGraph,
+        identifier::Hash,
+        lint_warnings::LintWarnings,
+        partial_ast::ImpossibleBranch,
+        resolve::{Resolutions, Resolved},
+    },
+    parser::{
+        ast::{
+            expr::{Expr, Expression},
+            func::FunctionDec,
+            ident::Ident,
+            statement::{State, Statement},
+        },
+        loader::Program,
+        span::{Span, Spanner},
+    },
+};
+
+/// Lint is each kind of warning
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+    UnusedVariable,
+    UnusedFunction,
+    UnusedParameter,
+    ShadowedBinding,
+    ImpossibleBranch,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 5] = [
+        Lint::UnusedVariable,
+        Lint::UnusedFunction,
+        Lint::UnusedParameter,
+        Lint::ShadowedBinding,
+        Lint::ImpossibleBranch,
+    ];
+
+    /// the stable name of the lint
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "unused-variable",
+            Lint::UnusedFunction => "unused-function",
+            Lint::UnusedParameter => "unused-parameter",
+            Lint::ShadowedBinding => "shadowed-binding",
+            Lint::ImpossibleBranch => "impossible-branch",
+        }
+    }
+
+    /// the lint with the stable name `name`
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match Lint::ALL.iter().find(|lint| lint.name() == name) {
+            Option::Some(lint) => Ok(*lint),
+            Option::None => Err(format!(
+                "`{}` is not a lint, expected one of: {}",
+                name,
+                Lint::ALL.iter().map(|lint| lint.name()).collect::<Vec<_>>().join(", ")
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Lints `program`, except for the lints within `allowed`.
+///
+/// every name it uses must be resolved (see `resolve_program`), and
+/// `calls` is its call graph.
+pub fn lint_program<W>(
+    program: &Program,
+    resolutions: &Resolutions,
+    calls: &CallGraph,
+    allowed: &BTreeSet<Lint>,
+) -> Vec<W>
+where
+    W: LintWarnings,
+{
+    let read = resolutions
+        .iter()
+        .filter_map(|(_, def)| match def {
+            Resolved::Var(var) => Some(var.get_clone()),
+            Resolved::Func(_) => None,
+        })
+        .collect();
+    let mut linter = Linter {
+        root: program.root().id,
+        read,
+        scopes: vec![BTreeMap::new()],
+        allowed,
+        warnings: Vec::new(),
+    };
+
+    // like name resolution, function bodies see every top level variable
+    let top_level = program.top_level();
+    let mut funcs = Vec::new();
+    for statement in top_level {
+        match statement.sttm.as_ref() {
+            State::Func(func) => funcs.push(func.as_ref()),
+            _ => linter.statement(statement),
+        };
+    }
+    for func in funcs {
+        linter.func(func);
+    }
+
+    for func in calls.unreachable() {
+        let name = func.get_name();
+        if linter.is_allowed(Lint::UnusedFunction, name) {
+            continue;
+        }
+        linter.warnings.push(W::unused_function(func, name.get_span()));
+    }
+    linter.warnings
+}
+
+/// Reports the arms of an `if` found to never be taken while typing
+/// (see `P2Program`), unless `impossible-branch` is `allowed`.
+pub fn lint_branches<W>(branches: &[ImpossibleBranch], allowed: &BTreeSet<Lint>) -> Vec<W>
+where
+    W: LintWarnings,
+{
+    if allowed.contains(&Lint::ImpossibleBranch) {
+        return Vec::new();
+    }
+    branches
+        .iter()
+        .map(|branch| W::impossible_branch(branch, &branch.condition, branch.outcome))
+        .collect()
+}
+
+struct Linter<'a, W> {
+    // the file id of the root file
+    root: u32,
+    // the declaration of every variable which is read
+    read: BTreeSet<Span>,
+    // the variables declared by each enclosing scope, innermost last
+    scopes: Vec<BTreeMap<Hash, Span>>,
+    allowed: &'a BTreeSet<Lint>,
+    warnings: Vec<W>,
+}
+
+impl<'a, W> Linter<'a, W>
+where
+    W: LintWarnings,
+{
+    fn is_allowed(&self, lint: Lint, name: &Ident) -> bool {
+        let unused = lint != Lint::ShadowedBinding && lint != Lint::ImpossibleBranch;
+        let exempt = name.get_span().starts_with('_') || name.get_file_id() != self.root;
+        self.allowed.contains(&lint) || (unused && exempt)
+    }
+
+    /// a function or block body, a new scope is entered by the caller
+    fn statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.statement(statement);
+        }
+        for statement in statements {
+            if let State::Func(func) = statement.sttm.as_ref() {
+                self.func(func);
+            }
+        }
+    }
+
+    /// lints a statement, functions are handled by the caller
+    fn statement(&mut self, statement: &Statement) {
+        match statement.sttm.as_ref() {
+            State::Declaration(assign) => {
+                self.expr(&assign.expr);
+                self.declare(&assign.name, assign.as_ref().as_ref());
+                if !self.read.contains(assign.as_ref().as_ref()) && !self.is_allowed(Lint::UnusedVariable, &assign.name) {
+                    let warning = W::unused_variable(assign.as_ref(), assign.name.get_span());
+                    self.warnings.push(warning);
+                }
+            }
+            State::Termination(expr) => self.expr(expr),
+            State::Analysis(analyze) => self.expr(&analyze.expr),
+            State::Func(_) | State::CompFunc(_) | State::Import(_) => {}
+        };
+    }
+
+    fn func(&mut self, func: &FunctionDec) {
+        self.scopes.push(BTreeMap::new());
+        for arg in func.args.iter() {
+            self.declare(&arg.name, arg.as_ref());
+            if !self.read.contains(arg.as_ref()) && !self.is_allowed(Lint::UnusedParameter, &arg.name) {
+                self.warnings.push(W::unused_parameter(arg, arg.name.get_span(), func));
+            }
+        }
+        self.statements(&func.body);
+        self.scopes.pop();
+    }
+
+    /// declares a variable within the innermost scope
+    fn declare(&mut self, name: &Ident, span: &Span) {
+        let hash = Hash::from(name);
+        let (inner, outer) = self.scopes.split_last_mut().unwrap();
+        let shadowed = outer.iter().rev().find_map(|scope| scope.get(&hash));
+        if let Option::Some(outer) = shadowed {
+            if !self.allowed.contains(&Lint::ShadowedBinding) {
+                self.warnings.push(W::shadowed_binding(span, name.get_span(), outer));
+            }
+        }
+        inner.insert(hash, span.clone());
+    }
+
+    fn expr(&mut self, e: &Expression) {
+        match e.kind.as_ref() {
+            Expr::Invoke(invoke) => {
+                for arg in invoke.args.iter() {
+                    self.expr(arg);
+                }
+            }
+            Expr::Op(op) => {
+                self.expr(&op.left);
+                self.expr(&op.right);
+            }
+            Expr::Unary(unary) => self.expr(&unary.expr),
+            Expr::Parens(inner) => self.expr(inner),
+            Expr::Cond(cond) => {
+                self.expr(&cond.condition);
+                self.expr(&cond.true_case);
+                self.expr(&cond.false_case);
+            }
+            Expr::Match(m) => {
+                self.expr(&m.scrutinee);
+                for arm in m.arms.iter() {
+                    self.expr(&arm.expr);
+                }
+            }
+            Expr::Block(block) => {
+                self.scopes.push(BTreeMap::new());
+                self.statements(&block.body);
+                self.scopes.pop();
+            }
+            Expr::Coll(coll) => {
+                for item in coll.items.iter() {
+                    self.expr(item);
+                }
+            }
+            Expr::Index(index) => {
+                self.expr(&index.collection);
+                self.expr(&index.index);
+            }
+            Expr::Len(len) => self.expr(&len.collection),
+            Expr::Var(_) | Expr::Num(_) | Expr::Dice(_) | Expr::Template(_) => {}
+        };
+    }
+}
+
+#[cfg(test)]
+fn lint_str(source: &str, allowed: &[Lint]) -> Vec<String> {
+    lint(&Program::from_str_for_test(source), allowed)
+}
+
+#[cfg(test)]
+fn lint(program: &Program, allowed: &[Lint]) -> Vec<String> {
+    use crate::internals::{
+        canonization::to_ast::{call_graph::call_graph, namespace::Namespace, resolve::resolve_program},
+        errors::term_errors::{render_for_test, HumanReadable},
+    };
+
+    let ns = Namespace::from_program::<HumanReadable>(program).ok().unwrap();
+    let resolutions = resolve_program::<HumanReadable>(program, &ns).ok().unwrap();
+    let calls = call_graph::<HumanReadable>(program, &resolutions).ok().unwrap();
+    let allowed = allowed.iter().cloned().collect();
+    render_for_test(lint_program::<HumanReadable>(program, &resolutions, &calls, &allowed))
+}
+
+#[test]
+fn lints_are_reported() {
+    let source = "let bonus = 2;\nlet spare = 3;\nconst _scratch = 4;\n\
+                  fn attack(roll: int, ap: int) int { roll + bonus }\n\
+                  fn helper(_x: int) int { 1 }\ncm total(0, helper, +) int;\n\
+                  analyze hit = attack({ let bonus := d20; bonus }, 1);";
+    let warnings = lint_str(source, &[]);
+    let expected = [
+        ("variable `spare` is never read", "<anon>:2:1", "unused-variable"),
+        ("binding `bonus` shadows", "<anon>:7:24", "shadowed-binding"),
+        ("parameter `ap` is never read", "<anon>:4:22", "unused-parameter"),
+        ("function `helper` is never used", "<anon>:5:1", "unused-function"),
+        ("function `total` is never used", "<anon>:6:1", "unused-function"),
+    ];
+    assert_eq!(warnings.len(), expected.len(), "{:?}", warnings);
+    for (warning, (message, at, lint)) in warnings.iter().zip(expected.iter()) {
+        assert!(warning.contains(message) && warning.contains(at) && warning.contains(lint), "{}", warning);
+    }
+
+    // each lint can be silenced by its stable name
+    let warnings = lint_str(source, &[Lint::UnusedFunction, Lint::ShadowedBinding]);
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(lint_str(source, &Lint::ALL).is_empty());
+    assert_eq!(Lint::parse("unused-parameter"), Ok(Lint::UnusedParameter));
+    assert!(Lint::parse("unused").err().unwrap().contains("shadowed-binding"));
+}
+
+#[test]
+fn arguments_shadow_top_level_variables() {
+    let warnings = lint_str("let x = 1;\nfn f(x: int) int { { let y := x; y } }\nf(x)", &[]);
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("binding `x` shadows") && warnings[0].contains("<anon>:1:1"), "{}", warnings[0]);
+}
+
+#[test]
+fn imported_declarations_are_not_unused() {
+    use crate::internals::{
+        errors::term_errors::HumanReadable,
+        parser::loader::{load_program, scratch_dir},
+    };
+
+    let dir = scratch_dir("lints");
+    std::fs::write(dir.join("lib.fx"), "cm libtotal(0, roll, +) int;\nfn unusedhelper(x: int) int { 1 }\nfn roll() int { d6 }").unwrap();
+    std::fs::write(dir.join("main.fx"), "import \"lib.fx\";\nlet spare = 2;\nroll()").unwrap();
+    let program = load_program::<HumanReadable>(&dir.join("main.fx")).ok().unwrap();
+    let warnings = lint(&program, &[]);
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("variable `spare` is never read"), "{}", warnings[0]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
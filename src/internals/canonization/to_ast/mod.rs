@@ -1,6 +1,11 @@
 
 pub mod template_stuff;
+pub mod call_graph;
 pub mod identifier;
 pub mod namespace;
+pub mod resolve;
+pub mod lint_warnings;
+pub mod lints;
+pub mod sweep;
 pub mod validation_errors;
 pub mod partial_ast;
@@ -1,28 +1,38 @@
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use serde::{Deserialize, Serialize};
 
 use crate::internals::{
-    canonization::to_ast::{
-        identifier::Hash,
-        validation_errors::ValidationErrors,
+    canonization::{
+        kinds::workable::TypeData,
+        to_ast::{
+            identifier::Hash,
+            partial_ast::ImpossibleBranch,
+            validation_errors::ValidationErrors,
+        },
     },
     parser::{
         span::{Span,Spanner},
         ast::{
+            args::FunctionArg,
             assign::Assign,
             func::FunctionDec,
             comparg::CompositionalFunction,
+            ident::Ident,
+            statement::StateTrait,
         },
+        loader::Program,
     }
 };
 
-/// A simple wrapper for the "two" kinds of vars
+/// A simple wrapper for the kinds of vars, function arguments are
+/// variables within the body of their function.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum VarType {
     Var(Assign),
     Const(Assign),
+    Arg(FunctionArg),
 }
 impl From<Assign> for VarType {
     fn from(x: Assign) -> Self {
@@ -34,11 +44,27 @@ impl From<Assign> for VarType {
     }
 }
 
+impl From<FunctionArg> for VarType {
+    fn from(x: FunctionArg) -> Self { Self::Arg(x) }
+}
+
+impl VarType {
+    /// the name the variable is declared with
+    pub fn get_name(&self) -> &Ident {
+        match self {
+            VarType::Var(x) => &x.name,
+            VarType::Const(x) => &x.name,
+            VarType::Arg(x) => &x.name,
+        }
+    }
+}
+
 impl AsRef<Span> for VarType {
     fn as_ref(&self) -> &Span {
         match self {
-            &VarType::Var(ref x) => x.as_ref(),
-            &VarType::Const(ref x) => x.as_ref(),
+            VarType::Var(x) => x.as_ref(),
+            VarType::Const(x) => x.as_ref(),
+            VarType::Arg(x) => x.as_ref(),
         }
     }
 }
@@ -62,25 +88,207 @@ impl From<CompositionalFunction> for FuncType {
 impl AsRef<Span> for FuncType {
     fn as_ref(&self) -> &Span {
         match self {
-            &FuncType::Normal(ref x) => x.as_ref(),
-            &FuncType::Comp(ref x) => x.as_ref(),
+            FuncType::Normal(x) => x.as_ref(),
+            FuncType::Comp(x) => x.as_ref(),
         }
     }
 }
 
 impl Spanner for FuncType { }
 
+impl FuncType {
+    /// the name the function is declared with
+    pub fn get_name(&self) -> &Ident {
+        match self {
+            FuncType::Normal(x) => &x.name,
+            FuncType::Comp(x) => &x.name,
+        }
+    }
+}
+
+/// A variable in scope, with the type its value was inferred to have
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Binding {
+    pub var: VarType,
+    pub kind: TypeData,
+}
+
+impl AsRef<Span> for Binding {
+    fn as_ref(&self) -> &Span {
+        self.var.as_ref()
+    }
+}
+
+impl Spanner for Binding { }
+
+/// Scope is every name declared directly within one scope
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Hash, Ord, Serialize, Deserialize)]
+struct Scope {
+    vars: BTreeMap<Hash,Binding>,
+    funcs: BTreeMap<Hash,FuncType>,
+}
+
+/// Namespace is every name visible at some point of the program.
+///
+/// Scopes nest lexically, the top level of the program (shared by
+/// every file) is the outermost, and each function body & block
+/// introduces a new innermost scope. The shadowing rules are:
+///
+/// * A name may only be declared once within a scope.
+/// * A scope may shadow any name declared by an enclosing scope.
+/// * Variables and functions are separate, a variable and a function
+///   may share a name.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Hash, Ord, Serialize, Deserialize)]
 pub struct Namespace {
-    vars: BTreeMap<Hash,VarType>,
-    funcs: BTreeMap<Hash,FuncType>,
+    // innermost scope is last, the top level is never left.
+    scopes: Vec<Scope>,
+    // functions whose bodies are being typed at a call site, see `expand`
+    expanding: BTreeSet<Hash>,
+    // arms of an `if` found to never be taken while typing
+    impossible: BTreeSet<ImpossibleBranch>,
+}
+
+impl Default for Namespace {
+    fn default() -> Self {
+        Self {
+            scopes: vec![Scope::default()],
+            expanding: BTreeSet::new(),
+            impossible: BTreeSet::new(),
+        }
+    }
 }
 
 impl Namespace {
 
-    // TODO: Add var
+    /// Builds the namespace shared by every file of a program,
+    /// the functions & compositional functions each file declares.
+    ///
+    /// A name declared in two files is a conflict, just like a name
+    /// declared twice in one file. An imported file may not declare
+    /// top level variables, they'd leak into the importing file.
+    pub fn from_program<E>(program: &Program) -> Result<Self, Vec<E>>
+    where
+        E: ValidationErrors,
+    {
+        let mut namespace = Self::default();
+        let mut errors = Vec::new();
+        let root = program.root().id;
+        for file in program.files.iter() {
+            for statement in file.body.body.iter() {
+                let result = match (statement.get_func(), statement.get_comp_func(), statement.get_dec()) {
+                    (Option::Some(func), _, _) => namespace.add_func::<_,E>(func),
+                    (_, Option::Some(comp), _) => namespace.add_func::<_,E>(comp),
+                    (_, _, Option::Some(var)) if file.id != root => Err(E::imported_var(var, var.name.get_span())),
+                    _ => Ok(()),
+                };
+                if let Err(e) = result {
+                    errors.push(e);
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(namespace)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// look up a function by name, the innermost declaration wins
+    pub fn get_func(&self, name: &Ident) -> Option<&FuncType> {
+        let hash = Hash::from(name);
+        self.scopes.iter().rev().find_map(|scope| scope.funcs.get(&hash))
+    }
+
+    /// look up a top level function by name, as seen from a function body
+    pub fn get_top_func(&self, name: &Ident) -> Option<&FuncType> {
+        self.scopes[0].funcs.get(&Hash::from(name))
+    }
+
+    /// look up a top level variable by name, as seen from a function body
+    pub fn get_top_var(&self, name: &Ident) -> Option<&Binding> {
+        self.scopes[0].vars.get(&Hash::from(name))
+    }
+
+    /// starts a new (innermost) scope, such as the body of a block
+    pub fn enter_scope(&mut self) {
+        self.scopes.push(Scope::default());
+    }
+
+    /// discards the innermost scope, and every name declared within it
+    pub fn leave_scope(&mut self) {
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+    }
+
+    /// declares a variable (or function argument) within the innermost scope.
+    pub fn add_var<'a,T,E>(&mut self, var: &'a T, kind: TypeData) -> Result<(),E>
+    where
+        T: 'static + Clone,
+        E: ValidationErrors,
+        VarType: From<T>,
+        Hash: From<&'a T>,
+    {
+        let hash = Hash::from(var);
+        let var = VarType::from(<T as Clone>::clone(var));
+        let scope = self.scopes.last_mut().unwrap();
+        if let Option::Some(old) = scope.vars.get(&hash) {
+            return Err(E::var_conflict(&var, old));
+        }
+        scope.vars.insert(hash, Binding { var, kind });
+        Ok(())
+    }
+
+    /// look up a variable by name, the innermost declaration wins
+    pub fn get_var(&self, name: &Ident) -> Option<&Binding> {
+        let hash = Hash::from(name);
+        self.scopes.iter().rev().find_map(|scope| scope.vars.get(&hash))
+    }
+
+    /// Gives the visible variable a narrower type within the innermost
+    /// scope, such as within a branch of an `if` which compared it.
+    pub fn narrow(&mut self, var: &Hash, kind: TypeData) {
+        let binding = self.scopes.iter().rev().find_map(|scope| scope.vars.get(var));
+        let binding = match binding {
+            Option::Some(binding) => Binding { var: binding.var.clone(), kind },
+            Option::None => return,
+        };
+        self.scopes.last_mut().unwrap().vars.insert(var.clone(), binding);
+    }
+
+    /// records an arm of an `if` which is never taken, the body of a
+    /// function typed at a call site (see `expand`) records nothing.
+    pub fn add_impossible_branch(&mut self, branch: ImpossibleBranch) {
+        self.impossible.insert(branch);
+    }
+
+    /// every arm recorded by `add_impossible_branch`, in source order
+    pub fn take_impossible_branches(&mut self) -> Vec<ImpossibleBranch> {
+        std::mem::take(&mut self.impossible).into_iter().collect()
+    }
+
+    /// The namespace seen by the body of the top level function `name`,
+    /// so it may be typed at a call site with narrower arguments.
+    ///
+    /// returns `None` if `name` isn't a top level function, or if its
+    /// body is already being expanded (it is recursive).
+    pub fn expand(&self, name: &Ident) -> Option<Namespace> {
+        let hash = Hash::from(name);
+        let func = self.scopes[0].funcs.get(&hash)?;
+        if self.get_func(name) != Some(func) || self.expanding.contains(&hash) {
+            return None;
+        }
+        let mut expanding = self.expanding.clone();
+        expanding.insert(hash);
+        Some(Namespace {
+            scopes: vec![self.scopes[0].clone()],
+            expanding,
+            impossible: BTreeSet::new(),
+        })
+    }
 
-    fn add_func<'a, T,E>(&mut self, arg: &'a T) -> Result<(),E>
+    /// declares a function within the innermost scope
+    pub fn add_func<'a,T,E>(&mut self, arg: &'a T) -> Result<(),E>
     where
         T: 'static + Clone,
         E: ValidationErrors,
@@ -89,14 +297,98 @@ impl Namespace {
     {
         let hash = Hash::from(arg);
         let data = FuncType::from(<T as Clone>::clone(arg));
-        match self.funcs.get(&hash) {
-            Option::Some(def) => {
-                return Err(E::func_conflict(&data, def));
-            },
-            _ => { }
-        };
-        self.funcs.insert(hash, data); 
+        let scope = self.scopes.last_mut().unwrap();
+        if let Option::Some(def) = scope.funcs.get(&hash) {
+            return Err(E::func_conflict(&data, def));
+        }
+        scope.funcs.insert(hash, data);
         Ok(())
     }
 }
 
+
+#[test]
+fn functions_from_every_file_are_merged() {
+    use crate::internals::{
+        errors::term_errors::HumanReadable,
+        parser::{
+            ast::statement::StateTrait,
+            loader::{load_program, scratch_dir},
+            span::Spanner,
+        },
+    };
+
+    let dir = scratch_dir("namespace");
+    std::fs::write(dir.join("lib.fx"), "fn bonus() int { 2 }\ncm total(0, x, +) int;").unwrap();
+    std::fs::write(dir.join("main.fx"), "import \"lib.fx\";\nfn hit(x: int) bool { x > 3 }\nhit(d6)").unwrap();
+
+    let program = load_program::<HumanReadable>(&dir.join("main.fx")).ok().unwrap();
+    let namespace = Namespace::from_program::<HumanReadable>(&program).ok().unwrap();
+    assert_eq!(namespace.scopes[0].funcs.len(), 3);
+    let hit = program.root().body.body[1].get_func().unwrap();
+    assert_eq!(namespace.get_func(&hit.name).unwrap().get_file_id(), program.root().id);
+
+    // redeclaring an imported function is a conflict
+    std::fs::write(dir.join("main.fx"), "import \"lib.fx\";\nfn bonus() int { 3 }\nbonus()").unwrap();
+    let program = load_program::<HumanReadable>(&dir.join("main.fx")).ok().unwrap();
+    assert_eq!(Namespace::from_program::<HumanReadable>(&program).err().unwrap().len(), 1);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn imported_files_only_export_functions() {
+    use crate::internals::{
+        errors::term_errors::{render_for_test, HumanReadable},
+        parser::loader::{load_program, scratch_dir},
+    };
+
+    let dir = scratch_dir("exports");
+    std::fs::write(dir.join("lib.fx"), "let secret = 5;\nfn bonus() int { 2 }").unwrap();
+    let errors = |main: &str| {
+        std::fs::write(dir.join("main.fx"), main).unwrap();
+        let program = load_program::<HumanReadable>(&dir.join("main.fx")).ok().unwrap();
+        render_for_test(Namespace::from_program::<HumanReadable>(&program).err().unwrap())
+    };
+
+    // an imported variable can't be read
+    let read = errors("import \"lib.fx\";\nsecret + bonus()");
+    assert_eq!(read.len(), 1, "{:?}", read);
+    assert!(read[0].contains("`secret` is declared at the top level of an imported file"), "{}", read[0]);
+    assert!(read[0].contains("lib.fx:1:1"), "{}", read[0]);
+
+    // nor does it conflict with a variable of the importing file
+    let redeclared = errors("import \"lib.fx\";\nlet secret = 1;\nsecret + bonus()");
+    assert_eq!(redeclared.len(), 1, "{:?}", redeclared);
+    assert!(!redeclared[0].contains("already defined"), "{}", redeclared[0]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn inner_scopes_shadow_outer_scopes() {
+    use crate::internals::{
+        canonization::kinds::primative::Prim,
+        errors::term_errors::HumanReadable,
+        parser::{ast::statement::StateTrait, generated::parse_code},
+    };
+
+    let body = parse_code::<HumanReadable>("let a = 1;\nlet a = 2;\nlet b = 3;").unwrap();
+    let decs = body.body.iter().map(|s| s.get_dec().unwrap()).collect::<Vec<_>>();
+    let int = || TypeData::from(Prim::new_idk_int());
+
+    let mut namespace = Namespace::default();
+    namespace.add_var::<_,HumanReadable>(decs[0], int()).unwrap();
+    assert!(namespace.add_var::<_,HumanReadable>(decs[1], int()).is_err());
+
+    namespace.enter_scope();
+    namespace.add_var::<_,HumanReadable>(decs[1], int()).unwrap();
+    namespace.add_var::<_,HumanReadable>(decs[2], int()).unwrap();
+    assert_eq!(namespace.get_var(&decs[0].name).unwrap().var, VarType::from(decs[1].clone()));
+    namespace.leave_scope();
+
+    assert_eq!(namespace.get_var(&decs[0].name).unwrap().var, VarType::from(decs[0].clone()));
+    assert!(namespace.get_var(&decs[2].name).is_none());
+
+    // the top level scope is never left
+    namespace.leave_scope();
+    assert!(namespace.get_var(&decs[0].name).is_some());
+}
@@ -0,0 +1,100 @@
+//! Analysis
+//!
+//! Collects the outputs a program reports. Every `analyze name = expr;`
+//! statement is an output, as is the trailing expression of the program
+//! (if there is one).
+
+use crate::internals::{
+    canonization::to_ast::{
+        namespace::Namespace,
+        partial_ast::phase1::expr::{to_p1_expr, P1Expression},
+        template_stuff::TemplateDefinations,
+        validation_errors::ValidationErrors,
+    },
+    parser::ast::{ident::Ident, statement::{Body, StateTrait}},
+};
+
+/// Output is a single reported result of the program.
+pub struct Output {
+    /// `None` for the trailing expression of the program
+    pub name: Option<Ident>,
+
+    pub expr: P1Expression,
+}
+
+/// Lowers every output of the program, in source order. Top level
+/// declarations must already be typed within `ns` (see `type_declarations`).
+///
+/// Outputs are independent, so an error in one does not stop the others
+/// from being checked. All errors are returned together.
+pub fn collect_outputs<E>(
+    body: &Body,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace,
+) -> Result<Vec<Output>, Vec<E>>
+where
+    E: ValidationErrors,
+{
+    let mut outputs = Vec::new();
+    let mut errors = Vec::new();
+    for (name, expr) in lower_outputs::<E>(body, t_def, ns) {
+        match expr {
+            Ok(expr) => outputs.push(Output { name, expr }),
+            Err(e) => errors.push(e),
+        };
+    }
+    if errors.is_empty() {
+        Ok(outputs)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Lowers every output of the program, in source order, keeping the
+/// result of each output next to its name.
+pub fn lower_outputs<E>(
+    body: &Body,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace,
+) -> Vec<(Option<Ident>, Result<P1Expression, E>)>
+where
+    E: ValidationErrors,
+{
+    let mut outputs = Vec::new();
+    for statement in body.body.iter() {
+        let (name, expr) = match (statement.get_analysis(), statement.get_term()) {
+            (Option::Some(analyze), _) => (Some(analyze.name.as_ref().clone()), analyze.expr.as_ref()),
+            (_, Option::Some(term)) => (None, term),
+            _ => continue,
+        };
+        outputs.push((name, to_p1_expr::<E>(expr, t_def, ns)));
+    }
+    outputs
+}
+
+#[test]
+fn every_output_is_reported() {
+    use crate::internals::{
+        canonization::kinds::{primative::Prim, workable::TypeDataTrait},
+        errors::term_errors::HumanReadable,
+        parser::{generated::parse_code, span::Spanner},
+    };
+
+    let source = "analyze hit = d6 >= 4;\nanalyze damage = 2d6 + 1;\n-d6";
+    let body = parse_code::<HumanReadable>(source).unwrap();
+    let mut t_def = TemplateDefinations::test_constructor(Vec::<(String,usize)>::new());
+    let outputs = collect_outputs::<HumanReadable>(&body, &mut t_def, &mut Namespace::default()).ok().unwrap();
+
+    let names = outputs.iter()
+        .map(|o| o.name.as_ref().map(|n| n.get_span().to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec![Some("hit".to_string()), Some("damage".to_string()), None]);
+    assert_eq!(outputs[0].expr.get_prim(), Some(&Prim::new_boolean()));
+    assert_eq!(outputs[1].expr.get_prim(), Some(&Prim::new_int_dynamic(13, 3, None)));
+    assert_eq!(outputs[2].expr.get_prim(), Some(&Prim::new_int_dynamic(-1, -6, None)));
+
+    // a program made only of statements has no trailing output
+    let body = parse_code::<HumanReadable>("analyze x = d0;\nanalyze y = (1 == 1) + 1;").unwrap();
+    let errors = collect_outputs::<HumanReadable>(&body, &mut t_def, &mut Namespace::default()).err().unwrap();
+    assert_eq!(errors.len(), 2);
+}
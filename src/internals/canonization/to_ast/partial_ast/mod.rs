@@ -18,3 +18,8 @@
 //! 5. Execution
 
 mod phase1;
+pub mod phase2;
+
+pub use self::phase1::narrowing::ImpossibleBranch;
+
+pub mod analysis;
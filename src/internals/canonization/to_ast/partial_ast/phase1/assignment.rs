@@ -2,15 +2,22 @@ use serde::{Deserialize, Serialize};
 
 use crate::internals::{
     parser::{
+        ast::assign::Assign,
         span::{Span,Spanner},
     },
     canonization::{
         to_ast::{
+            identifier::Hash,
+            namespace::Namespace,
+            template_stuff::TemplateDefinations,
             validation_errors::ValidationErrors,
-            partial_ast::phase1::expr::P1Expression,
+            partial_ast::phase1::{
+                constant::{fold_const, rolled},
+                expr::{fits,to_p1_expr,P1Expression},
+            },
         },
         kinds::{ workable::{TypeDataTrait,TypeData} },
-        
+
     },
 };
 
@@ -22,4 +29,42 @@ pub struct P1Assign {
     pub expr: P1Expression,
 }
 
-
+/// Types an assignment, then declares it within the innermost scope.
+///
+/// The value must fit the annotation (if there is one), the variable
+/// keeps the inferred type as it is never less precise. The value of a
+/// `const` must be known (see `constant`), it replaces the expression.
+pub(in crate::internals::canonization) fn to_p1_assign<E>(
+    assign: &Assign,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Assign,E>
+where
+    E: ValidationErrors,
+{
+    let expr = to_p1_expr(&assign.expr, t_def, ns)?;
+    let kind: TypeData = expr.get_copy();
+    if assign.kind.is_some() {
+        let expected = TypeData::from(&assign.kind);
+        if !fits(&kind, &expected) {
+            return Err(E::annotation_mismatch(&expr, &kind, assign, &expected));
+        }
+    }
+    let expr = if assign.is_constant {
+        if let Option::Some((usage, rolled)) = rolled(&assign.expr, ns) {
+            return Err(E::rolled_in_const(usage, &rolled, assign));
+        }
+        match fold_const(&expr) {
+            Ok(value) => value,
+            Err(unknown) => return Err(E::not_constant(unknown, &unknown.get_copy(), assign)),
+        }
+    } else {
+        expr
+    };
+    ns.add_var::<_,E>(assign, kind.clone())?;
+    Ok(P1Assign {
+        span: Box::new(assign.get_clone()),
+        identifier: Hash::from(assign),
+        kind: Box::new(kind),
+        expr,
+    })
+}
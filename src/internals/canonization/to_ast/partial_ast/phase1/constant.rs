@@ -0,0 +1,247 @@
+//! Constants
+//!
+//! A `const` must be known before any dice are rolled. Its initialiser
+//! may not roll dice or read a variable (or argument) which isn't a
+//! `const`, nor may the functions it invokes. Typing folds templates,
+//! arithmetic and calls with constant arguments, so what remains must
+//! then have a type holding a single value, or be a collection of them.
+//!
+//! The value of a `const` replaces its initialiser, and every read of it.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::internals::{
+    canonization::{
+        kinds::{
+            primative::{BooleanTrait, IntegerTrait, Prim, PrimativeTrait},
+            workable::{TypeData, TypeDataTrait},
+        },
+        to_ast::{
+            identifier::Hash,
+            namespace::{FuncType, Namespace, VarType},
+            partial_ast::phase1::expr::{P1Expr, P1Expression},
+        },
+    },
+    parser::{
+        ast::{
+            comparg::CompositionalArg,
+            expr::{Expr, Expression},
+            func::FunctionDec,
+            ident::Ident,
+            statement::{State, Statement},
+        },
+        span::{Span, Spanner},
+    },
+};
+
+/// Finds the first part of a `const` initialiser which is only known
+/// once dice are rolled, along with the dice roll or the declaration of
+/// the variable responsible (which may be within an invoked function).
+pub(in crate::internals::canonization) fn rolled<'a>(
+    expr: &'a Expression,
+    ns: &'a Namespace,
+) -> Option<(&'a Expression, Span)> {
+    let mut walk = Rolled {
+        ns,
+        scopes: vec![Locals::default()],
+        seen: BTreeSet::new(),
+        depth: 0,
+    };
+    walk.expr(expr)
+}
+
+/// the variables & functions declared by one scope of the initialiser,
+/// or of a function body.
+#[derive(Default)]
+struct Locals<'a> {
+    vars: BTreeSet<Hash>,
+    funcs: BTreeMap<Hash, &'a FunctionDec>,
+}
+
+struct Rolled<'a> {
+    ns: &'a Namespace,
+    scopes: Vec<Locals<'a>>,
+    // functions already searched
+    seen: BTreeSet<Hash>,
+    // how many function bodies deep the search is
+    depth: usize,
+}
+
+impl<'a> Rolled<'a> {
+    fn expr(&mut self, e: &'a Expression) -> Option<(&'a Expression, Span)> {
+        match e.kind.as_ref() {
+            Expr::Dice(_) => Some((e, e.get_clone())),
+            Expr::Var(ident) => self.var(ident).map(|def| (e, def)),
+            Expr::Invoke(invoke) => {
+                let args = invoke.args.iter().find_map(|arg| self.expr(arg));
+                args.or_else(|| self.call(&invoke.name).map(|def| (e, def)))
+            }
+            Expr::Op(op) => self.expr(&op.left).or_else(|| self.expr(&op.right)),
+            Expr::Unary(unary) => self.expr(&unary.expr),
+            Expr::Parens(inner) => self.expr(inner),
+            Expr::Cond(cond) => self
+                .expr(&cond.condition)
+                .or_else(|| self.expr(&cond.true_case))
+                .or_else(|| self.expr(&cond.false_case)),
+            Expr::Match(m) => self
+                .expr(&m.scrutinee)
+                .or_else(|| m.arms.iter().find_map(|arm| self.expr(&arm.expr))),
+            Expr::Block(block) => {
+                self.scopes.push(Locals::default());
+                let found = self.statements(&block.body);
+                self.scopes.pop();
+                found
+            }
+            Expr::Coll(coll) => coll.items.iter().find_map(|item| self.expr(item)),
+            Expr::Index(index) => self.expr(&index.collection).or_else(|| self.expr(&index.index)),
+            Expr::Len(len) => self.expr(&len.collection),
+            Expr::Num(_) | Expr::Template(_) => None,
+        }
+    }
+
+    /// statements within the innermost scope, a function is visible
+    /// throughout the scope which declares it.
+    fn statements(&mut self, statements: &'a [Statement]) -> Option<(&'a Expression, Span)> {
+        for statement in statements {
+            if let State::Func(func) = statement.sttm.as_ref() {
+                let scope = self.scopes.last_mut().unwrap();
+                scope.funcs.insert(Hash::from(&func.name), func.as_ref());
+            }
+        }
+        for statement in statements {
+            match statement.sttm.as_ref() {
+                State::Declaration(assign) => {
+                    if let Option::Some(found) = self.expr(&assign.expr) {
+                        return Some(found);
+                    }
+                    self.scopes.last_mut().unwrap().vars.insert(Hash::from(assign.as_ref()));
+                }
+                State::Termination(expr) => return self.expr(expr),
+                _ => {}
+            };
+        }
+        None
+    }
+
+    /// the declaration of a variable which isn't a `const`
+    fn var(&self, ident: &Ident) -> Option<Span> {
+        let hash = Hash::from(ident);
+        if self.scopes.iter().any(|scope| scope.vars.contains(&hash)) {
+            return None;
+        }
+        // a function body only sees the top level
+        let binding = if self.depth > 0 {
+            self.ns.get_top_var(ident)
+        } else {
+            self.ns.get_var(ident)
+        };
+        match binding.map(|binding| &binding.var) {
+            Option::Some(VarType::Var(assign)) => Some(assign.get_clone()),
+            Option::Some(VarType::Arg(arg)) => Some(arg.get_clone()),
+            Option::Some(&VarType::Const(_)) | Option::None => None,
+        }
+    }
+
+    /// searches the body of an invoked function, or the functions a
+    /// compositional function applies.
+    fn call(&mut self, ident: &Ident) -> Option<Span> {
+        let hash = Hash::from(ident);
+        if !self.seen.insert(hash.clone()) {
+            return None;
+        }
+        if let Option::Some(func) = self.scopes.iter().rev().find_map(|scope| scope.funcs.get(&hash)) {
+            return self.func(func);
+        }
+        let func = if self.depth > 0 {
+            self.ns.get_top_func(ident)
+        } else {
+            self.ns.get_func(ident)
+        };
+        // nothing declared where it's invoked is visible to its body
+        let scopes = std::mem::take(&mut self.scopes);
+        self.depth += 1;
+        let found = match func {
+            Option::Some(FuncType::Normal(dec)) => self.func(dec),
+            Option::Some(FuncType::Comp(comp)) => {
+                let args = [&comp.null_arg, &comp.single_arg, &comp.collection_arg];
+                args.iter().find_map(|arg| match &arg.arg {
+                    CompositionalArg::Func(ident) => self.call(ident),
+                    _ => None,
+                })
+            }
+            Option::None => None,
+        };
+        self.depth -= 1;
+        self.scopes = scopes;
+        found
+    }
+
+    fn func(&mut self, dec: &'a FunctionDec) -> Option<Span> {
+        let mut locals = Locals::default();
+        locals.vars.extend(dec.args.iter().map(Hash::from));
+        self.scopes.push(locals);
+        let found = self.statements(&dec.body);
+        self.scopes.pop();
+        found.map(|(_, def)| def)
+    }
+}
+
+/// the literal for a primative with a single value
+pub(in crate::internals::canonization) fn literal(prim: &Prim) -> Option<P1Expr> {
+    match (prim.get_int(), prim.get_bool()) {
+        (Option::Some(int), _) => int.get_constant().map(P1Expr::Value),
+        (_, Option::Some(b)) => b.get_constant().map(P1Expr::Bool),
+        _ => None,
+    }
+}
+
+/// does `kind` hold a single value
+pub(in crate::internals::canonization) fn is_constant(kind: &TypeData) -> bool {
+    kind.get_prim().and_then(literal).is_some()
+}
+
+/// Replaces `expr` with its value.
+///
+/// returns the innermost part of `expr` which isn't known, if there is one.
+pub(in crate::internals::canonization) fn fold_const(expr: &P1Expression) -> Result<P1Expression, &P1Expression> {
+    if let Option::Some(value) = expr.get_prim().and_then(literal) {
+        return Ok(P1Expression {
+            span: expr.span.clone(),
+            kind: expr.kind.clone(),
+            expr: value,
+        });
+    }
+    match &expr.expr {
+        P1Expr::Parens(inner) => fold_const(inner),
+        P1Expr::Collection(items) => {
+            let items = items.iter().map(fold_const).collect::<Result<Vec<_>, _>>()?;
+            Ok(P1Expression {
+                span: expr.span.clone(),
+                kind: expr.kind.clone(),
+                expr: P1Expr::Collection(items),
+            })
+        }
+        _ => Err(unknown(expr)),
+    }
+}
+
+/// the innermost part of `expr` which isn't known
+fn unknown(expr: &P1Expression) -> &P1Expression {
+    let children: Vec<&P1Expression> = match &expr.expr {
+        P1Expr::Value(_) | P1Expr::Bool(_) | P1Expr::Dice(_, _) | P1Expr::Var(_) => vec![],
+        P1Expr::Parens(x) | P1Expr::Unary(_, x) | P1Expr::Len(x) => vec![x],
+        P1Expr::Op(l, _, r) | P1Expr::Index(l, r) => vec![l, r],
+        P1Expr::Collection(items) | P1Expr::Invoke(_, items) => items.iter().collect(),
+        P1Expr::Match(scrutinee, arms) => {
+            Some(scrutinee.as_ref()).into_iter().chain(arms.iter().map(|arm| &arm.expr)).collect()
+        }
+        P1Expr::Block(assigns, term) => {
+            assigns.iter().map(|assign| &assign.expr).chain(Some(term.as_ref())).collect()
+        }
+        P1Expr::Cond(condition, true_case, false_case) => vec![condition, true_case, false_case],
+    };
+    match children.into_iter().find(|child| fold_const(child).is_err()) {
+        Option::Some(child) => unknown(child),
+        Option::None => expr,
+    }
+}
@@ -1,21 +1,45 @@
+use std::convert::TryFrom;
+
 use serde::{Deserialize, Serialize};
 
 use crate::internals::{
     parser::{
         ast::{
+            block::Block,
+            comparg::{CompositionalArg,CompositionalArgTrait,CompositionalFunction},
+            condition::Conditional,
             expr::{Expression,Expr},
-            template::Template,
+            func::FunctionDec,
+            ident::Ident,
+            invoke::Invoke,
+            matching::{Match,Pattern,PatternKind},
+            op::{Op,UnaryOp},
+            statement::{State,StateTrait,Statement},
         },
         span::{Span,Spanner},
     },
     canonization::{
         to_ast::{
+            identifier::Hash,
+            namespace::{FuncType,Namespace,VarType},
+            partial_ast::phase1::{
+                assignment::{to_p1_assign,P1Assign},
+                constant::{is_constant,literal},
+                narrowing::{narrow, ImpossibleBranch},
+            },
             template_stuff::TemplateDefinations,
             validation_errors::ValidationErrors,
         },
         kinds::{
             workable::{TypeDataTrait,TypeData},
-            primative::Prim,
+            primative::{BooleanTrait,OpError,Prim,PrimativeTrait,fold_op,trinary_op,unary_op},
+            collection::{Collection,CollectionTrait},
+            Compositional,
+            CompositionalTrait,
+            Function,
+            FunctionTrait,
+            Integer,
+            IntegerTrait,
         },
     }
 };
@@ -47,7 +71,28 @@ impl P1Expression {
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum P1Expr {
     Value(i64),
+    Bool(bool),
+    Dice(i64,i64),
     Parens(Box<P1Expression>),
+    Unary(UnaryOp,Box<P1Expression>),
+    Op(Box<P1Expression>,Op,Box<P1Expression>),
+    Collection(Vec<P1Expression>),
+    Index(Box<P1Expression>,Box<P1Expression>),
+    Len(Box<P1Expression>),
+    Match(Box<P1Expression>,Vec<P1Arm>),
+    Var(Hash),
+    Block(Vec<P1Assign>,Box<P1Expression>),
+    Invoke(Hash,Vec<P1Expression>),
+    Cond(Box<P1Expression>,Box<P1Expression>,Box<P1Expression>),
+}
+
+/// P1Arm is a single arm of a match, its pattern is reduced to an
+/// inclusive range, `None` being unbounded.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct P1Arm {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub expr: P1Expression,
 }
 
 /*
@@ -68,29 +113,706 @@ impl AsMut<TypeData> for P1Expression {
 impl TypeDataTrait for P1Expression { }
 
 
-fn to_p1_expr<E>(
+pub(in crate::internals::canonization) fn to_p1_expr<E>(
     e: &Expression,
-    t_def: &mut TemplateDefinations) -> Result<P1Expression,E>
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Expression,E>
 where
     E: ValidationErrors,
 {
     match e.kind.as_ref() {
-        &Expr::Template(ref template) => {
-            let x = t_def.get_value::<E>(template)?;
-            Ok(P1Expression::new( e, Prim::new_int_constant(x as i64), P1Expr::Value(x as i64)))
+        Expr::Template(template) => {
+            let x = match i64::try_from(t_def.get_value::<E>(template)?) {
+                Ok(x) => x,
+                Err(_) => return Err(E::integer_overflow(template.as_ref())),
+            };
+            Ok(P1Expression::new( e, Prim::new_int_constant(x), P1Expr::Value(x)))
         },
-        &Expr::Num(ref span) => {
-            let x = match i64::from_str_radix(span.get_span(), 10) {
+        Expr::Num(span) => {
+            let x = match span.get_span().parse::<i64>() {
                 Ok(x) => x,
                 Err(_) => return Err(E::malformed_int(span.as_ref(), e))
             };
-            Ok(P1Expression::new(e, Prim::new_int_constant(x as i64), P1Expr::Value(x as i64)))
+            Ok(P1Expression::new(e, Prim::new_int_constant(x), P1Expr::Value(x)))
         },
-        &Expr::Parens(ref expression) => {
-            let inner = to_p1_expr(expression, t_def)?;
+        Expr::Dice(dice) => {
+            let count = match dice.count {
+                Option::None => 1,
+                Option::Some(ref count) => match count.get_span().parse::<i64>() {
+                    Ok(x) => x,
+                    Err(_) => return Err(E::malformed_int(count.as_ref(), e)),
+                },
+            };
+            let sides = match dice.sides.get_span().parse::<i64>() {
+                Ok(x) => x,
+                Err(_) => return Err(E::malformed_int(dice.sides.as_ref(), e)),
+            };
+            if sides == 0 {
+                return Err(E::dice_without_sides(dice.sides.as_ref(), e));
+            }
+            // every die rolls at least a 1, at most its side count
+            let kind = Integer::new(count.checked_mul(sides), count, None);
+            Ok(P1Expression::new(e, Prim::from(kind), P1Expr::Dice(count, sides)))
+        }
+        Expr::Unary(unary) => {
+            let arg = to_p1_expr(&unary.expr, t_def, ns)?;
+            let kind = match arg.get_prim().map(|prim| unary_op(prim, unary.op)) {
+                Option::Some(Ok(kind)) => kind,
+                Option::Some(Err(OpError::Overflow)) => return Err(E::integer_overflow(unary.as_ref())),
+                _ => return Err(E::unary_op_type_error(&unary.op, &arg)),
+            };
+            Ok(P1Expression::new(e, kind, P1Expr::Unary(unary.op, Box::new(arg))))
+        }
+        Expr::Op(operation) => {
+            let left = to_p1_expr(&operation.left, t_def, ns)?;
+            let right = to_p1_expr(&operation.right, t_def, ns)?;
+            let kind = match (left.get_prim(), right.get_prim()) {
+                (Option::Some(l), Option::Some(r)) => trinary_op(l, operation.op, r),
+                _ => Err(OpError::Invalid),
+            };
+            let kind = match kind {
+                Ok(kind) => kind,
+                Err(OpError::Overflow) => return Err(E::integer_overflow(operation.as_ref())),
+                Err(OpError::Invalid) => return Err(E::trinary_op_type_error(&left, &operation.op, &right)),
+            };
+            Ok(P1Expression::new(e, kind, P1Expr::Op(Box::new(left), operation.op, Box::new(right))))
+        }
+        Expr::Parens(expression) => {
+            let inner = to_p1_expr(expression, t_def, ns)?;
             let data: TypeData = inner.get_copy();
             Ok(P1Expression::new(e, data, P1Expr::Parens(Box::new(inner))))
         }
-        _ => panic!()
+        Expr::Coll(coll) => {
+            let items = coll.items.iter()
+                .map(|item| to_p1_expr(item, t_def, ns))
+                .collect::<Result<Vec<P1Expression>,E>>()?;
+
+            // the interior type must contain every element
+            let mut interior: Option<Prim> = None;
+            for item in items.iter() {
+                interior = match (interior, item.get_prim()) {
+                    (Option::None, Option::Some(prim)) => Some(*prim),
+                    (Option::Some(acc), Option::Some(prim)) => acc.union(prim),
+                    (_, Option::None) => None,
+                };
+                if interior.is_none() {
+                    return Err(E::collection_element_error(coll.as_ref(), item));
+                }
+            }
+            let len = items.len() as i64;
+            let kind = Collection::new(interior.unwrap(), len, len, len);
+            Ok(P1Expression::new(e, kind, P1Expr::Collection(items)))
+        }
+        Expr::Index(index) => {
+            let coll = to_p1_expr(&index.collection, t_def, ns)?;
+            let idx = to_p1_expr(&index.index, t_def, ns)?;
+            let (interior, len) = match coll.get_coll() {
+                Option::Some(c) => (*c.get_interior(), *c.len()),
+                Option::None => return Err(E::expected_collection(&coll)),
+            };
+            let range = match idx.get_prim().and_then(|p| p.get_int()) {
+                Option::Some(int) => *int,
+                Option::None => return Err(E::expected_integer(&idx)),
+            };
+
+            // only reject indexes which are out of bounds for every possible length
+            let below = range.get_maximum().map(|max| max < 0).unwrap_or(false);
+            let above = range.get_minimum().into_iter()
+                .zip(len.get_maximum())
+                .any(|(min, len)| min >= len);
+            if below || above {
+                return Err(E::index_out_of_bounds(&idx, &range, &coll, &len));
+            }
+            Ok(P1Expression::new(e, interior, P1Expr::Index(Box::new(coll), Box::new(idx))))
+        }
+        Expr::Len(length) => {
+            let coll = to_p1_expr(&length.collection, t_def, ns)?;
+            let len = match coll.get_coll() {
+                Option::Some(c) => *c.len(),
+                Option::None => return Err(E::expected_collection(&coll)),
+            };
+            Ok(P1Expression::new(e, Prim::from(len), P1Expr::Len(Box::new(coll))))
+        }
+        Expr::Match(m) => to_p1_match(e, m, t_def, ns),
+        Expr::Block(block) => {
+            ns.enter_scope();
+            let block = to_p1_block(e, block, t_def, ns);
+            ns.leave_scope();
+            block
+        }
+        Expr::Var(ident) => match ns.get_var(ident) {
+            // a declaration which failed to type check
+            Option::Some(binding) if binding.kind.is_none() => Err(E::no_type_information(e)),
+            Option::Some(binding) => {
+                let kind = binding.kind.clone();
+                // a `const` is replaced by its value
+                let expr = match (&binding.var, kind.get_prim().and_then(literal)) {
+                    (VarType::Const(_), Option::Some(value)) => value,
+                    _ => P1Expr::Var(Hash::from(ident)),
+                };
+                Ok(P1Expression::new(e, kind, expr))
+            }
+            Option::None => Err(E::undefined_var(ident.as_ref())),
+        },
+        Expr::Invoke(invoke) => to_p1_invoke(e, invoke, t_def, ns),
+        Expr::Cond(cond) => to_p1_cond(e, cond, t_def, ns),
+    }
+}
+
+/// can a value of type `found` be used where `expected` is declared
+pub(in crate::internals::canonization) fn fits(found: &TypeData, expected: &TypeData) -> bool {
+    match (found, expected) {
+        (TypeData::Prim(f), TypeData::Prim(x)) => x.union(f).as_ref() == Some(x),
+        (TypeData::Coll(f), TypeData::Coll(x)) => {
+            let interior = x.get_interior().union(f.get_interior());
+            interior.as_ref() == Some(x.get_interior()) && x.len().union(f.len()) == *x.len()
+        }
+        _ => false,
+    }
+}
+
+/// the type of a value which is either `l` or `r`
+fn union(l: &TypeData, r: &TypeData) -> Option<TypeData> {
+    match (l.get_prim(), r.get_prim()) {
+        (Option::Some(l), Option::Some(r)) => l.union(r).map(TypeData::from),
+        _ => match (l.get_coll(), r.get_coll()) {
+            (Option::Some(l), Option::Some(r)) => l.union(r).map(TypeData::from),
+            _ if l == r => Some(l.clone()),
+            _ => None,
+        },
+    }
+}
+
+/// Types the body of a block or function, the statements are declared
+/// in the innermost scope which the caller enters.
+///
+/// A function declared within the body is visible throughout it, its
+/// own body is typed once every variable of the body is declared.
+pub(in crate::internals::canonization) fn to_p1_body<E>(
+    statements: &[Statement],
+    term: &Expression,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<(Vec<P1Assign>,P1Expression),E>
+where
+    E: ValidationErrors,
+{
+    for statement in statements {
+        match (statement.get_func(), statement.get_comp_func()) {
+            (Option::Some(func), _) => ns.add_func::<_,E>(func)?,
+            (_, Option::Some(comp)) => ns.add_func::<_,E>(comp)?,
+            _ => {}
+        };
+    }
+    let mut assigns = Vec::with_capacity(statements.len());
+    for statement in statements {
+        match statement.sttm.as_ref() {
+            State::Declaration(assign) => assigns.push(to_p1_assign(assign, t_def, ns)?),
+            State::Func(_) | State::CompFunc(_) => {}
+            _ => return Err(E::not_yet_analyzable(statement)),
+        };
+    }
+    for func in statements.iter().filter_map(|s| s.get_func()) {
+        to_p1_func(func, t_def, ns)?;
+    }
+    let term = to_p1_expr(term, t_def, ns)?;
+    Ok((assigns, term))
+}
+
+/// Types the body of a function with its arguments bound to their
+/// declared types, its value must fit the declared return type.
+pub(in crate::internals::canonization) fn to_p1_func<E>(
+    func: &FunctionDec,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<(Vec<P1Assign>,P1Expression),E>
+where
+    E: ValidationErrors,
+{
+    // arguments share the scope of the body
+    ns.enter_scope();
+    let body = to_p1_func_body(func, t_def, ns);
+    ns.leave_scope();
+    let (body, ret) = body?;
+
+    let expected = TypeData::from(&func.ret);
+    if !fits(&ret.kind, &expected) {
+        return Err(E::annotation_mismatch(&ret, &ret.get_copy(), func, &expected));
+    }
+    Ok((body, ret))
+}
+
+fn to_p1_func_body<E>(
+    func: &FunctionDec,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<(Vec<P1Assign>,P1Expression),E>
+where
+    E: ValidationErrors,
+{
+    for arg in func.args.iter() {
+        ns.add_var::<_,E>(arg, TypeData::from(arg))?;
+    }
+    let (statements, term) = func.body.split_at(func.body.len() - 1);
+    let term = match term[0].get_term() {
+        Option::Some(term) => term,
+        Option::None => return Err(E::no_type_information(func)),
+    };
+    to_p1_body(statements, term, t_def, ns)
+}
+
+// every `let` of a block is declared in the block's scope, which the caller enters
+fn to_p1_block<E>(
+    e: &Expression,
+    block: &Block,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Expression,E>
+where
+    E: ValidationErrors,
+{
+    let (assigns, term) = to_p1_body(block.statements(), block.term(), t_def, ns)?;
+    let kind: TypeData = term.get_copy();
+    Ok(P1Expression::new(e, kind, P1Expr::Block(assigns, Box::new(term))))
+}
+
+// only the signature of the function is used, its body is typed on its own
+fn to_p1_invoke<E>(
+    e: &Expression,
+    invoke: &Invoke,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Expression,E>
+where
+    E: ValidationErrors,
+{
+    let func = match ns.get_func(&invoke.name) {
+        Option::Some(func) => func.clone(),
+        Option::None => return Err(E::undefined_func(invoke.name.as_ref())),
+    };
+    // a compositional function is invoked upon a collection
+    let signature = match &func {
+        FuncType::Normal(dec) => Function::from(dec),
+        FuncType::Comp(comp) => match TypeData::from(comp.ret.as_ref()) {
+            TypeData::Prim(_) => Compositional::from(comp).get_collection().clone(),
+            _ => return Err(E::not_yet_analyzable(e)),
+        },
+    };
+    if signature.args_len() != invoke.args.len() {
+        return Err(E::wrong_arg_count(invoke, signature.args_len(), invoke.args.len(), &func));
+    }
+
+    let mut args = Vec::with_capacity(invoke.args.len());
+    for (i, arg) in invoke.args.iter().enumerate() {
+        let arg = to_p1_expr(arg, t_def, ns)?;
+        if !fits(&arg.kind, &signature[i]) {
+            let param = match &func {
+                FuncType::Normal(dec) => dec.args[i].get_clone(),
+                FuncType::Comp(comp) => comp.get_clone(),
+            };
+            return Err(E::arg_type_mismatch(&arg, &arg.kind, &param, &signature[i]));
+        }
+        args.push(arg);
+    }
+    let kind = match &func {
+        FuncType::Comp(comp) => to_p1_fold::<E>(comp, &args[0], t_def, ns)?,
+        // a call with constant arguments is evaluated
+        FuncType::Normal(dec) if args.iter().all(|arg| is_constant(&arg.kind)) => {
+            let kinds = args.iter().map(|arg| (arg, arg.get_copy())).collect();
+            to_p1_expansion::<E,_>(&invoke.name, dec, kinds, t_def, ns)?
+        }
+        FuncType::Normal(_) => Option::None,
+    };
+    let kind = kind.unwrap_or_else(|| signature.get_return().clone());
+    Ok(P1Expression::new(e, kind, P1Expr::Invoke(Hash::from(invoke.name.as_ref()), args)))
+}
+
+/// The type of folding `coll` with the compositional function `comp`,
+/// its bounds follow from the collection's length and the type of each
+/// element once it is mapped.
+///
+/// returns `None` if nothing is known beyond the declared return type.
+fn to_p1_fold<E>(
+    comp: &CompositionalFunction,
+    coll: &P1Expression,
+    t_def: &mut TemplateDefinations,
+    ns: &Namespace) -> Result<Option<TypeData>,E>
+where
+    E: ValidationErrors,
+{
+    let (coll, op) = match (coll.get_coll(), comp.collection_arg.arg.get_op()) {
+        (Option::Some(coll), Option::Some(op)) => (coll, *op),
+        _ => return Ok(None),
+    };
+    let identity = to_p1_comp_arg::<E>(&comp.null_arg.arg, Option::None, t_def, ns)?;
+    let element = to_p1_comp_arg::<E>(&comp.single_arg.arg, Some(coll.get_interior()), t_def, ns)?;
+    let kind = match (identity, element) {
+        (Option::Some(identity), Option::Some(element)) => match fold_op(&identity, op, &element, coll.len()) {
+            Ok(prim) => TypeData::from(prim),
+            Err(_) => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    if fits(&kind, &TypeData::from(comp.ret.as_ref())) {
+        Ok(Some(kind))
+    } else {
+        Ok(None)
+    }
+}
+
+/// The type of an argument of a compositional function, a function is
+/// applied to `element` (the identity has no argument).
+fn to_p1_comp_arg<E>(
+    arg: &CompositionalArg,
+    element: Option<&Prim>,
+    t_def: &mut TemplateDefinations,
+    ns: &Namespace) -> Result<Option<Prim>,E>
+where
+    E: ValidationErrors,
+{
+    let prim = match arg {
+        CompositionalArg::Primative(span) => match span.get_span() {
+            "true" => Some(Prim::from(true)),
+            "false" => Some(Prim::from(false)),
+            x => x.parse::<i64>().ok().map(Prim::from),
+        },
+        CompositionalArg::Template(template) => t_def
+            .get_value::<E>(template)
+            .ok()
+            .and_then(|x| i64::try_from(x).ok())
+            .map(Prim::from),
+        CompositionalArg::Func(ident) => {
+            let dec = match ns.get_func(ident) {
+                Option::Some(FuncType::Normal(dec)) => dec,
+                _ => return Ok(None),
+            };
+            let args = element.into_iter().map(|x| (ident.as_ref(), TypeData::from(*x))).collect();
+            let kind = to_p1_expansion::<E,_>(ident, dec, args, t_def, ns)?
+                .unwrap_or_else(|| Function::from(dec).get_return().clone());
+            kind.get_prim().cloned()
+        }
+        CompositionalArg::Op(_) => None,
+    };
+    Ok(prim)
+}
+
+/// The return type of the function `dec` when it is invoked with
+/// arguments of type `args`, its body is typed again with them.
+///
+/// returns `None` if nothing is known beyond the declared return type,
+/// such as for a recursive function. Each argument is paired with where
+/// it's passed, to report one which doesn't fit its parameter.
+fn to_p1_expansion<E,S>(
+    name: &Ident,
+    dec: &FunctionDec,
+    args: Vec<(&S, TypeData)>,
+    t_def: &mut TemplateDefinations,
+    ns: &Namespace) -> Result<Option<TypeData>,E>
+where
+    E: ValidationErrors,
+    S: Spanner,
+{
+    let mut body_ns = match ns.expand(name) {
+        Option::Some(body_ns) => body_ns,
+        Option::None => return Ok(None),
+    };
+    let term = match dec.body.last().and_then(|s| s.get_term()) {
+        Option::Some(term) if args.len() == dec.args.len() => term,
+        _ => return Ok(None),
+    };
+    for (param, (arg, kind)) in dec.args.iter().zip(args) {
+        let expected = TypeData::from(param);
+        if !fits(&kind, &expected) {
+            return Err(E::arg_type_mismatch(arg, &kind, param, &expected));
+        }
+        body_ns.add_var::<_,E>(param, kind)?;
+    }
+    let (_, ret) = to_p1_body::<E>(&dec.body[..dec.body.len() - 1], term, t_def, &mut body_ns)?;
+    let expected = TypeData::from(&dec.ret);
+    if !fits(&ret.kind, &expected) {
+        return Err(E::annotation_mismatch(&ret, &ret.get_copy(), dec, &expected));
+    }
+    Ok(Some(ret.get_copy()))
+}
+
+fn to_p1_cond<E>(
+    e: &Expression,
+    cond: &Conditional,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Expression,E>
+where
+    E: ValidationErrors,
+{
+    let condition = to_p1_expr(&cond.condition, t_def, ns)?;
+    let taken = match condition.get_prim().and_then(|p| p.get_bool()) {
+        Option::Some(b) => b.get_constant(),
+        Option::None => return Err(E::expected_boolean(&condition)),
+    };
+    let true_case = to_p1_arm(&cond.true_case, &condition, true, t_def, ns)?;
+    let false_case = to_p1_arm(&cond.false_case, &condition, false, t_def, ns)?;
+
+    // both arms must agree on the type of the conditional, though
+    // only an arm which can be taken contributes to its bounds
+    let kind = match union(&true_case.kind, &false_case.kind) {
+        Option::Some(kind) => match taken {
+            Option::Some(true) => true_case.get_copy(),
+            Option::Some(false) => false_case.get_copy(),
+            Option::None => kind,
+        },
+        Option::None => return Err(E::arm_type_mismatch(&true_case, &false_case)),
+    };
+    let expr = P1Expr::Cond(Box::new(condition), Box::new(true_case), Box::new(false_case));
+    Ok(P1Expression::new(e, kind, expr))
+}
+
+/// Types an arm of an `if` where `condition` has the value `outcome`,
+/// within a scope where the variables it compares are narrowed.
+///
+/// An arm which can never be taken is recorded within `ns`, it's still
+/// typed as defensive code is valid.
+fn to_p1_arm<E>(
+    arm: &Expression,
+    condition: &P1Expression,
+    outcome: bool,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Expression,E>
+where
+    E: ValidationErrors,
+{
+    let narrowing = narrow(condition, outcome);
+    if narrowing.impossible.is_some() {
+        ns.add_impossible_branch(ImpossibleBranch {
+            arm: arm.get_clone(),
+            condition: condition.get_clone(),
+            outcome,
+        });
+    }
+    ns.enter_scope();
+    for (var, kind) in narrowing.kinds {
+        ns.narrow(&var, kind);
+    }
+    let arm = to_p1_expr(arm, t_def, ns);
+    ns.leave_scope();
+    arm
+}
+
+fn to_p1_match<E>(
+    e: &Expression,
+    m: &Match,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace) -> Result<P1Expression,E>
+where
+    E: ValidationErrors,
+{
+    let scrutinee = to_p1_expr(&m.scrutinee, t_def, ns)?;
+    let range = match scrutinee.get_prim().and_then(|p| p.get_int()) {
+        Option::Some(int) => *int,
+        Option::None => return Err(E::expected_integer(&scrutinee)),
+    };
+
+    let mut arms = Vec::with_capacity(m.arms.len());
+    for arm in m.arms.iter() {
+        let (min, max) = match &arm.pattern.kind {
+            PatternKind::Wildcard => (None, None),
+            PatternKind::Value(value) => {
+                let value = pattern_int(value, arm.pattern.as_ref() as &Pattern)?;
+                (Some(value), Some(value))
+            }
+            PatternKind::Range(min, max) => {
+                let min = pattern_int(min, arm.pattern.as_ref() as &Pattern)?;
+                let max = pattern_int(max, arm.pattern.as_ref() as &Pattern)?;
+                if min > max {
+                    return Err(E::empty_pattern_range(arm.pattern.as_ref() as &Pattern));
+                }
+                (Some(min), Some(max))
+            }
+        };
+        let expr = to_p1_expr(&arm.expr, t_def, ns)?;
+        arms.push(P1Arm { min, max, expr });
+    }
+
+    if let Option::Some(missing) = first_uncovered(&range, &arms) {
+        return Err(E::non_exhaustive_match(m, &missing));
+    }
+
+    // every arm must agree on the type of the match
+    let first = &arms[0].expr;
+    let mut kind: TypeData = first.get_copy();
+    for arm in arms[1..].iter() {
+        kind = match union(&kind, &arm.expr.kind) {
+            Option::Some(kind) => kind,
+            Option::None => return Err(E::arm_type_mismatch(first, &arm.expr)),
+        };
+    }
+    Ok(P1Expression::new(e, kind, P1Expr::Match(Box::new(scrutinee), arms)))
+}
+
+// patterns may be negative, `- 3` is the same as `-3`
+fn pattern_int<E,P>(value: &Span, pattern: &P) -> Result<i64,E>
+where
+    E: ValidationErrors,
+    P: Spanner,
+{
+    let text = value.get_span().split_whitespace().collect::<String>();
+    match text.parse::<i64>() {
+        Ok(x) => Ok(x),
+        Err(_) => Err(E::malformed_int(value, pattern)),
+    }
+}
+
+/// the first run of values within `range` which no arm covers
+fn first_uncovered(range: &Integer, arms: &[P1Arm]) -> Option<Integer> {
+    let hi = range.get_maximum().unwrap_or(i64::MAX) as i128;
+    let mut intervals = arms.iter()
+        .map(|arm| (arm.min.unwrap_or(i64::MIN) as i128, arm.max.unwrap_or(i64::MAX) as i128))
+        .collect::<Vec<_>>();
+    intervals.sort();
+
+    // `next` is the smallest value not yet covered
+    let mut next = range.get_minimum().unwrap_or(i64::MIN) as i128;
+    for (min, max) in intervals {
+        if next > hi {
+            return None;
+        }
+        if min > next {
+            break;
+        }
+        next = next.max(max + 1);
     }
+    if next > hi {
+        return None;
+    }
+    let gap_end = arms.iter()
+        .filter_map(|arm| arm.min.map(|min| min as i128))
+        .filter(|min| *min > next)
+        .min()
+        .map(|min| (min - 1).min(hi))
+        .unwrap_or(hi);
+    let (min, max) = (next as i64, gap_end as i64);
+    Some(if min == max { Integer::new_constant(min) } else { Integer::new(max, min, None) })
+}
+
+#[cfg(test)]
+fn p1_from_str(source: &str) -> Result<P1Expression, crate::internals::errors::term_errors::HumanReadable> {
+    use crate::internals::{
+        errors::term_errors::HumanReadable,
+        parser::{ast::statement::StateTrait, generated::parse_code},
+    };
+
+    let body = parse_code::<HumanReadable>(source).unwrap();
+    let term = body.body.last().unwrap().get_term().unwrap();
+    let mut t_def = TemplateDefinations::test_constructor(Vec::<(String,usize)>::new());
+    to_p1_expr(term, &mut t_def, &mut Namespace::default())
+}
+
+#[test]
+fn collection_sizes_are_inferred() {
+    use crate::internals::canonization::kinds::Integer;
+
+    let coll = p1_from_str("[1, 5, 3]").unwrap();
+    assert_eq!(coll.get_coll(), Some(&Collection::new(Integer::new(5, 1, None), 3, 3, 3)));
+
+    let len = p1_from_str("len([1, 5, 3])").unwrap();
+    assert_eq!(len.get_prim(), Some(&Prim::new_int_constant(3)));
+
+    let item = p1_from_str("[1, 5, 3][2]").unwrap();
+    assert_eq!(item.get_prim(), Some(&Prim::new_int_dynamic(5, 1, None)));
+
+    assert!(p1_from_str("[1, 5, 3][3]").is_err());
+    assert!(p1_from_str("[1, 2][0][0]").is_err());
+}
+
+#[test]
+fn match_arms_must_be_exhaustive() {
+    use crate::internals::canonization::kinds::Integer;
+
+    let m = p1_from_str("match d6 { 1 => 0, 2..=6 => 10 }").unwrap();
+    assert_eq!(m.get_prim(), Some(&Prim::from(Integer::new(10, 0, None))));
+    assert!(p1_from_str("match 2d6 { 2..=7 => 0, _ => 1 }").is_ok());
+    assert!(p1_from_str("match -d4 { -4..=-1 => 1 }").is_ok());
+
+    let err = format!("{}", p1_from_str("match d6 { 1..=2 => 0, 5 => 1 }").err().unwrap());
+    assert!(err.contains("`3..=4` is not covered"), "{}", err);
+    let err = format!("{}", p1_from_str("match d6 { 1..=5 => 0 }").err().unwrap());
+    assert!(err.contains("`6` is not covered"), "{}", err);
+
+    assert!(p1_from_str("match d6 { 4..=1 => 0, _ => 1 }").is_err());
+    assert!(p1_from_str("match d6 { 1 => [1], _ => 1 }").is_err());
+}
+
+#[test]
+fn blocks_scope_their_bindings() {
+    use crate::internals::canonization::kinds::Integer;
+
+    let block = p1_from_str("{ let a := d6; let b := a * 2; a + b }").unwrap();
+    assert_eq!(block.get_prim(), Some(&Prim::from(Integer::new(18, 3, None))));
+
+    // inner blocks shadow, and their bindings end with the block
+    let shadow = p1_from_str("{ let a := 1; let b := { let a := d6; a }; a + b }").unwrap();
+    assert_eq!(shadow.get_prim(), Some(&Prim::from(Integer::new(7, 2, None))));
+    assert!(p1_from_str("{ let a := { let c := 1; c }; c }").is_err());
+
+    // blocks may be match arms
+    let arm = p1_from_str("match d6 { 1 => 0, _ => { let x := d6; x + 1 } }").unwrap();
+    assert_eq!(arm.get_prim(), Some(&Prim::from(Integer::new(7, 0, None))));
+
+    assert!(p1_from_str("{ let a := 1; let a := 2; a }").is_err());
+}
+
+#[test]
+fn overflow_is_reported_at_the_operation() {
+    use crate::internals::{
+        canonization::kinds::Integer,
+        errors::term_errors::HumanReadable,
+        parser::generated::parse_code,
+    };
+
+    let err = format!("{}", p1_from_str("1 + 9223372036854775807 * 2").err().unwrap());
+    assert!(err.contains("overflows an `int`") && err.contains(":1:5"), "{}", err);
+    let err = format!("{}", p1_from_str("-(-9223372036854775807 - 1)").err().unwrap());
+    assert!(err.contains("overflows an `int`") && err.contains(":1:1"), "{}", err);
+
+    // a range which only might overflow is unbounded on that side
+    let range = p1_from_str("d6 * 9223372036854775807").unwrap();
+    assert_eq!(range.get_prim(), Some(&Prim::from(Integer::new(None, i64::MAX, None))));
+
+    // template values are checked too
+    let mut t_def = TemplateDefinations::test_constructor(vec![("BIG", usize::MAX)]);
+    let body = parse_code::<HumanReadable>("${BIG} + 1").unwrap();
+    let term = body.body[0].get_term().unwrap();
+    let err = to_p1_expr::<HumanReadable>(term, &mut t_def, &mut Namespace::default()).err().unwrap();
+    assert!(format!("{}", err).contains("overflows an `int`"), "{}", err);
+}
+
+#[test]
+fn branches_narrow_their_conditions() {
+    use crate::internals::canonization::kinds::Integer;
+
+    let cond = p1_from_str("{ let x := d6; if x > 3 { x } else { x + 10 } }").unwrap();
+    assert_eq!(cond.get_prim(), Some(&Prim::from(Integer::new(13, 4, None))));
+    let cond = p1_from_str("{ let x := d6; if !(x <= 3) & x != 6 { x } else { 0 } }").unwrap();
+    assert_eq!(cond.get_prim(), Some(&Prim::from(Integer::new(5, 0, None))));
+
+    // comparisons within a branch fold to constants
+    let cond = p1_from_str("{ let x := d6; if 3 < x { x >= 4 } else { x < 4 } }").unwrap();
+    assert_eq!(cond.get_prim(), Some(&Prim::new_boolean_constant(true)));
+
+    // an arm which can't be taken doesn't add to the bounds
+    let cond = p1_from_str("if 1 > 2 { d6 } else { 5 }").unwrap();
+    assert_eq!(cond.get_prim(), Some(&Prim::new_int_constant(5)));
+
+    // an arm which contradicts an enclosing `if` is typed, and recorded
+    let impossible = |source: &str| {
+        use crate::internals::{errors::term_errors::HumanReadable, parser::generated::parse_code};
+
+        let body = parse_code::<HumanReadable>(source).unwrap();
+        let term = body.body[0].get_term().unwrap();
+        let mut t_def = TemplateDefinations::test_constructor(Vec::<(String,usize)>::new());
+        let mut ns = Namespace::default();
+        assert!(to_p1_expr::<HumanReadable>(term, &mut t_def, &mut ns).is_ok());
+        ns.take_impossible_branches()
+            .iter()
+            .map(|branch| (branch.get_span().to_string(), branch.condition.get_span().to_string(), branch.outcome))
+            .collect::<Vec<_>>()
+    };
+    let branches = impossible("{ let x := d6; if x > 3 { if x < 2 { 1 } else { 2 } } else { 0 } }");
+    assert_eq!(branches, vec![("1".to_string(), "x < 2".to_string(), true)]);
+    let branches = impossible("{ let b := d6 > 3; if b { 1 } else { if b { 2 } else { 3 } } }");
+    assert_eq!(branches, vec![("2".to_string(), "b".to_string(), true)]);
+    assert!(impossible("{ let x := d6; if x > 3 { x } else { 0 } }").is_empty());
 }
@@ -1,4 +1,6 @@
 
 pub mod assignment;
+pub mod constant;
 pub mod expr;
+pub mod narrowing;
 pub mod namespace;
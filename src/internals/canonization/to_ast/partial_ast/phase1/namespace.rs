@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::internals::canonization::to_ast::identifier::Hash;
+use crate::internals::{
+    canonization::to_ast::identifier::Hash,
+    parser::span::Span,
+};
 
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -0,0 +1,190 @@
+//! Narrowing
+//!
+//! Within a branch of an `if` the condition is known to hold (or not
+//! to hold), so the variables it compares have narrower types. Within
+//! `if x > 3 { a } else { b }` the arm `a` sees `x` as `4..` while `b`
+//! sees it as `..=3`.
+//!
+//! Only variables compared directly against an `int`, and `bool`
+//! variables, are narrowed. `!`, `&` and `|` are followed where the
+//! outcome of the whole condition fixes the outcome of its parts.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    canonization::{
+        kinds::{
+            primative::{Boolean, Integer, IntegerTrait, Prim, PrimativeTrait},
+            workable::{TypeData, TypeDataTrait},
+        },
+        to_ast::{
+            identifier::Hash,
+            partial_ast::phase1::expr::{P1Expr, P1Expression},
+        },
+    },
+    parser::{
+        ast::op::{Op, UnaryOp},
+        span::{Span, Spanner},
+    },
+};
+
+/// The narrowed types of the variables within a condition
+pub struct Narrowing {
+    pub kinds: BTreeMap<Hash, TypeData>,
+
+    /// a variable which can't hold any value where the condition has
+    /// the expected outcome, so the branch is never taken.
+    pub impossible: Option<Hash>,
+}
+
+/// ImpossibleBranch is an arm of an `if` which is never taken, as its
+/// condition never has the `outcome` the arm needs.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ImpossibleBranch {
+    pub arm: Span,
+    pub condition: Span,
+    pub outcome: bool,
+}
+
+impl AsRef<Span> for ImpossibleBranch {
+    fn as_ref(&self) -> &Span {
+        &self.arm
+    }
+}
+
+impl Spanner for ImpossibleBranch {}
+
+/// narrows the variables within `cond`, for when it evaluates to `outcome`
+pub(in crate::internals::canonization) fn narrow(cond: &P1Expression, outcome: bool) -> Narrowing {
+    let mut narrowing = Narrowing {
+        kinds: BTreeMap::new(),
+        impossible: None,
+    };
+    narrowing.condition(cond, outcome);
+    narrowing
+}
+
+impl Narrowing {
+    fn condition(&mut self, cond: &P1Expression, outcome: bool) {
+        match &cond.expr {
+            P1Expr::Parens(inner) => self.condition(inner, outcome),
+            P1Expr::Unary(UnaryOp::Not, inner) => self.condition(inner, !outcome),
+            P1Expr::Var(var) if cond.get_prim().map(|p| p.is_bool()).unwrap_or(false) => {
+                self.restrict(var, cond, outcome);
+            }
+            // both hold, or neither does
+            P1Expr::Op(l, Op::AND, r) if outcome => {
+                self.condition(l, true);
+                self.condition(r, true);
+            }
+            P1Expr::Op(l, Op::OR, r) if !outcome => {
+                self.condition(l, false);
+                self.condition(r, false);
+            }
+            P1Expr::Op(l, op, r) => {
+                // only comparisons can be negated
+                let op = negate(*op).map(|negated| if outcome { *op } else { negated });
+                if let Option::Some(op) = op {
+                    self.comparison(l, op, r);
+                    self.comparison(r, mirror(op), l);
+                }
+            }
+            _ => {}
+        };
+    }
+
+    /// narrows `var` (if it is a variable) so `var op other` holds
+    fn comparison(&mut self, var: &P1Expression, op: Op, other: &P1Expression) {
+        let hash = match &var.expr {
+            P1Expr::Var(hash) => hash,
+            _ => return,
+        };
+        let (current, other) = match (self.int(hash, var), other.get_prim().and_then(|p| p.get_int())) {
+            (Option::Some(current), Option::Some(other)) => (current, other),
+            _ => return,
+        };
+        let narrowed = match (op, other.get_constant()) {
+            // only the ends of a range can be removed
+            (Op::NE, Option::Some(c)) if current.get_minimum() == Some(c) => {
+                c.checked_add(1).and_then(|min| current.intersect(&Integer::new(None, min, None)))
+            }
+            (Op::NE, Option::Some(c)) if current.get_maximum() == Some(c) => {
+                c.checked_sub(1).and_then(|max| current.intersect(&Integer::new(max, None, None)))
+            }
+            (Op::NE, _) => return,
+            (op, _) => satisfying(op, other).and_then(|range| current.intersect(&range)),
+        };
+        match narrowed {
+            Option::Some(int) => {
+                self.kinds.insert(hash.clone(), TypeData::from(Prim::from(int)));
+            }
+            Option::None => self.impossible = Some(hash.clone()),
+        };
+    }
+
+    /// a `bool` variable is the outcome
+    fn restrict(&mut self, hash: &Hash, var: &P1Expression, outcome: bool) {
+        let current = self.kinds.get(hash).map(|k| k.get_prim()).unwrap_or(var.get_prim());
+        let narrowed = match current.and_then(|p| p.get_bool()) {
+            Option::Some(b) => b.intersect(&Boolean::new_constant(outcome)),
+            Option::None => return,
+        };
+        match narrowed {
+            Option::Some(b) => {
+                self.kinds.insert(hash.clone(), TypeData::from(Prim::from(b)));
+            }
+            Option::None => self.impossible = Some(hash.clone()),
+        };
+    }
+
+    /// the type of an `int` variable, as narrowed so far
+    fn int(&self, hash: &Hash, var: &P1Expression) -> Option<Integer> {
+        let kind = self.kinds.get(hash).map(|k| k.get_prim()).unwrap_or(var.get_prim());
+        kind.and_then(|p| p.get_int()).cloned()
+    }
+}
+
+/// the values of `x` for which `x op y` may hold, `None` if there are none
+fn satisfying(op: Op, y: &Integer) -> Option<Integer> {
+    let unbounded = Integer::new(None, None, None);
+    match op {
+        Op::GT => match y.get_minimum() {
+            Option::Some(min) => min.checked_add(1).map(|min| Integer::new(None, min, None)),
+            Option::None => Some(unbounded),
+        },
+        Op::GE => Some(Integer::new(None, y.get_minimum(), None)),
+        Op::LT => match y.get_maximum() {
+            Option::Some(max) => max.checked_sub(1).map(|max| Integer::new(max, None, None)),
+            Option::None => Some(unbounded),
+        },
+        Op::LE => Some(Integer::new(y.get_maximum(), None, None)),
+        Op::EQ => Some(*y),
+        _ => Some(unbounded),
+    }
+}
+
+/// `x op y` doesn't hold, when `x negate(op) y` does
+fn negate(op: Op) -> Option<Op> {
+    match op {
+        Op::GT => Some(Op::LE),
+        Op::GE => Some(Op::LT),
+        Op::LT => Some(Op::GE),
+        Op::LE => Some(Op::GT),
+        Op::EQ => Some(Op::NE),
+        Op::NE => Some(Op::EQ),
+        _ => None,
+    }
+}
+
+/// `x op y` is the same as `y mirror(op) x`
+fn mirror(op: Op) -> Op {
+    match op {
+        Op::GT => Op::LT,
+        Op::GE => Op::LE,
+        Op::LT => Op::GT,
+        Op::LE => Op::GE,
+        op => op,
+    }
+}
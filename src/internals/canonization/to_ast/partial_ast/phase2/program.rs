@@ -0,0 +1,329 @@
+//! Full AST
+//!
+//! Gives every expression of a program a type. Top level variables (all
+//! declared by the root file) are typed in declaration order, then every
+//! top level function body of every file with its arguments bound to
+//! their declared types. Finally the outputs of the root file are typed.
+//!
+//! Invocations only depend upon the signature of a function, so bodies
+//! may be typed in any order.
+
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    canonization::{
+        kinds::{
+            workable::{TypeData, TypeDataTrait},
+            Function, FunctionTrait,
+        },
+        to_ast::{
+            identifier::Hash,
+            namespace::Namespace,
+            partial_ast::{
+                analysis::{collect_outputs, Output},
+                phase1::{
+                    assignment::{to_p1_assign, P1Assign},
+                    expr::{to_p1_func, P1Expression},
+                    narrowing::ImpossibleBranch,
+                },
+            },
+            template_stuff::TemplateDefinations,
+            validation_errors::ValidationErrors,
+        },
+    },
+    parser::{
+        ast::{func::FunctionDec, statement::StateTrait},
+        loader::Program,
+        span::{Span, Spanner},
+    },
+};
+
+/// P2Func is a function whose body has been typed
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct P2Func {
+    pub span: Box<Span>,
+    pub identifier: Hash,
+    pub kind: Box<Function>,
+    pub body: Vec<P1Assign>,
+    pub ret: P1Expression,
+}
+
+impl AsRef<Span> for P2Func {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for P2Func {}
+
+/// P2Program is a program where every expression has a type
+#[derive(Default)]
+pub struct P2Program {
+    /// top level variables, in declaration order
+    pub assigns: Vec<P1Assign>,
+
+    /// top level functions, in declaration order
+    pub funcs: Vec<P2Func>,
+
+    pub outputs: Vec<Output>,
+
+    /// arms of an `if` which are never taken, in source order
+    pub impossible: Vec<ImpossibleBranch>,
+}
+
+/// Types every declaration & output of `program`.
+///
+/// `ns` is the top level namespace of the program (see
+/// `Namespace::from_program`), top level variables are declared within it.
+pub fn type_program<E>(
+    program: &Program,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace,
+) -> Result<P2Program, Vec<E>>
+where
+    E: ValidationErrors,
+{
+    let mut typed = type_declarations(program, t_def, ns)?;
+    typed.outputs = collect_outputs(&program.root().body, t_def, ns)?;
+    typed.impossible = ns.take_impossible_branches();
+    Ok(typed)
+}
+
+/// Types the top level variables & functions of `program`, outputs
+/// may be typed within `ns` afterwards.
+///
+/// Every error is returned together. A variable which fails to type
+/// check is still declared, so later uses of it aren't reported as
+/// undefined.
+pub fn type_declarations<E>(
+    program: &Program,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace,
+) -> Result<P2Program, Vec<E>>
+where
+    E: ValidationErrors,
+{
+    let mut typed = P2Program::default();
+    let mut errors = Vec::new();
+    let top_level = program.top_level();
+
+    for assign in top_level.clone().filter_map(|s| s.get_dec()) {
+        match to_p1_assign::<E>(assign, t_def, ns) {
+            Ok(assign) => typed.assigns.push(assign),
+            Err(e) => {
+                errors.push(e);
+                let _ = ns.add_var::<_, E>(assign, TypeData::None);
+            }
+        };
+    }
+    for func in top_level.filter_map(|s| s.get_func()) {
+        match to_p2_func::<E>(func, t_def, ns) {
+            Ok(func) => typed.funcs.push(func),
+            Err(e) => errors.push(e),
+        };
+    }
+
+    if errors.is_empty() {
+        Ok(typed)
+    } else {
+        Err(errors)
+    }
+}
+
+/// the value of the body must fit the declared return type
+fn to_p2_func<E>(
+    func: &FunctionDec,
+    t_def: &mut TemplateDefinations,
+    ns: &mut Namespace,
+) -> Result<P2Func, E>
+where
+    E: ValidationErrors,
+{
+    let (body, ret) = to_p1_func(func, t_def, ns)?;
+    Ok(P2Func {
+        span: Box::new(func.get_clone()),
+        identifier: Hash::from(func),
+        kind: Box::new(Function::from(func)),
+        body,
+        ret,
+    })
+}
+
+#[cfg(test)]
+fn type_str(source: &str) -> Result<P2Program, Vec<String>> {
+    use crate::internals::errors::term_errors::{render_for_test, HumanReadable};
+
+    let program = Program::from_str_for_test(source);
+    let mut ns = Namespace::from_program::<HumanReadable>(&program).map_err(render_for_test)?;
+    let mut t_def = TemplateDefinations::test_constructor(vec![("STRENGTH", 5usize)]);
+    type_program::<HumanReadable>(&program, &mut t_def, &mut ns).map_err(render_for_test)
+}
+
+#[test]
+fn every_expression_is_typed() {
+    use crate::internals::canonization::kinds::primative::Prim;
+
+    let source = "let bonus = 2;\nfn attack(roll: int) int { roll + bonus }\n\
+                  fn wound(s: int) int { if s > 4 { 2 } else { 5 } }\ncm total(0, attack, +) int;\n\
+                  analyze hit = attack(d20) >= 12;\nanalyze needs = wound(${STRENGTH});\n\
+                  analyze sum = total([d6, d6]);\nif bonus > 1 { d6 } else { 2d6 }";
+    let typed = type_str(source).unwrap();
+    assert_eq!(typed.assigns[0].kind.get_prim(), Some(&Prim::new_int_constant(2)));
+    assert_eq!(typed.funcs.len(), 2);
+    assert_eq!(typed.funcs[1].ret.get_prim(), Some(&Prim::new_int_dynamic(5, 2, None)));
+
+    let kinds = typed.outputs.iter().map(|o| o.expr.kind.to_string()).collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["bool false..=true", "int 2", "int 6..=16", "int 1..=6"]);
+}
+
+#[test]
+fn type_errors_are_reported() {
+    let first_error = |source: &str| type_str(source).err().unwrap().remove(0);
+
+    // invocations are checked against the signature
+    let err = first_error("fn f(a: int, b: bool) int { a }\nf(1)");
+    assert!(err.contains("takes 2 arguments but 1 was supplied"), "{}", err);
+    let err = first_error("fn f(a: int, b: bool) int { a }\nf(1, 2)");
+    assert!(err.contains("expected `bool") && err.contains("<anon>:1:14"), "{}", err);
+    let err = first_error("cm total(0, x, +) int;\ntotal(3)");
+    assert!(err.contains("expected `vec<int ..>"), "{}", err);
+
+    // both arms of an `if` must agree, and the condition must be a `bool`
+    let err = first_error("if d6 > 3 { 1 } else { [1] }");
+    assert!(err.contains("does not match the first arm"), "{}", err);
+    let err = first_error("if d6 { 1 } else { 2 }");
+    assert!(err.contains("expected a `bool`"), "{}", err);
+
+    // annotations, and declared return types
+    let err = first_error("let x: bool == d6;\nx");
+    assert!(err.contains("expected `bool") && err.contains("found `int 1..=6`"), "{}", err);
+    let err = first_error("fn f(a: int) bool { a }\nf(1)");
+    assert!(err.contains("expected `bool") && err.contains("declared here"), "{}", err);
+    assert!(type_str("let x: int == d6;\nfn f(a: vec<int>) int { len(a) }\nf([x, 2])").is_ok());
+
+    // a body evaluated with constant arguments reports its own errors
+    let err = first_error("fn pick(i: int) int { [1, 2, 3][i] }\nlet a = pick(5);\na");
+    assert!(err.contains("index `5` is out of bounds") && err.contains("<anon>:1:33"), "{}", err);
+    assert!(type_str("fn pick(i: int) int { [1, 2, 3][i] }\nlet a = pick(d6);\na").is_ok());
+
+    // a variable which failed to type check has no type
+    let errors = type_str("let x = (1 == 1) + 1;\nfn f() int { x }\n1").err().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[1].contains("cannot be determined"), "{}", errors[1]);
+}
+
+#[test]
+fn folds_are_bounded_by_length() {
+    let source = "fn id(x: int) int { x }\nfn keep(x: bool) bool { x }\n\
+                  cm sum(0, id, +) int;\ncm count(0, 1, +) int;\ncm all(true, keep, &) bool;\n\
+                  let rolls = if d6 > 3 { [d6, d6, d6] } else { [d6, d6, d6, d6, d6] };\n\
+                  analyze total = sum(rolls);\nanalyze n = count(rolls);\n\
+                  analyze lucky = all([d2 > 0, 2 > 1]);\nanalyze some = all([d6 > 3, d2 > 0]);\n\
+                  fn loop(x: int) int { again([x, 1]) }\ncm again(0, loop, +) int;\nagain([2])";
+    let typed = type_str(source).unwrap();
+    assert_eq!(typed.assigns[0].kind.to_string(), "vec<int 1..=6> len 3..=5");
+
+    let kinds = typed.outputs.iter().map(|o| o.expr.kind.to_string()).collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["int 3..=30", "int 3..=5", "bool true", "bool false..=true", "int .."]);
+
+    // declared collections may be of any length
+    let typed = type_str("cm sum(0, 1, +) int;\nfn f(v: vec<int>) int { sum(v) }\nf([1])").unwrap();
+    assert_eq!(typed.funcs[0].ret.kind.to_string(), "int 0..");
+}
+
+#[test]
+fn consts_are_folded() {
+    use crate::internals::canonization::to_ast::partial_ast::phase1::expr::P1Expr;
+
+    let source = "fn double(x: int) int { x * 2 }\nconst a = double(${STRENGTH}) + 1;\n\
+                  const b = [a, (3)];\nconst c = !(a > 10);\nlet d = a + d6;\nd";
+    let typed = type_str(source).unwrap();
+    assert_eq!(typed.assigns[0].expr.expr, P1Expr::Value(11));
+    match &typed.assigns[1].expr.expr {
+        P1Expr::Collection(items) => {
+            let items = items.iter().map(|item| item.expr.clone()).collect::<Vec<_>>();
+            assert_eq!(items, vec![P1Expr::Value(11), P1Expr::Value(3)]);
+        }
+        other => panic!("{:?}", other),
+    };
+    assert_eq!(typed.assigns[2].expr.expr, P1Expr::Bool(false));
+    // reads of a const are replaced by its value
+    match &typed.assigns[3].expr.expr {
+        P1Expr::Op(l, _, _) => assert_eq!(l.expr, P1Expr::Value(11)),
+        other => panic!("{:?}", other),
+    };
+}
+
+#[test]
+fn consts_must_be_known() {
+    let first_error = |source: &str| type_str(source).err().unwrap().remove(0);
+
+    // a dice roll, or a read of a variable which isn't `const`, is reported
+    let err = first_error("const a = 2 * (1 + d6);");
+    assert!(err.contains("before any dice are rolled") && err.contains("<anon>:1:20"), "{}", err);
+    assert!(err.contains("declared `const` here"), "{}", err);
+    let err = first_error("let x = d4;\nconst a = x + 1;");
+    assert!(err.contains("<anon>:2:11") && err.contains("only known once dice are rolled"), "{}", err);
+    let err = first_error("fn roll() int { d6 }\nconst a = [roll()];");
+    assert!(err.contains("<anon>:2:12") && err.contains("<anon>:1:17"), "{}", err);
+    let err = first_error("fn f(x: int) int { const y = x; y }\nf(1)");
+    assert!(err.contains("<anon>:1:30") && err.contains("<anon>:1:6"), "{}", err);
+
+    // even when the type has a single value
+    for source in ["const a = d6 * 0;", "const b = d6 >= 1;", "let x = d6 * 0;\nconst a = x;",
+                   "fn zero() int { d6 * 0 }\nconst a = { let z := zero(); z };",
+                   "fn zero(x: int) int { x * 0 }\ncm total(0, zero, +) int;\nconst a = total([d6]);"].iter() {
+        let err = first_error(source);
+        assert!(err.contains("before any dice are rolled"), "{}", err);
+    }
+
+    // what is left must then fold to a value
+    let err = first_error("fn f(x: int) int { f(x) }\nconst a = f(1);");
+    assert!(err.contains("found `int ..`"), "{}", err);
+    // an error within an invoked function is reported as it is
+    let err = first_error("fn f() int { later }\nconst c = f();\nlet later = 1;");
+    assert!(err.contains("cannot find variable `later`"), "{}", err);
+
+    assert!(type_str("let x = 4;\nconst a = if x > 3 { 1 } else { d6 };").is_err());
+    assert!(type_str("const x = 4;\nfn f(y: int) int { { let z := y; z } }\nconst a = [f(x), ${STRENGTH}];").is_ok());
+}
+
+#[test]
+fn impossible_branches_are_warnings() {
+    use crate::internals::{canonization::to_ast::lints::{lint_branches, Lint}, errors::term_errors::HumanReadable};
+
+    let arms = |source: &str| {
+        let typed = type_str(source).unwrap();
+        typed.impossible.iter().map(|branch| branch.get_span().to_string()).collect::<Vec<_>>()
+    };
+    // the type of a top level variable rules an arm out
+    assert_eq!(arms("let x = d6;\nif x > 10 { 1 } else { 2 }"), vec!["1"]);
+    assert_eq!(arms("let x = d6;\nif x > 3 & x < 2 { 1 } else { 2 }"), vec!["1"]);
+    assert_eq!(arms("let x = d6;\nif x <= 6 { 1 } else { 2 }"), vec!["2"]);
+    // within a function body, the enclosing `if` does
+    let source = "fn f(x: int) int { if x > 3 { if x < 2 { 1 } else { 2 } } else { 0 } }\nf(5) + f(d6)";
+    assert_eq!(arms(source), vec!["1"]);
+    assert!(arms("let x = d6;\nif x > 3 { 1 } else { 2 }").is_empty());
+
+    let typed = type_str("let x = d6;\nif x > 10 { 1 } else { 2 }").unwrap();
+    let warnings = lint_branches::<HumanReadable>(&typed.impossible, &Default::default());
+    let warning = warnings[0].to_string();
+    assert!(warning.contains("WARNING") && warning.contains("never taken"), "{}", warning);
+    assert!(warning.contains("never `true`") && warning.contains("impossible-branch"), "{}", warning);
+    let allowed = vec![Lint::ImpossibleBranch].into_iter().collect();
+    assert!(lint_branches::<HumanReadable>(&typed.impossible, &allowed).is_empty());
+}
+
+#[test]
+fn nested_functions_are_typed() {
+    let source = "fn f(x: int) int { fn g(y: int) int { y + x + z } let z := 2; g(1) }\n\
+                  cm total(0, f, +) int;\n{ fn inner() int { 1 } inner() + total([1, 2]) }";
+    let typed = type_str(source).unwrap();
+    assert_eq!(typed.outputs[0].expr.kind.to_string(), "int ..");
+
+    // their bodies are checked, and their names may conflict
+    let err = type_str("{ fn g() int { [1] } g() }").err().unwrap().remove(0);
+    assert!(err.contains("expected `int") && err.contains("<anon>:1:16"), "{}", err);
+    let err = type_str("fn f() int { fn g() int { 1 } fn g() int { 2 } g() }\nf()").err().unwrap().remove(0);
+    assert!(err.contains("already defined") && err.contains("<anon>:1:31"), "{}", err);
+}
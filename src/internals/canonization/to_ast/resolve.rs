@@ -0,0 +1,399 @@
+//! Name Resolution
+//!
+//! Finds the definition of every variable which is read, and every
+//! function which is invoked.
+//!
+//! Top level functions are visible from anywhere within the program
+//! (imported files only contribute functions, see `Program::top_level`),
+//! top level variables once they're declared. A function body only sees
+//! the top level variables declared before the function is first
+//! invoked, possibly through other functions. Within a body or block, variables are visible from
+//! their declaration to the end of the scope, while functions are
+//! visible throughout the scope which declares them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    canonization::{
+        kinds::workable::TypeData,
+        to_ast::{
+            identifier::Hash,
+            namespace::{FuncType, Namespace, VarType},
+            validation_errors::ValidationErrors,
+        },
+    },
+    parser::{
+        ast::{
+            comparg::{CompositionalArg, CompositionalFunction},
+            expr::{Expr, Expression},
+            func::FunctionDec,
+            ident::Ident,
+            statement::{State, Statement},
+        },
+        loader::Program,
+        span::{Span, Spanner},
+    },
+};
+
+/// The definition a name was resolved to
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Resolved {
+    Var(VarType),
+    Func(FuncType),
+}
+
+impl AsRef<Span> for Resolved {
+    fn as_ref(&self) -> &Span {
+        match self {
+            Resolved::Var(x) => x.as_ref(),
+            Resolved::Func(x) => x.as_ref(),
+        }
+    }
+}
+
+impl Spanner for Resolved {}
+
+/// The definition of every name used by a program
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Resolutions {
+    // keyed by the span of the identifier at the use site
+    uses: BTreeMap<Span, Resolved>,
+}
+
+impl Resolutions {
+    /// the definition of an identifier which reads a variable,
+    /// or names a function.
+    pub fn get(&self, usage: &Ident) -> Option<&Resolved> {
+        self.uses.get(usage.as_ref())
+    }
+
+    /// every use, and the definition it resolved to
+    pub fn iter(&self) -> impl Iterator<Item = (&Span, &Resolved)> {
+        self.uses.iter()
+    }
+}
+
+/// Resolves every name used by `program`.
+///
+/// `ns` is the top level namespace of the program (see
+/// `Namespace::from_program`), it is not modified.
+pub fn resolve_program<E>(program: &Program, ns: &Namespace) -> Result<Resolutions, Vec<E>>
+where
+    E: ValidationErrors,
+{
+    let mut resolver = Resolver {
+        ns: ns.clone(),
+        uses: BTreeMap::new(),
+        vars: BTreeMap::new(),
+        funcs: BTreeMap::new(),
+        owner: Owner::Top(0),
+        top_vars: BTreeMap::new(),
+        calls: BTreeMap::new(),
+        top_reads: BTreeMap::new(),
+        errors: Vec::new(),
+    };
+
+    // top level variables are known before they're declared, so
+    // reading one too early can point at its declaration.
+    let top_level = program.top_level();
+    for (i, statement) in top_level.clone().enumerate() {
+        if let State::Declaration(assign) = statement.sttm.as_ref() {
+            resolver.vars.insert(Hash::from(assign.as_ref()), assign.get_clone());
+            resolver.top_vars.insert(assign.get_clone(), i);
+        }
+    }
+
+    let mut funcs = Vec::new();
+    for (i, statement) in top_level.enumerate() {
+        resolver.owner = Owner::Top(i);
+        match statement.sttm.as_ref() {
+            State::Func(func) => funcs.push(func.as_ref()),
+            State::CompFunc(comp) => resolver.comp(comp),
+            _ => resolver.statement(statement),
+        };
+    }
+    for func in funcs {
+        resolver.func(func);
+    }
+    resolver.check_top_reads();
+
+    if resolver.errors.is_empty() {
+        Ok(Resolutions {
+            uses: resolver.uses,
+        })
+    } else {
+        Err(resolver.errors)
+    }
+}
+
+/// where a name is used, a top level statement (by position) or the
+/// body of a function (by the span of its declaration).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Owner {
+    Top(usize),
+    Func(Span),
+}
+
+struct Resolver<E> {
+    ns: Namespace,
+    uses: BTreeMap<Span, Resolved>,
+    // a declaration of every name seen, to explain names which
+    // exist but are not visible where they are used.
+    vars: BTreeMap<Hash, Span>,
+    funcs: BTreeMap<Hash, Span>,
+    owner: Owner,
+    // the position of every top level variable's declaration
+    top_vars: BTreeMap<Span, usize>,
+    // the functions invoked by each owner
+    calls: BTreeMap<Owner, Vec<Span>>,
+    // the top level variables read by each function body
+    top_reads: BTreeMap<Span, Vec<(Ident, Span, usize)>>,
+    errors: Vec<E>,
+}
+
+impl<E> Resolver<E>
+where
+    E: ValidationErrors,
+{
+    /// a function or block body, a new scope is entered by the caller
+    fn statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            let result = match statement.sttm.as_ref() {
+                State::Func(func) => {
+                    self.funcs.insert(Hash::from(func.as_ref()), func.get_clone());
+                    self.ns.add_func::<_, E>(func.as_ref())
+                }
+                State::CompFunc(comp) => {
+                    self.funcs.insert(Hash::from(comp.as_ref()), comp.get_clone());
+                    self.ns.add_func::<_, E>(comp.as_ref())
+                }
+                _ => Ok(()),
+            };
+            if let Err(e) = result {
+                self.errors.push(e);
+            }
+        }
+        for statement in statements {
+            self.statement(statement);
+        }
+        for statement in statements {
+            match statement.sttm.as_ref() {
+                State::Func(func) => self.func(func),
+                State::CompFunc(comp) => self.comp(comp),
+                _ => {}
+            };
+        }
+    }
+
+    /// resolves a statement, functions are handled by the caller
+    fn statement(&mut self, statement: &Statement) {
+        match statement.sttm.as_ref() {
+            State::Declaration(assign) => {
+                self.expr(&assign.expr);
+                self.vars.insert(Hash::from(assign.as_ref()), assign.get_clone());
+                if let Err(e) = self.ns.add_var::<_, E>(assign.as_ref(), TypeData::None) {
+                    self.errors.push(e);
+                }
+            }
+            State::Termination(expr) => self.expr(expr),
+            State::Analysis(analyze) => self.expr(&analyze.expr),
+            State::Func(_) | State::CompFunc(_) | State::Import(_) => {}
+        };
+    }
+
+    /// A function body is evaluated where the function is first invoked,
+    /// by a top level statement or the functions it invokes. It can't
+    /// read a top level variable declared after that statement.
+    fn check_top_reads(&mut self) {
+        let mut checked = BTreeSet::new();
+        let tops = self.calls.keys()
+            .filter_map(|owner| match *owner {
+                Owner::Top(i) => Some(i),
+                Owner::Func(_) => None,
+            })
+            .collect::<Vec<usize>>();
+        for i in tops {
+            let mut pending = self.calls[&Owner::Top(i)].clone();
+            while let Option::Some(func) = pending.pop() {
+                if !checked.insert(func.clone()) {
+                    continue;
+                }
+                for &(ref ident, ref def, declared) in self.top_reads.get(&func).into_iter().flatten() {
+                    if declared >= i {
+                        self.errors.push(E::not_in_scope(ident, def));
+                    }
+                }
+                pending.extend(self.calls.get(&Owner::Func(func)).into_iter().flatten().cloned());
+            }
+        }
+    }
+
+    fn func(&mut self, func: &FunctionDec) {
+        let owner = std::mem::replace(&mut self.owner, Owner::Func(func.get_clone()));
+        self.ns.enter_scope();
+        for arg in func.args.iter() {
+            self.vars.insert(Hash::from(arg), arg.get_clone());
+            if let Err(e) = self.ns.add_var::<_, E>(arg, TypeData::None) {
+                self.errors.push(e);
+            }
+        }
+        self.statements(&func.body);
+        self.ns.leave_scope();
+        self.owner = owner;
+    }
+
+    fn comp(&mut self, comp: &CompositionalFunction) {
+        let owner = std::mem::replace(&mut self.owner, Owner::Func(comp.get_clone()));
+        for arg in [&comp.null_arg, &comp.single_arg, &comp.collection_arg].iter() {
+            if let CompositionalArg::Func(ident) = &arg.arg {
+                self.call(ident);
+            }
+        }
+        self.owner = owner;
+    }
+
+    fn expr(&mut self, e: &Expression) {
+        match e.kind.as_ref() {
+            Expr::Var(ident) => self.var(ident),
+            Expr::Invoke(invoke) => {
+                self.call(&invoke.name);
+                for arg in invoke.args.iter() {
+                    self.expr(arg);
+                }
+            }
+            Expr::Op(op) => {
+                self.expr(&op.left);
+                self.expr(&op.right);
+            }
+            Expr::Unary(unary) => self.expr(&unary.expr),
+            Expr::Parens(inner) => self.expr(inner),
+            Expr::Cond(cond) => {
+                self.expr(&cond.condition);
+                self.expr(&cond.true_case);
+                self.expr(&cond.false_case);
+            }
+            Expr::Match(m) => {
+                self.expr(&m.scrutinee);
+                for arm in m.arms.iter() {
+                    self.expr(&arm.expr);
+                }
+            }
+            Expr::Block(block) => {
+                self.ns.enter_scope();
+                self.statements(&block.body);
+                self.ns.leave_scope();
+            }
+            Expr::Coll(coll) => {
+                for item in coll.items.iter() {
+                    self.expr(item);
+                }
+            }
+            Expr::Index(index) => {
+                self.expr(&index.collection);
+                self.expr(&index.index);
+            }
+            Expr::Len(len) => self.expr(&len.collection),
+            Expr::Num(_) | Expr::Dice(_) | Expr::Template(_) => {}
+        };
+    }
+
+    /// an identifier which reads a variable
+    fn var(&mut self, ident: &Ident) {
+        if let Option::Some(binding) = self.ns.get_var(ident) {
+            let def = binding.var.get_clone();
+            if let (Owner::Func(func), Option::Some(declared)) = (&self.owner, self.top_vars.get(&def)) {
+                let read = (ident.clone(), def.clone(), *declared);
+                self.top_reads.entry(func.clone()).or_default().push(read);
+            }
+            let resolved = Resolved::Var(binding.var.clone());
+            self.uses.insert(ident.get_clone(), resolved);
+            return;
+        }
+        let err = match (self.ns.get_func(ident), self.vars.get(&Hash::from(ident))) {
+            (Option::Some(func), _) => E::not_a_value(ident, func),
+            (_, Option::Some(def)) => E::not_in_scope(ident, def),
+            _ => E::undefined_var(ident),
+        };
+        self.errors.push(err);
+    }
+
+    /// an identifier which names a function
+    fn call(&mut self, ident: &Ident) {
+        if let Option::Some(func) = self.ns.get_func(ident) {
+            self.calls.entry(self.owner.clone()).or_default().push(func.get_clone());
+            let resolved = Resolved::Func(func.clone());
+            self.uses.insert(ident.get_clone(), resolved);
+            return;
+        }
+        let err = match (self.ns.get_var(ident), self.funcs.get(&Hash::from(ident))) {
+            (Option::Some(binding), _) => E::not_a_function(ident, &binding.var),
+            (_, Option::Some(def)) => E::not_in_scope(ident, def),
+            _ => E::undefined_func(ident),
+        };
+        self.errors.push(err);
+    }
+}
+
+#[cfg(test)]
+fn resolve_str(source: &str) -> Result<Resolutions, Vec<String>> {
+    use crate::internals::errors::term_errors::{render_for_test, HumanReadable};
+
+    let program = Program::from_str_for_test(source);
+    let ns = Namespace::from_program::<HumanReadable>(&program).map_err(render_for_test)?;
+    resolve_program::<HumanReadable>(&program, &ns).map_err(render_for_test)
+}
+
+#[test]
+fn names_resolve_to_their_definitions() {
+    let source = "let bonus = 2;\nfn attack(roll: int) int { roll + bonus + later }\nlet later = 1;\n\
+                  analyze hit = attack({ let bonus := d20; bonus });\ncm total(0, attack, +) int;";
+    let resolutions = resolve_str(source).unwrap();
+    let defs = resolutions
+        .iter()
+        .map(|(usage, def)| format!("{}:{} -> {}", usage.get_span(), usage.get_start_column(), def.get_span()))
+        .collect::<Vec<String>>();
+    assert_eq!(defs, vec![
+        "roll:28 -> roll: int",
+        "bonus:35 -> let bonus = 2",
+        "later:43 -> let later = 1",
+        "attack:15 -> fn attack(roll: int) int { roll + bonus + later }",
+        "bonus:42 -> let bonus := d20",
+        "attack:13 -> fn attack(roll: int) int { roll + bonus + later }",
+    ]);
+}
+
+#[test]
+fn unresolved_names_are_reported() {
+    let first_error = |source: &str| resolve_str(source).err().unwrap().remove(0);
+
+    let err = first_error("x + 1");
+    assert!(err.contains("cannot find variable `x`"), "{}", err);
+    let err = first_error("f(1)");
+    assert!(err.contains("cannot find function `f`"), "{}", err);
+
+    // the definition is given as a secondary span
+    let err = first_error("let y = x;\nlet x = 1;");
+    assert!(err.contains("`x` is not in scope") && err.contains("<anon>:2:1"), "{}", err);
+
+    // a body can't read a variable declared after the function is first invoked
+    let err = first_error("fn f() int { later }\nlet a = f();\nlet later = 1;");
+    assert!(err.contains("`later` is not in scope") && err.contains("<anon>:3:1"), "{}", err);
+    let err = first_error("fn g() int { later }\nfn f() int { g() }\nanalyze a = f();\nlet later = 1;\nf()");
+    assert!(err.contains("`later` is not in scope"), "{}", err);
+    let err = first_error("cm total(0, g, +) int;\nfn g(x: int) int { x + later }\nlet a = total([1]);\nlet later = 1;");
+    assert!(err.contains("`later` is not in scope"), "{}", err);
+    let err = first_error("{ let a := 1; a } + a");
+    assert!(err.contains("`a` is not in scope") && err.contains("<anon>:1:3"), "{}", err);
+    let err = first_error("fn f() int { 1 }\nlet y = f;");
+    assert!(err.contains("is a function, not a value") && err.contains("<anon>:1:1"), "{}", err);
+    let err = first_error("let g = 1;\ng(2)");
+    assert!(err.contains("is a variable, not a function"), "{}", err);
+
+    // arguments and a body share a scope, so they can't be redeclared
+    let err = first_error("fn f(a: int) int { let a = 2; a }");
+    assert!(err.contains("already defined"), "{}", err);
+    assert!(resolve_str("fn f(a: int) int { { let a := 2; a } }").is_ok());
+    assert!(resolve_str("let a = 1;\nlet a = 2;").is_err());
+}
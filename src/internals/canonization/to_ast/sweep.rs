@@ -0,0 +1,97 @@
+//! Sweep
+//!
+//! A sweep varies templates across ranges of values, the program
+//! is canonized once for every combination of them.
+
+use itertools::Itertools;
+
+/// Sweep is every value a single template takes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sweep {
+    pub name: String,
+    pub values: Vec<usize>,
+}
+
+impl Sweep {
+    /// parses `NAME=VALUES`, values are an inclusive range `3..=8` or a
+    /// list `1,3,5`.
+    ///
+    /// A bare `3..8` is rejected rather than guessing whether it
+    /// includes 8.
+    pub fn parse(arg: &str) -> Result<Self, String> {
+        let mut parts = arg.splitn(2, '=');
+        let name = parts.next().unwrap_or("").trim();
+        let values = match parts.next() {
+            Option::Some(values) => values.trim(),
+            Option::None => return Err(format!("`{}` is not of the form NAME=VALUES", arg)),
+        };
+        if name.is_empty() {
+            return Err(format!("`{}` does not name a template", arg));
+        }
+        let int = |s: &str| match s.trim().parse::<usize>() {
+            Ok(x) => Ok(x),
+            Err(_) => Err(format!(
+                "template `{}` must be swept over non-negative integers, found `{}`",
+                name, s
+            )),
+        };
+        let values = match values.find("..") {
+            Option::Some(i) => {
+                let (start, end) = (values[..i].trim(), values[i + 2..].trim());
+                let min = int(start)?;
+                let max = match end.strip_prefix('=') {
+                    Option::Some(max) => int(max)?,
+                    Option::None => {
+                        return Err(format!(
+                            "`{}` is not a range for template `{}`, write `{}..={}` to include {}",
+                            values, name, start, end, end
+                        ))
+                    }
+                };
+                if min > max {
+                    return Err(format!("`{}` is an empty range for template `{}`", values, name));
+                }
+                (min..=max).collect()
+            }
+            Option::None => values.split(',').map(int).collect::<Result<Vec<usize>, String>>()?,
+        };
+        Ok(Self {
+            name: name.to_string(),
+            values,
+        })
+    }
+}
+
+/// every combination of the swept values, the first sweep varies slowest.
+///
+/// each combination holds one value per sweep, in the order of `sweeps`
+pub fn combinations(sweeps: &[Sweep]) -> Vec<Vec<usize>> {
+    sweeps
+        .iter()
+        .map(|sweep| sweep.values.iter().cloned())
+        .multi_cartesian_product()
+        .collect()
+}
+
+#[test]
+fn sweeps_cover_every_combination() {
+    let strength = Sweep::parse("STRENGTH=3..=5").unwrap();
+    assert_eq!(strength.values, vec![3, 4, 5]);
+    assert_eq!(Sweep::parse("S = 3 ..= 4").unwrap().values, vec![3, 4]);
+    assert_eq!(Sweep::parse("S=3..=3").unwrap().values, vec![3]);
+    // whether a bare range includes its end isn't guessed
+    let err = Sweep::parse("AP=0..3").err().unwrap();
+    assert!(err.contains("write `0..=3` to include 3"), "{}", err);
+    assert_eq!(Sweep::parse("AP=0,2").unwrap().values, vec![0, 2]);
+    assert_eq!(Sweep::parse("AP=1").unwrap().values, vec![1]);
+    assert!(Sweep::parse("AP=3..=1").is_err());
+    assert!(Sweep::parse("AP=-1..=1").is_err());
+    assert!(Sweep::parse("=1..=2").is_err());
+    assert!(Sweep::parse("AP").is_err());
+
+    let ap = Sweep::parse("AP=0,2").unwrap();
+    assert_eq!(
+        combinations(&[strength, ap]),
+        vec![vec![3, 0], vec![3, 2], vec![4, 0], vec![4, 2], vec![5, 0], vec![5, 2]]
+    );
+}
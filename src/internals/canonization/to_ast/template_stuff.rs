@@ -1,103 +1,315 @@
-
-use std::collections::HashMap;
-
-use serde::{Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
 
 use crate::internals::{
+    canonization::to_ast::validation_errors::ValidationErrors,
     parser::{
         ast::{
             ident::Ident,
-            template::{Template,TemplateBehavior,TemplateFallback},
+            template::{Template, TemplateBehavior, TemplateFallback},
         },
-        span::{Span,Spanner},
+        span::{Span, Spanner},
     },
-    canonization::to_ast::validation_errors::ValidationErrors,
 };
 
+/// environment variables with this prefix define templates,
+/// `FOXHOLE_STRENGTH=4` defines `STRENGTH`.
+pub const ENV_PREFIX: &str = "FOXHOLE_";
 
-/// Handles storing & updating template information
-pub struct TemplateDefinations {
-    data: HashMap<String,usize>,
+/// Where the value of a template came from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// `--define NAME=VALUE`
+    Cli,
+    /// `--sweep NAME=VALUES`
+    Sweep,
+    /// a `--defines` json file
+    File(PathBuf),
+    /// an environment variable, by its full name
+    Env(String),
+    /// the fallback of `${NAME :- value}`
+    Fallback(Span),
+    /// the assignment of `${NAME := value}`
+    Assign(Span),
 }
 
-
-impl Default for TemplateDefinations {
-    fn default() -> TemplateDefinations {
-        Self {
-            data: std::env::vars()
-                .filter_map(|(key,value)| -> Option<(String,usize)> {
-                    usize::from_str_radix(&value, 10)
-                        .ok()
-                        .map(|v| (key,v))
-                })
-                .collect::<HashMap<String,usize>>(),
+impl fmt::Display for TemplateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = |span: &Span| {
+            format!(
+                "{}:{}:{}",
+                span.get_source().get_name(),
+                span.get_start_line(),
+                span.get_start_column()
+            )
+        };
+        match self {
+            TemplateSource::Cli => write!(f, "--define"),
+            TemplateSource::Sweep => write!(f, "--sweep"),
+            TemplateSource::File(path) => write!(f, "{}", path.display()),
+            TemplateSource::Env(var) => write!(f, "${}", var),
+            TemplateSource::Fallback(span) => write!(f, "fallback at {}", location(span)),
+            TemplateSource::Assign(span) => write!(f, "assigned at {}", location(span)),
         }
     }
 }
 
+/// A template's value, and where it came from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Definition {
+    pub value: usize,
+    pub source: TemplateSource,
+}
+
+/// Handles storing & updating template information
+///
+/// Values are only taken from sources the user asked for. When more
+/// than one defines a template the last one given wins, callers add
+/// the environment, then files, then the command line.
+#[derive(Clone, Default)]
+pub struct TemplateDefinations {
+    data: HashMap<String, Definition>,
+    // every template which has been resolved, and the value it resolved to
+    resolved: BTreeMap<String, Definition>,
+}
+
 impl TemplateDefinations {
+    pub fn test_constructor<I, S>(iter: I) -> Self
+    where
+        String: From<S>,
+        I: IntoIterator<Item = (S, usize)>,
+    {
+        let mut t_def = Self::default();
+        for (name, value) in iter {
+            t_def.define(name, value, TemplateSource::Cli);
+        }
+        t_def
+    }
 
-    pub fn test_constructor<I,S>(iter: I) -> Self
+    /// gives a template a value, replacing any earlier definition
+    pub fn define<S>(&mut self, name: S, value: usize, source: TemplateSource)
     where
         String: From<S>,
-        I: IntoIterator<Item=(S,usize)>,
     {
-        Self {
-            data: iter.into_iter().map(|(a,b)| (String::from(a),b)).collect(),
+        self.data
+            .insert(String::from(name), Definition { value, source });
+    }
+
+    /// parses a `NAME=VALUE` definition
+    pub fn parse_define(arg: &str) -> Result<(String, usize), String> {
+        let mut parts = arg.splitn(2, '=');
+        let name = parts.next().unwrap_or("").trim();
+        let value = match parts.next() {
+            Option::Some(value) => value.trim(),
+            Option::None => return Err(format!("`{}` is not of the form NAME=VALUE", arg)),
+        };
+        if name.is_empty() {
+            return Err(format!("`{}` does not name a template", arg));
+        }
+        match value.parse::<usize>() {
+            Ok(value) => Ok((name.to_string(), value)),
+            Err(_) => Err(format!(
+                "template `{}` must be a non-negative integer, found `{}`",
+                name, value
+            )),
+        }
+    }
+
+    /// defines every template within a json object of the form `{"NAME": 4}`
+    pub fn load_file(&mut self, path: &Path) -> Result<(), String> {
+        let text = match read_to_string(path) {
+            Ok(text) => text,
+            Err(e) => return Err(format!("cannot read `{}`: {}", path.display(), e)),
+        };
+        let object = match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&text)
+        {
+            Ok(object) => object,
+            Err(e) => return Err(format!("`{}` is not a json object: {}", path.display(), e)),
+        };
+        for (name, value) in object {
+            let value = match value.as_u64() {
+                Option::Some(value) => value as usize,
+                Option::None => {
+                    return Err(format!(
+                        "`{}`: template `{}` must be a non-negative integer, found `{}`",
+                        path.display(),
+                        name,
+                        value
+                    ))
+                }
+            };
+            self.define(name, value, TemplateSource::File(path.to_path_buf()));
+        }
+        Ok(())
+    }
+
+    /// defines a template for every variable named `prefix` + `NAME`,
+    /// variables whose values are not integers are ignored.
+    pub fn load_env<I>(&mut self, prefix: &str, vars: I)
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (var, value) in vars {
+            let name = match var.strip_prefix(prefix) {
+                Option::Some(name) if !name.is_empty() => name.to_string(),
+                _ => continue,
+            };
+            if let Ok(value) = value.parse::<usize>() {
+                self.define(name, value, TemplateSource::Env(var));
+            }
         }
     }
 
+    /// every template resolved so far, by name
+    pub fn resolved(&self) -> impl Iterator<Item = (&str, &Definition)> {
+        self.resolved.iter().map(|(name, def)| (name.as_str(), def))
+    }
+
     /// attempts to find value for a template
-    pub fn get_value<E>(&mut self, template: &Template) -> Result<usize,E>
+    pub fn get_value<E>(&mut self, template: &Template) -> Result<usize, E>
+    where
+        E: ValidationErrors,
+    {
+        let def = self.find_value::<E>(template)?;
+        let value = def.value;
+        self.resolved
+            .insert(template.ident.get_span().to_string(), def);
+        Ok(value)
+    }
+
+    fn find_value<E>(&mut self, template: &Template) -> Result<Definition, E>
     where
-        E: ValidationErrors
+        E: ValidationErrors,
     {
         if let Some(x) = self.lookup_ident(&template.ident) {
             return Ok(x);
         }
 
-        match &template.behavior {
-            &Option::None => {
-                Err(E::no_value_for_template(template))
-            },
-            &Option::Some(TemplateBehavior::Fallback(TemplateFallback::Num(ref val))) => {
-                match usize::from_str_radix(val.get_span(), 10) {
-                    Ok(x) => Ok(x),
+        match template.behavior {
+            Option::None => Err(E::no_value_for_template(template)),
+            Option::Some(TemplateBehavior::Fallback(TemplateFallback::Num(ref val))) => {
+                match val.get_span().parse::<usize>() {
+                    Ok(x) => Ok(Definition {
+                        value: x,
+                        source: TemplateSource::Fallback(template.get_clone()),
+                    }),
                     Err(_) => Err(E::unparsable_template_fallback(template, val.as_ref())),
                 }
-            },
-            &Option::Some(TemplateBehavior::Assign(TemplateFallback::Num(ref val))) => {
-                match usize::from_str_radix(val.get_span(), 10) {
-                    Ok(x) => {
-                        self.insert_ident(&template.ident, x);
-                        Ok(x)
-                    }
-                    Err(_) => return Err(E::unparsable_template_fallback(template,val.as_ref()))
+            }
+            Option::Some(TemplateBehavior::Assign(TemplateFallback::Num(ref val))) => {
+                match val.get_span().parse::<usize>() {
+                    Ok(x) => Ok(self.insert_ident(template, x)),
+                    Err(_) => Err(E::unparsable_template_fallback(template, val.as_ref())),
                 }
             }
-            &Option::Some(TemplateBehavior::Fallback(TemplateFallback::Template(ref t))) => {
+            Option::Some(TemplateBehavior::Fallback(TemplateFallback::Template(ref t))) => {
                 match self.get_value::<E>(t) {
-                    Ok(x) => Ok(x),
-                    Err(e) => Err(E::recursive_template_error(template,e))
+                    Ok(x) => Ok(Definition {
+                        value: x,
+                        source: TemplateSource::Fallback(template.get_clone()),
+                    }),
+                    Err(e) => Err(E::recursive_template_error(template, e)),
                 }
             }
-            &Option::Some(TemplateBehavior::Assign(TemplateFallback::Template(ref t))) => {
+            Option::Some(TemplateBehavior::Assign(TemplateFallback::Template(ref t))) => {
                 match self.get_value::<E>(t) {
-                    Ok(x) => {
-                        self.insert_ident(&template.ident, x);
-                        Ok(x)
-                    }
-                    Err(e) => Err(E::recursive_template_error(template,e))
+                    Ok(x) => Ok(self.insert_ident(template, x)),
+                    Err(e) => Err(E::recursive_template_error(template, e)),
                 }
             }
         }
     }
 
-    fn insert_ident(&mut self, ident: &Ident, value: usize) {
-        self.data.insert(ident.get_span().to_string(), value);
+    fn insert_ident(&mut self, template: &Template, value: usize) -> Definition {
+        let def = Definition {
+            value,
+            source: TemplateSource::Assign(template.get_clone()),
+        };
+        self.data
+            .insert(template.ident.get_span().to_string(), def.clone());
+        def
     }
-    
-    fn lookup_ident(&self, ident: &Ident) -> Option<usize> {
-        self.data.get(ident.get_span()).map(|x| x.clone())
+
+    fn lookup_ident(&self, ident: &Ident) -> Option<Definition> {
+        self.data.get(ident.get_span()).cloned()
     }
 }
+
+#[test]
+fn template_values_record_their_source() {
+    use crate::internals::{
+        canonization::{
+            kinds::{primative::Prim, workable::TypeDataTrait},
+            to_ast::{namespace::Namespace, partial_ast::analysis::collect_outputs},
+        },
+        errors::term_errors::HumanReadable,
+        parser::generated::parse_code,
+    };
+
+    assert_eq!(
+        TemplateDefinations::parse_define("AP = 2"),
+        Ok(("AP".to_string(), 2))
+    );
+    assert!(TemplateDefinations::parse_define("AP").is_err());
+    assert!(TemplateDefinations::parse_define("=2").is_err());
+    assert!(TemplateDefinations::parse_define("AP=-1").is_err());
+
+    let mut t_def = TemplateDefinations::default();
+    t_def.load_env(
+        ENV_PREFIX,
+        vec![
+            ("FOXHOLE_S".to_string(), "3".to_string()),
+            ("FOXHOLE_INPUT_FILE".to_string(), "main.fx".to_string()),
+            ("HOME".to_string(), "4".to_string()),
+        ],
+    );
+    t_def.define("T", 5, TemplateSource::Cli);
+
+    let source = "${S} + ${T} + ${A := 1} + ${A} + ${B :- ${S}}";
+    let body = parse_code::<HumanReadable>(source).unwrap();
+    let outputs = collect_outputs::<HumanReadable>(&body, &mut t_def, &mut Namespace::default())
+        .ok()
+        .unwrap();
+    assert_eq!(
+        outputs[0].expr.get_prim(),
+        Some(&Prim::new_int_constant(13))
+    );
+
+    let resolved = t_def
+        .resolved()
+        .map(|(name, def)| format!("{}={} {}", name, def.value, def.source))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        resolved,
+        vec![
+            "A=1 assigned at <anon>:1:15",
+            "B=3 fallback at <anon>:1:34",
+            "S=3 $FOXHOLE_S",
+            "T=5 --define",
+        ]
+    );
+
+    // nothing is taken from the environment unless it's loaded, the
+    // process environment is left alone as tests run in parallel
+    let body = parse_code::<HumanReadable>("${S}").unwrap();
+    let mut unloaded = TemplateDefinations::default();
+    assert!(
+        collect_outputs::<HumanReadable>(&body, &mut unloaded, &mut Namespace::default()).is_err()
+    );
+    unloaded.load_env(ENV_PREFIX, vec![("FOXHOLE_S".to_string(), "3".to_string())]);
+    assert!(
+        collect_outputs::<HumanReadable>(&body, &mut unloaded, &mut Namespace::default()).is_ok()
+    );
+
+    let path = std::env::temp_dir().join(format!("foxhole-defines-{}.json", std::process::id()));
+    std::fs::write(&path, "{\"S\": 6, \"T\": 1}").unwrap();
+    t_def.load_file(&path).unwrap();
+    assert_eq!(t_def.data["S"].source, TemplateSource::File(path.clone()));
+    std::fs::write(&path, "{\"S\": \"six\"}").unwrap();
+    assert!(t_def.load_file(&path).is_err());
+    let _ = std::fs::remove_file(&path);
+}
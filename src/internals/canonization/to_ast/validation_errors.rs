@@ -1,16 +1,23 @@
 
-use crate::internals::parser::{
-    span::Spanner,
-    ast::{
-        template::Template,
-        op::Op,
+use crate::internals::{
+    parser::{
+        span::Spanner,
+        ast::{
+            template::Template,
+            op::{Op,UnaryOp},
+        },
     },
+    canonization::kinds::{primative::int::Integer, workable::TypeData},
 };
 
 // Covers a lot of the errors that can occur while validating the parse tree
 pub trait ValidationErrors: Sized {
 
     fn malformed_int<A,B>(bad_int: &A, expr: &B) -> Self
+    where
+        A: Spanner,
+        B: Spanner;
+    fn dice_without_sides<A,B>(sides: &A, dice: &B) -> Self
     where
         A: Spanner,
         B: Spanner;
@@ -37,6 +44,82 @@ pub trait ValidationErrors: Sized {
     where
         L: Spanner,
         R: Spanner;
+    fn unary_op_type_error<S>(op: &UnaryOp, arg: &S) -> Self
+    where
+        S: Spanner;
+    fn integer_overflow<S>(arg: &S) -> Self
+    where
+        S: Spanner;
+    fn not_yet_analyzable<S>(arg: &S) -> Self
+    where
+        S: Spanner;
+    fn arm_type_mismatch<F,S>(first: &F, other: &S) -> Self
+    where
+        F: Spanner,
+        S: Spanner;
+    fn annotation_mismatch<V,A>(value: &V, found: &TypeData, annotated: &A, expected: &TypeData) -> Self
+    where
+        V: Spanner,
+        A: Spanner;
+    fn expected_boolean<S>(arg: &S) -> Self
+    where
+        S: Spanner;
+    fn not_constant<U,A>(unknown: &U, found: &TypeData, assign: &A) -> Self
+    where
+        U: Spanner,
+        A: Spanner;
+    fn rolled_in_const<U,R,A>(usage: &U, rolled: &R, assign: &A) -> Self
+    where
+        U: Spanner,
+        R: Spanner,
+        A: Spanner;
+
+    /*
+     * Invocation Errors
+     *
+     */
+    fn wrong_arg_count<I,F>(invoke: &I, expected: usize, found: usize, func: &F) -> Self
+    where
+        I: Spanner,
+        F: Spanner;
+    fn recursive_call<C,F>(call: &C, func: &F, cycle: &[String]) -> Self
+    where
+        C: Spanner,
+        F: Spanner;
+    fn arg_type_mismatch<A,P>(arg: &A, found: &TypeData, param: &P, expected: &TypeData) -> Self
+    where
+        A: Spanner,
+        P: Spanner;
+
+    /*
+     * Match Errors
+     *
+     */
+    fn non_exhaustive_match<M>(m: &M, missing: &Integer) -> Self
+    where
+        M: Spanner;
+    fn empty_pattern_range<S>(pattern: &S) -> Self
+    where
+        S: Spanner;
+
+    /*
+     * Collection Errors
+     *
+     */
+    fn collection_element_error<C,S>(coll: &C, element: &S) -> Self
+    where
+        C: Spanner,
+        S: Spanner;
+    fn index_out_of_bounds<I,C>(index: &I, index_range: &Integer, coll: &C, len: &Integer) -> Self
+    where
+        I: Spanner,
+        C: Spanner;
+    fn expected_collection<S>(arg: &S) -> Self
+    where
+        S: Spanner;
+    fn expected_integer<S>(arg: &S) -> Self
+    where
+        S: Spanner;
 
     /*
      * Namespace collisions
@@ -51,4 +134,33 @@ pub trait ValidationErrors: Sized {
     where
         N: Spanner,
         O: Spanner;
+
+    /// a top level variable of an imported file, only functions &
+    /// compositional functions are imported.
+    fn imported_var<S>(var: &S, name: &str) -> Self
+    where
+        S: Spanner;
+
+    /*
+     * Name resolution
+     *
+     */
+    fn undefined_var<U>(usage: &U) -> Self
+    where
+        U: Spanner;
+    fn undefined_func<U>(usage: &U) -> Self
+    where
+        U: Spanner;
+    fn not_in_scope<U,D>(usage: &U, def: &D) -> Self
+    where
+        U: Spanner,
+        D: Spanner;
+    fn not_a_function<U,D>(usage: &U, def: &D) -> Self
+    where
+        U: Spanner,
+        D: Spanner;
+    fn not_a_value<U,D>(usage: &U, def: &D) -> Self
+    where
+        U: Spanner,
+        D: Spanner;
 }
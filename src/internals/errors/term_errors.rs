@@ -3,6 +3,7 @@ use std::{
     fmt,
     hash::Hash,
     iter::{once, repeat},
+    path::{Path, PathBuf},
 };
 
 use ansi_term::{Color, Style};
@@ -10,9 +11,20 @@ use itertools::Itertools;
 use lrpar::{Lexer, NonStreamingLexer};
 use num_traits::{PrimInt, Unsigned};
 
-use crate::internals::parser::{
-    span::{Span, Spanner},
-    traits::SyntaxError,
+use crate::internals::{
+    canonization::{
+        kinds::{primative::int::Integer, workable::TypeData},
+        to_ast::{lint_warnings::LintWarnings, lints::Lint, validation_errors::ValidationErrors},
+    },
+    parser::{
+        ast::{
+            import::Import,
+            op::{Op, UnaryOp},
+            template::Template,
+        },
+        span::{Span, Spanner},
+        traits::{ImportError, SyntaxError},
+    },
 };
 
 /// HumanReadable Errors
@@ -38,6 +50,12 @@ impl fmt::Display for HumanReadable {
     }
 }
 
+/// every diagnostic as it would be shown, for a test to inspect
+#[cfg(test)]
+pub(crate) fn render_for_test(diagnostics: Vec<HumanReadable>) -> Vec<String> {
+    diagnostics.iter().map(|d| d.to_string()).collect()
+}
+
 impl SyntaxError for HumanReadable {
     fn lex_error<'a, U, L>(_lexer: &L, _source: &'a str, span: &Span) -> Self
     where
@@ -48,15 +66,17 @@ impl SyntaxError for HumanReadable {
             arg: vec![
                 Item::from(Color::Red.normal().paint("ERROR")),
                 Item::from(" Unrecongized Lex Item"),
+                Item::from(EOL),
             ]
             .into_iter()
-            .chain(format_text(span).into_iter())
+            .chain(format_location(span))
+            .chain(format_text(span))
             .chain(once(Item::from(EOL)))
             .collect(),
         }
     }
 
-    fn parse_error<'a, U, L>(_lexer: &L, _source: &'a str, span: &Span) -> Self
+    fn parse_error<'a, U, L>(_lexer: &L, _source: &'a str, span: &Span, repairs: &[String]) -> Self
     where
         U: Unsigned + PrimInt + Hash,
         L: NonStreamingLexer<'a, U> + Lexer<U> + ?Sized,
@@ -65,16 +85,545 @@ impl SyntaxError for HumanReadable {
             arg: vec![
                 Item::from(Color::Red.normal().paint("ERROR")),
                 Item::from(" Parse error"),
+                Item::from(EOL),
+            ]
+            .into_iter()
+            .chain(format_location(span))
+            .chain(format_text(span))
+            .chain(once(Item::from(EOL)))
+            .chain(format_repairs(repairs))
+            .collect(),
+        }
+    }
+
+    fn dice_as_name<'a, U, L>(_lexer: &L, _source: &'a str, span: &Span) -> Self
+    where
+        U: Unsigned + PrimInt + Hash,
+        L: NonStreamingLexer<'a, U> + Lexer<U> + ?Sized,
+    {
+        let dice = span.get_span();
+        Self::error(format!("`{}` is a dice literal, not a name", dice), span).help(format!(
+            "`d` followed by digits always rolls dice, rename it (e.g. `{}_roll`)",
+            dice
+        ))
+    }
+}
+
+impl ImportError for HumanReadable {
+    fn unreadable_file(path: &Path, reason: String, import: Option<&Import>) -> Self {
+        let message = format!("cannot read `{}`: {}", path.display(), reason);
+        match import {
+            Option::Some(import) => Self::error(message, import),
+            Option::None => Self {
+                arg: vec![
+                    Item::from(Color::Red.normal().paint("ERROR")),
+                    Item::from(" "),
+                    Item::from(message),
+                    Item::from(EOL),
+                ],
+            },
+        }
+    }
+
+    fn import_cycle(import: &Import, cycle: &[PathBuf]) -> Self {
+        let cycle = cycle
+            .iter()
+            .map(|path| format!("`{}`", path.display()))
+            .join(" -> ");
+        Self::error(format!("import cycle: {}", cycle), import)
+    }
+}
+
+impl HumanReadable {
+    /// an error message which points at the source of the error
+    fn error<S>(message: String, span: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self {
+            arg: vec![
+                Item::from(Color::Red.normal().paint("ERROR")),
+                Item::from(" "),
+                Item::from(message),
+                Item::from(EOL),
             ]
             .into_iter()
-            .chain(format_text(span).into_iter())
+            .chain(format_location(span))
+            .chain(format_text(&span.get_clone()))
             .chain(once(Item::from(EOL)))
             .collect(),
         }
     }
+
+    /// a warning message which points at its source, the lint which
+    /// silences it is named by `allow`
+    fn warning<S>(message: String, span: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self {
+            arg: vec![
+                Item::from(Color::Yellow.normal().paint("WARNING")),
+                Item::from(" "),
+                Item::from(message),
+                Item::from(EOL),
+            ]
+            .into_iter()
+            .chain(format_location(span))
+            .chain(format_text(&span.get_clone()))
+            .chain(once(Item::from(EOL)))
+            .collect(),
+        }
+    }
+
+    /// names the lint which silences the warning
+    fn allow(self, lint: Lint) -> Self {
+        self.help(format!("`--allow {}` silences this", lint))
+    }
+
+    /// attaches a secondary location to the error
+    fn note<S>(mut self, message: String, span: &S) -> Self
+    where
+        S: Spanner,
+    {
+        self.arg.extend(
+            vec![
+                Item::from(Color::Cyan.normal().paint("     note")),
+                Item::from(": "),
+                Item::from(message),
+                Item::from(EOL),
+            ]
+            .into_iter()
+            .chain(format_location(span))
+            .chain(format_text(&span.get_clone()))
+            .chain(once(Item::from(EOL))),
+        );
+        self
+    }
+
+    /// suggests a fix for the error
+    fn help(mut self, message: String) -> Self {
+        self.arg.extend(vec![
+            Item::from(Color::Cyan.normal().paint("     help")),
+            Item::from(": "),
+            Item::from(message),
+            Item::from(EOL),
+        ]);
+        self
+    }
 }
 
-const EOL: &'static str = {
+impl LintWarnings for HumanReadable {
+    fn unused_variable<S>(var: &S, name: &str) -> Self
+    where
+        S: Spanner,
+    {
+        Self::warning(format!("variable `{}` is never read", name), var).allow(Lint::UnusedVariable)
+    }
+
+    fn unused_function<S>(func: &S, name: &str) -> Self
+    where
+        S: Spanner,
+    {
+        Self::warning(format!("function `{}` is never used", name), func).allow(Lint::UnusedFunction)
+    }
+
+    fn unused_parameter<A, F>(arg: &A, name: &str, func: &F) -> Self
+    where
+        A: Spanner,
+        F: Spanner,
+    {
+        Self::warning(format!("parameter `{}` is never read", name), arg)
+            .note("within this function".to_string(), func)
+            .allow(Lint::UnusedParameter)
+    }
+
+    fn shadowed_binding<S, O>(binding: &S, name: &str, outer: &O) -> Self
+    where
+        S: Spanner,
+        O: Spanner,
+    {
+        Self::warning(format!("binding `{}` shadows an outer variable", name), binding)
+            .note("the outer variable is declared here".to_string(), outer)
+            .allow(Lint::ShadowedBinding)
+    }
+
+    fn impossible_branch<A, C>(arm: &A, condition: &C, outcome: bool) -> Self
+    where
+        A: Spanner,
+        C: Spanner,
+    {
+        Self::warning("this branch is never taken".to_string(), arm)
+            .note(format!("this condition is never `{}` here", outcome), condition)
+            .allow(Lint::ImpossibleBranch)
+    }
+}
+
+impl ValidationErrors for HumanReadable {
+    fn malformed_int<A, B>(bad_int: &A, expr: &B) -> Self
+    where
+        A: Spanner,
+        B: Spanner,
+    {
+        Self::error(
+            format!("`{}` is not a valid integer", bad_int.get_span()),
+            expr,
+        )
+    }
+
+    fn dice_without_sides<A, B>(sides: &A, dice: &B) -> Self
+    where
+        A: Spanner,
+        B: Spanner,
+    {
+        Self::error("dice must have at least one side".to_string(), sides)
+            .note("within this dice literal".to_string(), dice)
+    }
+
+    fn no_value_for_template(template: &Template) -> Self {
+        let name = template.ident.get_span();
+        Self::error(
+            format!("no value was given for template `{}`", name),
+            template,
+        )
+        .help(format!(
+            "pass `--define {0}=<value>`, list it within a `--defines` file, or give it a fallback `${{{0} :- <value>}}`",
+            name
+        ))
+    }
+
+    fn unparsable_template_fallback<S>(template: &Template, value: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error(
+            format!(
+                "fallback `{}` for template `{}` is not a valid integer",
+                value.get_span(),
+                template.ident.get_span()
+            ),
+            template,
+        )
+    }
+
+    fn recursive_template_error(template: &Template, interior: Self) -> Self {
+        let mut err = Self::error(
+            format!(
+                "cannot resolve template `{}`",
+                template.ident.get_span()
+            ),
+            template,
+        );
+        err.arg.extend(interior.arg);
+        err
+    }
+
+    fn no_type_information<S>(arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error(
+            "the type of this expression cannot be determined".to_string(),
+            arg,
+        )
+    }
+
+    fn trinary_op_type_error<L, R>(l: &L, op: &Op, r: &R) -> Self
+    where
+        L: Spanner,
+        R: Spanner,
+    {
+        Self::error(
+            format!("operator `{}` cannot be applied to these types", op.symbol()),
+            l,
+        )
+        .note("right hand side".to_string(), r)
+    }
+
+    fn unary_op_type_error<S>(op: &UnaryOp, arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error(
+            format!("operator `{}` cannot be applied to this type", op.symbol()),
+            arg,
+        )
+    }
+
+    fn integer_overflow<S>(arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error("this overflows an `int`".to_string(), arg).help(format!(
+            "an `int` lies within `{}..={}`",
+            i64::MIN,
+            i64::MAX
+        ))
+    }
+
+    fn not_yet_analyzable<S>(arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error(
+            "this kind of expression cannot be analyzed yet".to_string(),
+            arg,
+        )
+    }
+
+    fn arm_type_mismatch<F, S>(first: &F, other: &S) -> Self
+    where
+        F: Spanner,
+        S: Spanner,
+    {
+        Self::error(
+            "this arm's type does not match the first arm".to_string(),
+            other,
+        )
+        .note("the first arm".to_string(), first)
+    }
+
+    fn annotation_mismatch<V, A>(value: &V, found: &TypeData, annotated: &A, expected: &TypeData) -> Self
+    where
+        V: Spanner,
+        A: Spanner,
+    {
+        Self::error(
+            format!("expected `{}`, found `{}`", expected, found),
+            value,
+        )
+        .note("the type is declared here".to_string(), annotated)
+    }
+
+    fn expected_boolean<S>(arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error("expected a `bool`".to_string(), arg)
+    }
+
+    fn not_constant<U, A>(unknown: &U, found: &TypeData, assign: &A) -> Self
+    where
+        U: Spanner,
+        A: Spanner,
+    {
+        Self::error(
+            format!("expected a value known before any dice are rolled, found `{}`", found),
+            unknown,
+        )
+        .note("the variable is declared `const` here".to_string(), assign)
+        .help("use `let` for a value which is only known at runtime".to_string())
+    }
+
+    fn rolled_in_const<U, R, A>(usage: &U, rolled: &R, assign: &A) -> Self
+    where
+        U: Spanner,
+        R: Spanner,
+        A: Spanner,
+    {
+        let mut err = Self::error(
+            "expected a value known before any dice are rolled".to_string(),
+            usage,
+        );
+        if usage.as_ref() != rolled.as_ref() {
+            err = err.note("this is only known once dice are rolled".to_string(), rolled);
+        }
+        err.note("the variable is declared `const` here".to_string(), assign)
+            .help("use `let` for a value which is only known at runtime".to_string())
+    }
+
+    fn wrong_arg_count<I, F>(invoke: &I, expected: usize, found: usize, func: &F) -> Self
+    where
+        I: Spanner,
+        F: Spanner,
+    {
+        let plural = |n: usize| if n == 1 { "argument" } else { "arguments" };
+        Self::error(
+            format!(
+                "this function takes {} {} but {} {} supplied",
+                expected,
+                plural(expected),
+                found,
+                if found == 1 { "was" } else { "were" }
+            ),
+            invoke,
+        )
+        .note("the function is defined here".to_string(), func)
+    }
+
+    fn recursive_call<C, F>(call: &C, func: &F, cycle: &[String]) -> Self
+    where
+        C: Spanner,
+        F: Spanner,
+    {
+        let through = cycle[1..].iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>();
+        let msg = if through.is_empty() {
+            format!("`{}` calls itself", cycle[0])
+        } else {
+            format!("`{}` calls itself through {}", cycle[0], through.join(", "))
+        };
+        Self::error(msg, call)
+            .note("the function is defined here".to_string(), func)
+            .help("every call is expanded when evaluated, recursion has no bound".to_string())
+    }
+
+    fn arg_type_mismatch<A, P>(arg: &A, found: &TypeData, param: &P, expected: &TypeData) -> Self
+    where
+        A: Spanner,
+        P: Spanner,
+    {
+        Self::error(
+            format!("expected `{}`, found `{}`", expected, found),
+            arg,
+        )
+        .note("the parameter is declared here".to_string(), param)
+    }
+
+    fn non_exhaustive_match<M>(m: &M, missing: &Integer) -> Self
+    where
+        M: Spanner,
+    {
+        Self::error(
+            format!("match is not exhaustive, `{}` is not covered", missing),
+            m,
+        )
+    }
+
+    fn empty_pattern_range<S>(pattern: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error(
+            "this range pattern matches no values".to_string(),
+            pattern,
+        )
+    }
+
+    fn collection_element_error<C, S>(coll: &C, element: &S) -> Self
+    where
+        C: Spanner,
+        S: Spanner,
+    {
+        Self::error(
+            "collection elements must all be `int`, or all be `bool`".to_string(),
+            element,
+        )
+        .note("within this collection".to_string(), coll)
+    }
+
+    fn index_out_of_bounds<I, C>(index: &I, index_range: &Integer, coll: &C, len: &Integer) -> Self
+    where
+        I: Spanner,
+        C: Spanner,
+    {
+        Self::error(
+            format!(
+                "index `{}` is out of bounds for a collection of length `{}`",
+                index_range, len
+            ),
+            index,
+        )
+        .note("the collection".to_string(), coll)
+    }
+
+    fn expected_collection<S>(arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error("expected a collection".to_string(), arg)
+    }
+
+    fn expected_integer<S>(arg: &S) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error("expected an `int`".to_string(), arg)
+    }
+
+    fn var_conflict<N, O>(new: &N, old: &O) -> Self
+    where
+        N: Spanner,
+        O: Spanner,
+    {
+        Self::error("this variable is already defined".to_string(), new)
+        .note("previously defined here".to_string(), old)
+    }
+
+    fn func_conflict<N, O>(new: &N, old: &O) -> Self
+    where
+        N: Spanner,
+        O: Spanner,
+    {
+        Self::error("this function is already defined".to_string(), new)
+        .note("previously defined here".to_string(), old)
+    }
+
+    fn imported_var<S>(var: &S, name: &str) -> Self
+    where
+        S: Spanner,
+    {
+        Self::error(format!("`{}` is declared at the top level of an imported file", name), var)
+            .help("an import only brings in `fn` & `cm` declarations, declare variables in the importing file".to_string())
+    }
+
+    fn undefined_var<U>(usage: &U) -> Self
+    where
+        U: Spanner,
+    {
+        Self::error(
+            format!("cannot find variable `{}` in this scope", usage.get_span()),
+            usage,
+        )
+    }
+
+    fn undefined_func<U>(usage: &U) -> Self
+    where
+        U: Spanner,
+    {
+        Self::error(
+            format!("cannot find function `{}` in this scope", usage.get_span()),
+            usage,
+        )
+    }
+
+    fn not_in_scope<U, D>(usage: &U, def: &D) -> Self
+    where
+        U: Spanner,
+        D: Spanner,
+    {
+        Self::error(
+            format!("`{}` is not in scope here", usage.get_span()),
+            usage,
+        )
+        .note("it is only defined here, later or within another scope".to_string(), def)
+    }
+
+    fn not_a_function<U, D>(usage: &U, def: &D) -> Self
+    where
+        U: Spanner,
+        D: Spanner,
+    {
+        Self::error(
+            format!("`{}` is a variable, not a function", usage.get_span()),
+            usage,
+        )
+        .note("the variable is defined here".to_string(), def)
+    }
+
+    fn not_a_value<U, D>(usage: &U, def: &D) -> Self
+    where
+        U: Spanner,
+        D: Spanner,
+    {
+        Self::error(
+            format!("`{}` is a function, not a value", usage.get_span()),
+            usage,
+        )
+        .note("the function is defined here".to_string(), def)
+    }
+}
+
+const EOL: &str = {
     #[cfg(target_family = "unix")]
     {
         "\n"
@@ -106,13 +655,9 @@ impl From<String> for Item {
         Item::Owned(arg)
     }
 }
-impl<'a, S> From<ansi_term::ANSIGenericString<'a, S>> for Item
-where
-    S: 'a + ToOwned + ?Sized + fmt::Debug,
-    <S as ToOwned>::Owned: fmt::Debug,
-{
-    fn from(arg: ansi_term::ANSIGenericString<'a, S>) -> Item {
-        Item::Owned(format!("{:?}", arg))
+impl<'a> From<ansi_term::ANSIString<'a>> for Item {
+    fn from(arg: ansi_term::ANSIString<'a>) -> Item {
+        Item::Owned(format!("{}", arg))
     }
 }
 impl From<usize> for Item {
@@ -121,6 +666,42 @@ impl From<usize> for Item {
     }
 }
 
+/// error recovery can find a lot of equally good repairs,
+/// only the first few are worth showing to a human.
+const MAX_REPAIRS: usize = 3;
+
+fn format_repairs(repairs: &[String]) -> Vec<Item> {
+    repairs
+        .iter()
+        .take(MAX_REPAIRS)
+        .flat_map(|repair| {
+            vec![
+                Item::from(Color::Cyan.normal().paint("     help")),
+                Item::from(": try to "),
+                Item::new(repair),
+                Item::from(EOL),
+            ]
+        })
+        .collect()
+}
+
+/// where a span is, as `path:line:col` (the way rustc reports it)
+fn format_location<S>(span: &S) -> Vec<Item>
+where
+    S: Spanner,
+{
+    vec![
+        Item::from(Color::Blue.normal().paint("  --> ")),
+        Item::from(format!(
+            "{}:{}:{}",
+            span.get_source().get_name(),
+            span.get_start_line(),
+            span.get_start_column()
+        )),
+        Item::from(EOL),
+    ]
+}
+
 fn format_text(span: &Span) -> Vec<Item> {
     let (prefix, token, suffix) = span.get_prefix_token_suffix();
     if span.is_one_line() {
@@ -132,7 +713,7 @@ fn format_text(span: &Span) -> Vec<Item> {
         ]
     } else {
         (span.get_start_line()..(span.get_end_line() + 2))
-            .map(|x| Item::from(x))
+            .map(Item::from)
             .interleave_shortest(
                 prefix
                     .lines()
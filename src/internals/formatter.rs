@@ -0,0 +1,537 @@
+//! Formatter
+//!
+//! Re-emits parsed source in one canonical style: one statement per
+//! line, single spaces around binary operators, and four space
+//! indentation within `fn`, `if`, and block bodies. A single blank line
+//! between statements is kept, runs of blank lines are collapsed.
+//!
+//! Ordinary comments are not part of the AST, they're recovered from
+//! the source text. A comment is emitted on its own line before the
+//! statement it precedes, or after the statement it trails (on the
+//! same line). A `/* */` comment within an expression stays beside the
+//! code it precedes (or the operand before an operator), while a `//`
+//! comment there is moved after the statement containing it.
+
+use std::collections::VecDeque;
+
+use crate::internals::parser::{
+    ast::{
+        block::Block,
+        comparg::{CompositionalArg, CompositionalFunction, CompositionalFunctionArg},
+        condition::Conditional,
+        doc::Doc,
+        expr::{Expr, Expression},
+        func::FunctionDec,
+        kind::Kind,
+        matching::PatternKind,
+        statement::{State, Statement},
+    },
+    generated::parse_named,
+    source_map::SourceName,
+    span::Spanner,
+    traits::SyntaxError,
+};
+
+const INDENT: &str = "    ";
+
+/// Parses `source` and returns it in the canonical style.
+///
+/// Source which fails to parse is never formatted, the syntax errors
+/// (reported within `name`) are returned instead.
+pub fn format_code<E>(name: SourceName, source: &str) -> Result<String, Vec<E>>
+where
+    E: SyntaxError,
+{
+    let body = parse_named::<E>(name, source)?;
+    let mut printer = Printer::new(source);
+    printer.statements(&body.body);
+    printer.comments_before(source.len());
+    Ok(printer.out)
+}
+
+/// A comment's byte range within the source
+struct Comment {
+    start: usize,
+    end: usize,
+}
+
+/// Finds every comment which the lexer skips. `///` doc comments are
+/// part of the AST, so they are not returned.
+fn find_comments(source: &str) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+    let line_end = |from: usize| {
+        bytes[from..]
+            .iter()
+            .position(|b| *b == b'\n' || *b == b'\r')
+            .map(|i| from + i)
+            .unwrap_or(bytes.len())
+    };
+    let mut comments = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let end = if rest.starts_with(b"///") && rest.get(3) != Some(&b'/') {
+            // doc comment, retained by the parser
+            i = line_end(i);
+            continue;
+        } else if rest.starts_with(b"//") || rest.starts_with(b"#!") {
+            line_end(i)
+        } else if rest.starts_with(b"\"") {
+            // string literals may contain `//`
+            i = rest[1..]
+                .iter()
+                .position(|b| *b == b'"' || *b == b'\n')
+                .map(|p| i + p + 2)
+                .unwrap_or(bytes.len());
+            continue;
+        } else if rest.starts_with(b"/*") {
+            rest[2..]
+                .windows(2)
+                .position(|w| w == b"*/")
+                .map(|p| i + p + 4)
+                .unwrap_or(bytes.len())
+        } else {
+            i += 1;
+            continue;
+        };
+        comments.push(Comment { start: i, end });
+        i = end;
+    }
+    comments
+}
+
+struct Printer<'a> {
+    source: &'a str,
+    comments: VecDeque<Comment>,
+    out: String,
+    depth: usize,
+    // end of the last item emitted at the current depth, `None` at
+    // the start of a body, where blank lines are never kept.
+    last: Option<usize>,
+}
+
+impl<'a> Printer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            comments: find_comments(source).into_iter().collect(),
+            out: String::with_capacity(source.len()),
+            depth: 0,
+            last: None,
+        }
+    }
+
+    fn push(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.depth {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn newline(&mut self) {
+        self.out.push('\n');
+        self.indent();
+    }
+
+    /// starts a line for an item beginning at `start` in the source,
+    /// keeping (at most one) blank line which preceded it.
+    fn begin_item(&mut self, start: usize) {
+        if let Option::Some(last) = self.last {
+            // the gap is only whitespace, a blank line is a second newline
+            let gap = &self.source[last.min(start)..start];
+            if gap.matches('\n').count() > 1 {
+                self.out.push('\n');
+            }
+        }
+        self.indent();
+    }
+
+    /// emits every pending comment which starts before `pos` on its own line
+    fn comments_before(&mut self, pos: usize) {
+        while self.comments.front().map(|c| c.start < pos).unwrap_or(false) {
+            let comment = self.comments.pop_front().unwrap();
+            self.begin_item(comment.start);
+            let text = self.source[comment.start..comment.end].trim_end();
+            self.push(text);
+            self.out.push('\n');
+            self.last = Some(comment.end);
+        }
+    }
+
+    /// appends a comment which directly follows `end` on the same line
+    fn trailing_comment(&mut self, end: usize) {
+        let same_line = match self.comments.front() {
+            Option::Some(c) => {
+                // only whitespace may separate the comment from `end`
+                let gap = &self.source[end.min(c.start)..c.start];
+                c.start >= end && !gap.contains('\n') && gap.trim().is_empty()
+            }
+            Option::None => false,
+        };
+        if same_line {
+            let comment = self.comments.pop_front().unwrap();
+            self.push(" ");
+            let text = self.source[comment.start..comment.end].trim_end();
+            self.push(text);
+            self.last = Some(comment.end);
+        }
+    }
+
+    /// the first code at or after `from`, skipping whitespace and comments
+    fn code_after(&self, from: usize) -> usize {
+        let mut pos = from;
+        loop {
+            let rest = &self.source[pos..];
+            pos += rest.len() - rest.trim_start().len();
+            match self.comments.iter().find(|c| c.start == pos) {
+                Option::Some(comment) => pos = comment.end,
+                Option::None => return pos,
+            }
+        }
+    }
+
+    /// emits the pending `/* */` comments which start before `pos` within
+    /// an expression, `after` the code already emitted or before the code
+    /// which follows.
+    fn inline_comments(&mut self, pos: usize, after: bool) {
+        while let Option::Some(comment) = self.comments.front() {
+            if comment.start >= pos || !self.source[comment.start..].starts_with("/*") {
+                break;
+            }
+            let comment = self.comments.pop_front().unwrap();
+            let text = &self.source[comment.start..comment.end];
+            if after {
+                self.push(" ");
+                self.push(text);
+            } else {
+                self.push(text);
+                self.push(" ");
+            }
+        }
+    }
+
+    fn statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.comments_before(statement.get_start_byte_index());
+            self.begin_item(statement.get_start_byte_index());
+            self.statement(statement);
+            self.last = Some(statement.get_end_byte_index());
+            self.trailing_comment(statement.get_end_byte_index());
+            self.out.push('\n');
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement.sttm.as_ref() {
+            State::Declaration(assign) => {
+                self.doc(&assign.doc);
+                self.push(if assign.is_constant { "const " } else { "let " });
+                self.push(assign.name.get_span());
+                match assign.kind.as_ref() {
+                    Option::Some(kind) => {
+                        self.push(": ");
+                        self.kind(kind);
+                        self.push(" == ");
+                    }
+                    Option::None => self.push(" = "),
+                };
+                self.expr(&assign.expr);
+                self.push(";");
+            }
+            State::Func(func) => self.func(func),
+            State::CompFunc(comp) => self.comp(comp),
+            State::Termination(expr) => self.expr(expr),
+            State::Import(import) => {
+                self.push("import ");
+                self.push(import.path.get_span());
+                self.push(";");
+            }
+            State::Analysis(analyze) => {
+                self.push("analyze ");
+                self.push(analyze.name.get_span());
+                self.push(" = ");
+                self.expr(&analyze.expr);
+                self.push(";");
+            }
+        }
+    }
+
+    fn doc(&mut self, doc: &Option<Box<Doc>>) {
+        if let Option::Some(doc) = doc {
+            for line in doc.lines.iter() {
+                self.push(line.get_span().trim_end());
+                self.newline();
+            }
+        }
+    }
+
+    fn func(&mut self, func: &FunctionDec) {
+        self.doc(&func.doc);
+        self.push("fn ");
+        self.push(func.name.get_span());
+        self.push("(");
+        for (i, arg) in func.args.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.push(arg.name.get_span());
+            self.push(": ");
+            self.kind(&arg.kind);
+        }
+        self.push(") ");
+        self.kind(&func.ret);
+        self.push(" {");
+        self.out.push('\n');
+
+        self.depth += 1;
+        self.last = None;
+        self.statements(&func.body);
+        self.comments_before(func.get_end_byte_index());
+        self.depth -= 1;
+
+        self.indent();
+        self.push("}");
+    }
+
+    fn comp(&mut self, comp: &CompositionalFunction) {
+        self.doc(&comp.doc);
+        self.push("cm ");
+        self.push(comp.name.get_span());
+        self.push("(");
+        self.comp_arg(&comp.null_arg);
+        self.push(", ");
+        self.comp_arg(&comp.single_arg);
+        self.push(", ");
+        self.comp_arg(&comp.collection_arg);
+        self.push(") ");
+        self.kind(&comp.ret);
+        self.push(";");
+    }
+
+    fn comp_arg(&mut self, arg: &CompositionalFunctionArg) {
+        match &arg.arg {
+            CompositionalArg::Primative(span) => self.push(span.get_span()),
+            CompositionalArg::Template(template) => self.push(template.get_span()),
+            CompositionalArg::Func(ident) => self.push(ident.get_span()),
+            CompositionalArg::Op(op) => self.push(op.symbol()),
+        }
+    }
+
+    fn kind(&mut self, kind: &Kind) {
+        self.push(match *kind {
+            Kind::Int => "int",
+            Kind::Bool => "bool",
+            Kind::CollOfInt => "vec<int>",
+            Kind::CollOfBool => "vec<bool>",
+        });
+    }
+
+    fn list(&mut self, items: &[Expression]) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            self.expr(item);
+        }
+    }
+
+    /// `else if` chains are nested conditionals, they're printed as a chain
+    fn cond(&mut self, cond: &Conditional) {
+        self.push("if ");
+        self.expr(&cond.condition);
+        self.push(" ");
+        self.arm(&cond.true_case);
+        self.push(" else ");
+        match cond.false_case.kind.as_ref() {
+            Expr::Cond(chained) => self.cond(chained),
+            _ => self.arm(&cond.false_case),
+        }
+    }
+
+    /// an `if` arm, its braces are part of the arm unless it's a block
+    fn arm(&mut self, e: &Expression) {
+        match e.kind.as_ref() {
+            Expr::Block(block) => self.block(block),
+            _ => {
+                self.push("{");
+                self.depth += 1;
+                self.newline();
+                self.expr(e);
+                self.depth -= 1;
+                self.newline();
+                self.push("}");
+            }
+        }
+    }
+
+    fn block(&mut self, block: &Block) {
+        self.push("{");
+        self.out.push('\n');
+
+        self.depth += 1;
+        self.last = None;
+        self.statements(&block.body);
+        self.comments_before(block.get_end_byte_index());
+        self.depth -= 1;
+
+        self.indent();
+        self.push("}");
+    }
+
+    fn expr(&mut self, e: &Expression) {
+        self.inline_comments(e.get_start_byte_index(), false);
+        match e.kind.as_ref() {
+            Expr::Var(ident) => self.push(ident.get_span()),
+            Expr::Num(span) => self.push(span.get_span()),
+            Expr::Dice(dice) => self.push(dice.get_span()),
+            Expr::Template(template) => self.push(template.get_span()),
+            Expr::Invoke(invoke) => {
+                self.push(invoke.name.get_span());
+                self.push("(");
+                self.list(&invoke.args);
+                self.push(")");
+            }
+            Expr::Op(op) => {
+                self.expr(&op.left);
+                let at = self.code_after(op.left.get_end_byte_index());
+                self.inline_comments(at, true);
+                self.push(" ");
+                self.push(op.op.symbol());
+                self.push(" ");
+                self.expr(&op.right);
+            }
+            Expr::Unary(unary) => {
+                self.push(unary.op.symbol());
+                // `- -3` isn't `--3`
+                let start = self.out.len();
+                self.expr(&unary.expr);
+                if unary.op.symbol() == "-" && self.out[start..].starts_with('-') {
+                    self.out.insert(start, ' ');
+                }
+            }
+            Expr::Parens(inner) => {
+                self.push("(");
+                self.expr(inner);
+                self.push(")");
+            }
+            Expr::Cond(cond) => self.cond(cond),
+            Expr::Block(block) => self.block(block),
+            Expr::Match(m) => {
+                self.push("match ");
+                self.expr(&m.scrutinee);
+                self.push(" {");
+                self.depth += 1;
+                for arm in m.arms.iter() {
+                    self.newline();
+                    match &arm.pattern.kind {
+                        PatternKind::Wildcard => self.push("_"),
+                        PatternKind::Value(value) => self.push(value.get_span()),
+                        PatternKind::Range(min, max) => {
+                            self.push(min.get_span());
+                            self.push("..=");
+                            self.push(max.get_span());
+                        }
+                    };
+                    self.push(" => ");
+                    self.expr(&arm.expr);
+                    self.push(",");
+                }
+                self.depth -= 1;
+                self.newline();
+                self.push("}");
+            }
+            Expr::Coll(coll) => {
+                self.push("[");
+                self.list(&coll.items);
+                self.push("]");
+            }
+            Expr::Index(index) => {
+                self.expr(&index.collection);
+                self.push("[");
+                self.expr(&index.index);
+                self.push("]");
+            }
+            Expr::Len(length) => {
+                self.push("len(");
+                self.expr(&length.collection);
+                self.push(")");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn anon() -> SourceName {
+    SourceName::Memory("test".to_string())
+}
+
+#[test]
+fn formatting_is_canonical_and_stable() {
+    use crate::internals::errors::term_errors::HumanReadable;
+
+    let source = "#!/usr/bin/foxhole\nimport   \"lib//rules.fx\" ;\n\n\n// bonus\nlet   bonus=2;  // flat\n/// Rolls to hit.\nfn attack(roll:int)int{\nroll+bonus /* stacks */\n}\nanalyze hit=if attack(d20)>=12{1}else{-d4};\nlen( [1,2 ,3] )\n";
+    let expected = "#!/usr/bin/foxhole\nimport \"lib//rules.fx\";\n\n// bonus\nlet bonus = 2; // flat\n/// Rolls to hit.\nfn attack(roll: int) int {\n    roll + bonus /* stacks */\n}\nanalyze hit = if attack(d20) >= 12 {\n    1\n} else {\n    -d4\n};\nlen([1, 2, 3])\n";
+    let formatted = format_code::<HumanReadable>(anon(), source).ok().unwrap();
+    assert_eq!(formatted, expected);
+
+    // formatting formatted code changes nothing
+    let again = format_code::<HumanReadable>(anon(), &formatted).ok().unwrap();
+    assert_eq!(again, formatted);
+
+    // block comments inside an expression stay in place, line comments
+    // are moved after its statement
+    let inline = format_code::<HumanReadable>(anon(), "1 +/* two */2 /* three */* x").ok().unwrap();
+    assert_eq!(inline, "1 + /* two */ 2 /* three */ * x\n");
+    let moved = format_code::<HumanReadable>(anon(), "1 + // two\n2").ok().unwrap();
+    assert_eq!(moved, "1 + 2\n// two\n");
+
+    // a negation of a negation stays two minuses
+    let negated = format_code::<HumanReadable>(anon(), "let x = - -3;\nlet y = !!b;").ok().unwrap();
+    assert_eq!(negated, "let x = - -3;\nlet y = !!b;\n");
+
+    // `else if` chains stay chains, match arms end with a comma
+    let chain = format_code::<HumanReadable>(anon(), "if a{1}else if b{2}else{match d6{1=>0,2..=6=>1}}").ok().unwrap();
+    assert_eq!(chain, "if a {\n    1\n} else if b {\n    2\n} else {\n    match d6 {\n        1 => 0,\n        2..=6 => 1,\n    }\n}\n");
+
+    // blank lines within indented bodies are kept, but not invented
+    let body = "fn f() int {\n    let a = 1;\n\n    let b = 2;\n    a + b\n}\n";
+    assert_eq!(format_code::<HumanReadable>(anon(), body).ok().unwrap(), body);
+
+    // blocks are indented like bodies, an `if` arm's block shares the arm's braces
+    let block = format_code::<HumanReadable>(anon(), "if a{let b:=1;b}else{2}+{let c=d6;// roll\nc}").ok().unwrap();
+    assert_eq!(block, "if a {\n    let b = 1;\n    b\n} else {\n    2\n} + {\n    let c = d6; // roll\n    c\n}\n");
+
+    assert!(format_code::<HumanReadable>(anon(), "let = 2;").is_err());
+}
+
+#[test]
+fn samples_round_trip() {
+    use crate::internals::errors::term_errors::HumanReadable;
+
+    let samples = [
+        include_str!("parser/generated/samples/example1.fx"),
+        include_str!("parser/generated/samples/example2.fx"),
+        include_str!("parser/generated/samples/example3.fx"),
+        include_str!("parser/generated/samples/example4.fx"),
+        include_str!("parser/generated/samples/example5.fx"),
+        include_str!("parser/generated/samples/example6.fx"),
+        include_str!("parser/generated/samples/example7.fx"),
+    ];
+    // already canonical, so formatting keeps them as written
+    let canonical = [
+        "let x = - -3;\nlet y = -(-d6);\n",
+        "let z = 1 + /* inline */ 2 /* after */ * d6;\n",
+    ];
+    for sample in samples.iter() {
+        let formatted = format_code::<HumanReadable>(anon(), sample).ok().unwrap();
+        let again = format_code::<HumanReadable>(anon(), &formatted).ok().unwrap();
+        assert_eq!(again, formatted);
+    }
+    for sample in canonical.iter() {
+        let formatted = format_code::<HumanReadable>(anon(), sample).ok().unwrap();
+        assert_eq!(&formatted, sample);
+    }
+}
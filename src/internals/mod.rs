@@ -1,3 +1,4 @@
 pub mod canonization;
 pub mod errors;
+pub mod formatter;
 pub mod parser;
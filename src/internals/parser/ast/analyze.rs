@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    parser::{
+        ast::{expr::Expression, ident::Ident},
+        span::{Span, Spanner},
+    },
+};
+
+/// Analyze declares a named output of the program, `analyze name = expr;`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Analyze {
+    pub name: Box<Ident>,
+
+    pub expr: Box<Expression>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Analyze {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for Analyze {}
+
+impl Analyze {
+    pub(in crate::internals::parser) fn new<F>(
+        name: Ident,
+        expr: Expression,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let name = Box::new(name);
+        let expr = Box::new(expr);
+        Ok(Self { name, expr, span })
+    }
+}
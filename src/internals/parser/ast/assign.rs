@@ -1,6 +1,6 @@
 use crate::internals::{
     parser::{
-        ast::{expr::Expression, ident::Ident, kind::Kind},
+        ast::{doc::Doc, expr::Expression, ident::Ident, kind::Kind},
         span::{Span, Spanner},
     },
 };
@@ -15,6 +15,9 @@ pub struct Assign {
     pub kind: Box<Option<Kind>>,
     pub is_constant: bool,
 
+    /// `///` comments preceding the assignment
+    pub doc: Option<Box<Doc>>,
+
     pub span: Box<Span>,
 }
 
@@ -47,7 +50,14 @@ impl Assign {
             name,
             expr,
             kind,
+            doc: None,
             span,
         })
     }
+
+    /// attach the doc comment which precedes this assignment
+    pub(in crate::internals::parser) fn with_doc(mut self, doc: Doc) -> Self {
+        self.doc = Some(Box::new(doc));
+        self
+    }
 }
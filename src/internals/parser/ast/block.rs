@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    parser::{
+        ast::{
+            expr::Expression,
+            statement::{State, StateTrait, Statement},
+        },
+        span::{Span, Spanner},
+    },
+};
+
+/// Block is a braced list of statements used as an expression,
+/// `{ let a := 2; a * a }`. Its value is the trailing expression,
+/// names it declares are only visible within the block.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Block {
+    /// the final statement is always a `Termination`
+    pub body: Vec<Statement>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Block {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for Block {}
+
+impl Block {
+    pub(in crate::internals::parser) fn new<F>(
+        body: Vec<Statement>,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        Ok(Self { body, span })
+    }
+
+    /// the statements before the trailing expression
+    pub fn statements(&self) -> &[Statement] {
+        &self.body[..self.body.len() - 1]
+    }
+
+    /// the expression the block evaluates to
+    pub fn term(&self) -> &Expression {
+        self.body.last().and_then(|s| s.get_term()).unwrap()
+    }
+
+    /// the arm of an `if`, a lone expression is kept as is rather
+    /// than being wrapped in a block.
+    pub(in crate::internals::parser) fn into_arm<F>(
+        mut body: Vec<Statement>,
+        span: F,
+    ) -> Result<Expression, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        if body.len() == 1 && body[0].is_term() {
+            if let State::Termination(term) = *body.pop().unwrap().sttm {
+                return Ok(*term);
+            }
+        }
+        let block = Self::new(body, span)?;
+        let span = block.get_clone();
+        Expression::new(block, move || Ok(span))
+    }
+}
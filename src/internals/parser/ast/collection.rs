@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    parser::{
+        ast::expr::Expression,
+        span::{Span, Spanner},
+    },
+};
+
+/// CollectionLiteral builds a collection in place, `[e1, e2, ...]`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct CollectionLiteral {
+    pub items: Box<[Expression]>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for CollectionLiteral {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+impl Spanner for CollectionLiteral {}
+
+impl CollectionLiteral {
+    pub(in crate::internals::parser) fn new<I, F>(
+        items: I,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        I: IntoIterator<Item = Expression>,
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let items = items
+            .into_iter()
+            .collect::<Vec<Expression>>()
+            .into_boxed_slice();
+        Ok(Self { items, span })
+    }
+}
+
+/// Index reads a single element of a collection, `xs[i]`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Index {
+    pub collection: Box<Expression>,
+
+    pub index: Box<Expression>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Index {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+impl Spanner for Index {}
+
+impl Index {
+    pub(in crate::internals::parser) fn new<F>(
+        collection: Expression,
+        index: Expression,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let collection = Box::new(collection);
+        let index = Box::new(index);
+        Ok(Self {
+            collection,
+            index,
+            span,
+        })
+    }
+}
+
+/// Length is the number of elements within a collection, `len(xs)`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Length {
+    pub collection: Box<Expression>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Length {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+impl Spanner for Length {}
+
+impl Length {
+    pub(in crate::internals::parser) fn new<F>(
+        collection: Expression,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let collection = Box::new(collection);
+        Ok(Self { collection, span })
+    }
+}
@@ -1,6 +1,6 @@
 use crate::internals::{
     parser::{
-        ast::{ident::Ident, kind::Kind, op::Op, template::Template},
+        ast::{doc::Doc, ident::Ident, kind::Kind, op::Op, template::Template},
         span::{Span, Spanner},
     },
 };
@@ -58,6 +58,9 @@ pub struct CompositionalFunction {
     pub collection_arg: Box<CompositionalFunctionArg>,
     pub ret: Box<Kind>,
 
+    /// `///` comments preceding the function
+    pub doc: Option<Box<Doc>>,
+
     pub span: Box<Span>,
 }
 
@@ -91,7 +94,14 @@ impl CompositionalFunction {
             single_arg,
             collection_arg,
             ret,
+            doc: None,
             span,
         })
     }
+
+    /// attach the doc comment which precedes this function
+    pub(in crate::internals::parser) fn with_doc(mut self, doc: Doc) -> Self {
+        self.doc = Some(Box::new(doc));
+        self
+    }
 }
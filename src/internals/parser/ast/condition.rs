@@ -48,3 +48,13 @@ impl Conditional {
         })
     }
 }
+
+impl Conditional {
+    /// wraps an `else if` into the expression of the enclosing `else`
+    pub(in crate::internals::parser) fn into_expression(
+        self,
+    ) -> Result<Expression, lrpar::Lexeme<u32>> {
+        let span = self.get_clone();
+        Expression::new(self, move || Ok(span))
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+// cargo doesn't realize I'm using this in a function signature.
+#[allow(unused_imports)]
+use lrpar::{Lexeme, Lexer, NonStreamingLexer};
+
+use crate::internals::{
+    parser::span::{Span, Spanner},
+};
+
+/// Dice is a dice literal. `d6`, `3d6`, etc.
+///
+/// Modifiers such as the `+1` in `2d3+1` are not part of the literal,
+/// they are parsed as a normal `Operation` on the dice.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Dice {
+    /// how many dice are rolled, `None` when omitted (`d6`)
+    pub count: Option<Box<Span>>,
+
+    /// how many sides each die has
+    pub sides: Box<Span>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Dice {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+impl Spanner for Dice {}
+
+impl Dice {
+    /// Build a dice literal from the `DICE` lexeme.
+    ///
+    /// The `count` & `sides` spans are sliced out of the lexeme so that
+    /// errors may point at either half of the literal.
+    pub(in crate::internals::parser) fn new<'input, L, F>(
+        l: &L,
+        lexeme: Result<Lexeme<u32>, Lexeme<u32>>,
+        span: F,
+    ) -> Result<Self, Lexeme<u32>>
+    where
+        L: NonStreamingLexer<'input, u32> + Lexer<u32> + ?Sized,
+        F: FnOnce() -> Result<Span, Lexeme<u32>>,
+    {
+        let lexeme = lexeme?;
+        let span = Box::new(span()?);
+        let whole = lexeme.span();
+        // the lexer guarantees exactly one `d` within the token
+        let split = whole.start() + l.span_str(whole).find('d').unwrap();
+        let count = if split == whole.start() {
+            None
+        } else {
+            let count = lrpar::Span::new(whole.start(), split);
+            Some(Box::new(Span::new(l, None, count)?))
+        };
+        let sides = lrpar::Span::new(split + 1, whole.end());
+        let sides = Box::new(Span::new(l, None, sides)?);
+        Ok(Self { count, sides, span })
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    parser::span::{Span, Spanner},
+};
+
+/// Doc is a run of `///` doc comment lines.
+///
+/// It is attached to the `FunctionDec`, `CompositionalFunction`,
+/// or `Assign` which immediately follows it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Doc {
+    /// each `///` line, including the slashes
+    pub lines: Vec<Span>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Doc {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+impl Spanner for Doc {}
+
+impl Doc {
+    pub(in crate::internals::parser) fn new<F>(
+        lines: Vec<Span>,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        Ok(Self { lines, span })
+    }
+
+    /// The documentation text, with the `///` (and one following space)
+    /// removed from every line.
+    pub fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| {
+                let line = line.get_span().trim_start_matches("///");
+                line.strip_prefix(' ').unwrap_or(line)
+            })
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+}
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 use crate::internals::{
     parser::{
         ast::{
-            condition::Conditional, ident::Ident, invoke::Invoke, op::Operation, template::Template,
+            block::Block, collection::{CollectionLiteral, Index, Length},
+            condition::Conditional, dice::Dice, ident::Ident, invoke::Invoke, matching::Match,
+            op::{Operation, UnaryOperation}, template::Template,
         },
         span::{Span, Spanner},
     },
@@ -33,15 +35,29 @@ pub enum Expr {
 
     Num(Box<Span>),
 
+    Dice(Box<Dice>),
+
     Template(Box<Template>),
 
     Invoke(Box<Invoke>),
 
     Op(Box<Operation>),
 
+    Unary(Box<UnaryOperation>),
+
     Parens(Box<Expression>),
 
     Cond(Box<Conditional>),
+
+    Match(Box<Match>),
+
+    Block(Box<Block>),
+
+    Coll(Box<CollectionLiteral>),
+
+    Index(Box<Index>),
+
+    Len(Box<Length>),
 }
 macro_rules! expr_from {
     ($TypeName: ident; { $($Variant: ident => $Interior: ident);*}) => {
@@ -57,12 +73,19 @@ macro_rules! expr_from {
 }
 expr_from! { Expr; {
     Cond => Conditional;
+    Match => Match;
+    Block => Block;
     Var => Ident;
     Num => Span;
+    Dice => Dice;
     Template => Template;
     Invoke => Invoke;
     Op => Operation;
-    Parens => Expression
+    Unary => UnaryOperation;
+    Parens => Expression;
+    Coll => CollectionLiteral;
+    Index => Index;
+    Len => Length
 }}
 
 impl Expression {
@@ -1,6 +1,6 @@
 use crate::internals::{
     parser::{
-        ast::{args::FunctionArg, ident::Ident, kind::Kind, statement::Statement},
+        ast::{args::FunctionArg, doc::Doc, ident::Ident, kind::Kind, statement::Statement},
         span::{Span, Spanner},
     },
 };
@@ -17,6 +17,9 @@ pub struct FunctionDec {
 
     pub body: Vec<Statement>,
     pub ret: Box<Kind>,
+
+    /// `///` comments preceding the function
+    pub doc: Option<Box<Doc>>,
 }
 
 impl FunctionDec {
@@ -44,8 +47,15 @@ impl FunctionDec {
             args,
             body,
             ret,
+            doc: None,
         })
     }
+
+    /// attach the doc comment which precedes this function
+    pub(in crate::internals::parser) fn with_doc(mut self, doc: Doc) -> Self {
+        self.doc = Some(Box::new(doc));
+        self
+    }
 }
 impl AsRef<Span> for FunctionDec {
     fn as_ref(&self) -> &Span {
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    parser::span::{Span, Spanner},
+};
+
+/// Import pulls the declarations of another source file into this one,
+/// `import "path.fx";`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Import {
+    /// the string literal, including its quotes
+    pub path: Box<Span>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Import {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for Import {}
+
+impl Import {
+    pub(in crate::internals::parser) fn new<F>(
+        path: Span,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let path = Box::new(path);
+        Ok(Self { path, span })
+    }
+
+    /// the imported path, as written (without quotes).
+    ///
+    /// relative paths are relative to the importing file.
+    pub fn get_path(&self) -> &str {
+        self.path.get_span().trim_matches('"')
+    }
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use crate::internals::{
+    parser::{
+        ast::expr::Expression,
+        span::{Span, Spanner},
+    },
+};
+
+/// Match selects an arm by comparing an integer against patterns,
+/// `match x { 1 => a, 2..=3 => b, _ => c }`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Match {
+    pub scrutinee: Box<Expression>,
+
+    pub arms: Vec<MatchArm>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for Match {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for Match {}
+
+impl Match {
+    pub(in crate::internals::parser) fn new<F>(
+        scrutinee: Expression,
+        arms: Vec<MatchArm>,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let scrutinee = Box::new(scrutinee);
+        Ok(Self {
+            scrutinee,
+            arms,
+            span,
+        })
+    }
+}
+
+/// A single `pattern => expr` arm of a `Match`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub pattern: Box<Pattern>,
+
+    pub expr: Box<Expression>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for MatchArm {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for MatchArm {}
+
+impl MatchArm {
+    pub(in crate::internals::parser) fn new<F>(
+        pattern: Pattern,
+        expr: Expression,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let pattern = Box::new(pattern);
+        let expr = Box::new(expr);
+        Ok(Self {
+            pattern,
+            expr,
+            span,
+        })
+    }
+}
+
+/// Pattern is the left hand side of a `MatchArm`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Pattern {
+    pub kind: PatternKind,
+
+    pub span: Box<Span>,
+}
+
+/// The values a `Pattern` matches
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PatternKind {
+    /// `_`, matches everything
+    Wildcard,
+    /// a single (possibly negative) integer, `3`
+    Value(Box<Span>),
+    /// an inclusive range of integers, `2..=3`
+    Range(Box<Span>, Box<Span>),
+}
+
+impl AsRef<Span> for Pattern {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for Pattern {}
+
+impl Pattern {
+    pub(in crate::internals::parser) fn new<F>(
+        kind: PatternKind,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        Ok(Self { kind, span })
+    }
+}
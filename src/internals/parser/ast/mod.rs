@@ -20,7 +20,7 @@ macro_rules! stuff {
         }
 
         impl AsRef<$Kind> for $Kind {
-            fn as_ref<'a>(&'a self) -> &'a Self {
+            fn as_ref(&self) -> &Self {
                 self
             }
         }
@@ -57,28 +57,38 @@ macro_rules! stuff {
     };
 }
 
+pub mod analyze;
 pub mod args;
 pub mod assign;
+pub mod block;
+pub mod collection;
 pub mod comparg;
 pub mod condition;
+pub mod dice;
+pub mod doc;
 pub mod expr;
 pub mod func;
 pub mod ident;
+pub mod import;
 pub mod invoke;
 pub mod kind;
+pub mod matching;
 pub mod op;
 pub mod statement;
 pub mod template;
 
+use crate::internals::parser::ast::analyze::Analyze;
 use crate::internals::parser::ast::args::FunctionArg;
 use crate::internals::parser::ast::assign::Assign;
+use crate::internals::parser::ast::collection::{CollectionLiteral, Index, Length};
 use crate::internals::parser::ast::comparg::{CompositionalFunction, CompositionalFunctionArg};
 use crate::internals::parser::ast::condition::Conditional;
+use crate::internals::parser::ast::dice::Dice;
 use crate::internals::parser::ast::expr::Expression;
 use crate::internals::parser::ast::func::FunctionDec;
 use crate::internals::parser::ast::ident::Ident;
 use crate::internals::parser::ast::invoke::Invoke;
-use crate::internals::parser::ast::op::Operation;
+use crate::internals::parser::ast::op::{Operation, UnaryOperation};
 use crate::internals::parser::ast::statement::{State, Statement};
 use crate::internals::parser::ast::template::Template;
 use crate::internals::parser::span::{Span, Spanner};
@@ -48,12 +48,16 @@ impl Operation {
     }
 }
 
+// variants are named after the grammar's tokens (`'ADD'`, `'MOD'`, ...)
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Op {
     ADD,
     SUB,
     MUL,
     DIV,
+    MOD,
+    POW,
     EQ,
     NE,
     GT,
@@ -64,3 +68,77 @@ pub enum Op {
     OR,
     XOR,
 }
+
+impl Op {
+    /// the source text of the operator
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Op::ADD => "+",
+            Op::SUB => "-",
+            Op::MUL => "*",
+            Op::DIV => "/",
+            Op::MOD => "%",
+            Op::POW => "**",
+            Op::EQ => "==",
+            Op::NE => "!=",
+            Op::GT => ">",
+            Op::LT => "<",
+            Op::GE => ">=",
+            Op::LE => "<=",
+            Op::AND => "&",
+            Op::OR => "|",
+            Op::XOR => "^",
+        }
+    }
+}
+
+/// UnaryOperation is an operator applied to a single expression, `-x` or `!x`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct UnaryOperation {
+    pub op: UnaryOp,
+
+    pub expr: Box<Expression>,
+
+    pub span: Box<Span>,
+}
+
+impl AsRef<Span> for UnaryOperation {
+    fn as_ref(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl Spanner for UnaryOperation {}
+
+impl UnaryOperation {
+    pub(in crate::internals::parser) fn new<F>(
+        op: UnaryOp,
+        expr: Expression,
+        span: F,
+    ) -> Result<Self, lrpar::Lexeme<u32>>
+    where
+        F: FnOnce() -> Result<Span, lrpar::Lexeme<u32>>,
+    {
+        let span = Box::new(span()?);
+        let expr = Box::new(expr);
+        Ok(Self { op, expr, span })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum UnaryOp {
+    /// arithmetic negation `-x`
+    Neg,
+    /// logical not `!x`
+    Not,
+}
+
+impl UnaryOp {
+    /// the source text of the operator
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            UnaryOp::Neg => "-",
+            UnaryOp::Not => "!",
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::internals::{
     parser::{
         ast::{
-            assign::Assign, comparg::CompositionalFunction, expr::Expression, func::FunctionDec,
+            analyze::Analyze, assign::Assign, comparg::CompositionalFunction, expr::Expression,
+            func::FunctionDec, import::Import,
         },
         span::{Span, Spanner},
     },
@@ -73,12 +74,14 @@ stuff! {
         FunctionDec => Func => is_func => get_func,
         CompositionalFunction => CompFunc => is_comp_func => get_comp_func,
         Expression => Termination => is_term => get_term,
+        Analyze => Analysis => is_analysis => get_analysis,
+        Import => Import => is_import => get_import,
     }
 }
 
 impl AsRef<State> for Statement {
     #[inline(always)]
-    fn as_ref<'a>(&'a self) -> &'a State {
+    fn as_ref(&self) -> &State {
         self.sttm.as_ref()
     }
 }
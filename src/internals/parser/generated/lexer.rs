@@ -4,123 +4,170 @@ use lrlex::{LexerDef, LRNonStreamingLexerDef, Rule};
 #[allow(dead_code)]
 pub fn lexerdef() -> LRNonStreamingLexerDef<u32> {
     let rules = vec![
-Rule::new(Some(27), Some("NUM".to_string()), "[0-9]+".to_string()).unwrap(),
-Rule::new(None, Some("ANALYZE".to_string()), "analyze".to_string()).unwrap(),
-Rule::new(Some(26), Some("COMP".to_string()), "cm".to_string()).unwrap(),
-Rule::new(Some(25), Some("FN".to_string()), "fn".to_string()).unwrap(),
-Rule::new(Some(34), Some("INT".to_string()), "int".to_string()).unwrap(),
-Rule::new(Some(35), Some("BOOL".to_string()), "bool".to_string()).unwrap(),
-Rule::new(Some(28), Some("TRUE".to_string()), "true".to_string()).unwrap(),
-Rule::new(Some(29), Some("FALSE".to_string()), "false".to_string()).unwrap(),
-Rule::new(Some(36), Some("VEC_INT".to_string()), "vec<int>".to_string()).unwrap(),
-Rule::new(Some(37), Some("VEC_BOOL".to_string()), "vec<bool>".to_string()).unwrap(),
-Rule::new(Some(20), Some("CONST".to_string()), "const".to_string()).unwrap(),
-Rule::new(Some(23), Some("LET".to_string()), "let".to_string()).unwrap(),
-Rule::new(Some(16), Some("IF".to_string()), "if".to_string()).unwrap(),
-Rule::new(Some(19), Some("ELSE".to_string()), "else".to_string()).unwrap(),
-Rule::new(Some(33), Some("TEMPLATE_FALLBACK".to_string()), ":-".to_string()).unwrap(),
-Rule::new(Some(32), Some("TEMPLATE_ASSIGN".to_string()), ":=".to_string()).unwrap(),
-Rule::new(Some(31), Some("TEMPLATE_START".to_string()), "\\$\\{".to_string()).unwrap(),
-Rule::new(Some(22), Some("ASSIGN".to_string()), "=".to_string()).unwrap(),
-Rule::new(Some(17), Some("RBRACE".to_string()), "\\{".to_string()).unwrap(),
-Rule::new(Some(18), Some("LBRACE".to_string()), "\\}".to_string()).unwrap(),
-Rule::new(Some(14), Some("LPAR".to_string()), "\\(".to_string()).unwrap(),
-Rule::new(Some(15), Some("RPAR".to_string()), "\\)".to_string()).unwrap(),
-Rule::new(Some(13), Some("XOR".to_string()), "\\^".to_string()).unwrap(),
-Rule::new(Some(12), Some("OR".to_string()), "\\|".to_string()).unwrap(),
-Rule::new(Some(11), Some("AND".to_string()), "&".to_string()).unwrap(),
-Rule::new(Some(24), Some("COMMA".to_string()), ",".to_string()).unwrap(),
-Rule::new(Some(0), Some("SEMI".to_string()), ";".to_string()).unwrap(),
-Rule::new(Some(21), Some("COLON".to_string()), ":".to_string()).unwrap(),
-Rule::new(Some(1), Some("ADD".to_string()), "\\+".to_string()).unwrap(),
-Rule::new(Some(3), Some("MUL".to_string()), "\\*".to_string()).unwrap(),
-Rule::new(Some(2), Some("SUB".to_string()), "-".to_string()).unwrap(),
-Rule::new(Some(4), Some("DIV".to_string()), "/".to_string()).unwrap(),
-Rule::new(Some(5), Some("EQ".to_string()), "==".to_string()).unwrap(),
-Rule::new(Some(6), Some("NE".to_string()), "!=".to_string()).unwrap(),
-Rule::new(Some(8), Some("LT".to_string()), "<".to_string()).unwrap(),
-Rule::new(Some(7), Some("GT".to_string()), ">".to_string()).unwrap(),
-Rule::new(Some(10), Some("LE".to_string()), "<=".to_string()).unwrap(),
-Rule::new(Some(9), Some("GE".to_string()), ">=".to_string()).unwrap(),
-Rule::new(Some(30), Some("IDENT".to_string()), "[a-zA-Z_][a-zA-Z0-9_]*".to_string()).unwrap(),
-Rule::new(Some(39), None, "((//|#![^\\n\\r]*)|[ \\t\\n\\r]+)".to_string()).unwrap(),
+Rule::new(Some(36), Some("NUM".to_string()), "[0-9]+".to_string()).unwrap(),
+Rule::new(Some(0), Some("ANALYZE".to_string()), "analyze".to_string()).unwrap(),
+Rule::new(Some(3), Some("IMPORT".to_string()), "import".to_string()).unwrap(),
+Rule::new(Some(42), Some("COMP".to_string()), "cm".to_string()).unwrap(),
+Rule::new(Some(41), Some("FN".to_string()), "fn".to_string()).unwrap(),
+Rule::new(Some(49), Some("INT".to_string()), "int".to_string()).unwrap(),
+Rule::new(Some(50), Some("BOOL".to_string()), "bool".to_string()).unwrap(),
+Rule::new(Some(44), Some("TRUE".to_string()), "true".to_string()).unwrap(),
+Rule::new(Some(45), Some("FALSE".to_string()), "false".to_string()).unwrap(),
+Rule::new(Some(51), Some("VEC_INT".to_string()), "vec<int>".to_string()).unwrap(),
+Rule::new(Some(52), Some("VEC_BOOL".to_string()), "vec<bool>".to_string()).unwrap(),
+Rule::new(Some(37), Some("CONST".to_string()), "const".to_string()).unwrap(),
+Rule::new(Some(39), Some("LET".to_string()), "let".to_string()).unwrap(),
+Rule::new(Some(27), Some("IF".to_string()), "if".to_string()).unwrap(),
+Rule::new(Some(24), Some("LEN".to_string()), "len".to_string()).unwrap(),
+Rule::new(Some(28), Some("ELSE".to_string()), "else".to_string()).unwrap(),
+Rule::new(Some(31), Some("MATCH".to_string()), "match".to_string()).unwrap(),
+Rule::new(Some(34), Some("UNDERSCORE".to_string()), "_".to_string()).unwrap(),
+Rule::new(Some(48), Some("TEMPLATE_FALLBACK".to_string()), ":-".to_string()).unwrap(),
+Rule::new(Some(40), Some("TEMPLATE_ASSIGN".to_string()), ":=".to_string()).unwrap(),
+Rule::new(Some(47), Some("TEMPLATE_START".to_string()), "\\$\\{".to_string()).unwrap(),
+Rule::new(Some(33), Some("ARROW".to_string()), "=>".to_string()).unwrap(),
+Rule::new(Some(35), Some("RANGE_INCL".to_string()), "\\.\\.=".to_string()).unwrap(),
+Rule::new(Some(1), Some("ASSIGN".to_string()), "=".to_string()).unwrap(),
+Rule::new(Some(29), Some("RBRACE".to_string()), "\\{".to_string()).unwrap(),
+Rule::new(Some(30), Some("LBRACE".to_string()), "\\}".to_string()).unwrap(),
+Rule::new(Some(25), Some("LPAR".to_string()), "\\(".to_string()).unwrap(),
+Rule::new(Some(26), Some("RPAR".to_string()), "\\)".to_string()).unwrap(),
+Rule::new(Some(22), Some("LBRACKET".to_string()), "\\[".to_string()).unwrap(),
+Rule::new(Some(23), Some("RBRACKET".to_string()), "\\]".to_string()).unwrap(),
+Rule::new(Some(20), Some("XOR".to_string()), "\\^".to_string()).unwrap(),
+Rule::new(Some(19), Some("OR".to_string()), "\\|".to_string()).unwrap(),
+Rule::new(Some(18), Some("AND".to_string()), "&".to_string()).unwrap(),
+Rule::new(Some(32), Some("COMMA".to_string()), ",".to_string()).unwrap(),
+Rule::new(Some(2), Some("SEMI".to_string()), ";".to_string()).unwrap(),
+Rule::new(Some(38), Some("COLON".to_string()), ":".to_string()).unwrap(),
+Rule::new(Some(6), Some("ADD".to_string()), "\\+".to_string()).unwrap(),
+Rule::new(Some(11), Some("POW".to_string()), "\\*\\*".to_string()).unwrap(),
+Rule::new(Some(8), Some("MUL".to_string()), "\\*".to_string()).unwrap(),
+Rule::new(Some(10), Some("MOD".to_string()), "%".to_string()).unwrap(),
+Rule::new(Some(7), Some("SUB".to_string()), "-".to_string()).unwrap(),
+Rule::new(Some(9), Some("DIV".to_string()), "/".to_string()).unwrap(),
+Rule::new(Some(12), Some("EQ".to_string()), "==".to_string()).unwrap(),
+Rule::new(Some(13), Some("NE".to_string()), "!=".to_string()).unwrap(),
+Rule::new(Some(21), Some("NOT".to_string()), "!".to_string()).unwrap(),
+Rule::new(Some(15), Some("LT".to_string()), "<".to_string()).unwrap(),
+Rule::new(Some(14), Some("GT".to_string()), ">".to_string()).unwrap(),
+Rule::new(Some(17), Some("LE".to_string()), "<=".to_string()).unwrap(),
+Rule::new(Some(16), Some("GE".to_string()), ">=".to_string()).unwrap(),
+Rule::new(Some(43), Some("DICE".to_string()), "[0-9]*d[0-9]+".to_string()).unwrap(),
+Rule::new(Some(4), Some("STRING".to_string()), "\"[^\"\\n\\r]*\"".to_string()).unwrap(),
+Rule::new(Some(46), Some("IDENT".to_string()), "[a-zA-Z_][a-zA-Z0-9_]*".to_string()).unwrap(),
+Rule::new(Some(5), Some("DOC".to_string()), "///([^/\\n\\r][^\\n\\r]*)?".to_string()).unwrap(),
+Rule::new(Some(53), None, "//[^\\n\\r]*".to_string()).unwrap(),
+Rule::new(Some(54), None, "/\\*([^*]|\\*+[^*/])*\\*+/".to_string()).unwrap(),
+Rule::new(Some(55), None, "#![^\\n\\r]*".to_string()).unwrap(),
+Rule::new(Some(56), None, "[ \\t\\n\\r]+".to_string()).unwrap(),
 ];
     LRNonStreamingLexerDef::from_rules(rules)
 }
 #[allow(dead_code)]
-pub const T_INT: u32 = 34;
+pub const T_LBRACKET: u32 = 22;
 #[allow(dead_code)]
-pub const T_GT: u32 = 7;
+pub const T_ARROW: u32 = 33;
 #[allow(dead_code)]
-pub const T_LT: u32 = 8;
+pub const T_CONST: u32 = 37;
 #[allow(dead_code)]
-pub const T_OR: u32 = 12;
+pub const T_DOC: u32 = 5;
 #[allow(dead_code)]
-pub const T_TEMPLATE_ASSIGN: u32 = 32;
+pub const T_LPAR: u32 = 25;
 #[allow(dead_code)]
-pub const T_XOR: u32 = 13;
+pub const T_EQ: u32 = 12;
 #[allow(dead_code)]
-pub const T_ADD: u32 = 1;
+pub const T_NOT: u32 = 21;
 #[allow(dead_code)]
-pub const T_NUM: u32 = 27;
+pub const T_RPAR: u32 = 26;
 #[allow(dead_code)]
-pub const T_ASSIGN: u32 = 22;
+pub const T_TRUE: u32 = 44;
 #[allow(dead_code)]
-pub const T_ELSE: u32 = 19;
+pub const T_IF: u32 = 27;
 #[allow(dead_code)]
-pub const T_EQ: u32 = 5;
+pub const T_MOD: u32 = 10;
 #[allow(dead_code)]
-pub const T_LBRACE: u32 = 18;
+pub const T_COMP: u32 = 42;
 #[allow(dead_code)]
-pub const T_LE: u32 = 10;
+pub const T_COLON: u32 = 38;
 #[allow(dead_code)]
-pub const T_COMP: u32 = 26;
+pub const T_TEMPLATE_ASSIGN: u32 = 40;
 #[allow(dead_code)]
-pub const T_LPAR: u32 = 14;
+pub const T_IMPORT: u32 = 3;
 #[allow(dead_code)]
-pub const T_GE: u32 = 9;
+pub const T_ASSIGN: u32 = 1;
 #[allow(dead_code)]
-pub const T_TEMPLATE_START: u32 = 31;
+pub const T_GE: u32 = 16;
 #[allow(dead_code)]
-pub const T_VEC_INT: u32 = 36;
+pub const T_RANGE_INCL: u32 = 35;
 #[allow(dead_code)]
-pub const T_CONST: u32 = 20;
+pub const T_NE: u32 = 13;
 #[allow(dead_code)]
-pub const T_LET: u32 = 23;
+pub const T_RBRACE: u32 = 29;
 #[allow(dead_code)]
-pub const T_SUB: u32 = 2;
+pub const T_LBRACE: u32 = 30;
 #[allow(dead_code)]
-pub const T_AND: u32 = 11;
+pub const T_VEC_INT: u32 = 51;
 #[allow(dead_code)]
-pub const T_RPAR: u32 = 15;
+pub const T_RBRACKET: u32 = 23;
 #[allow(dead_code)]
-pub const T_RBRACE: u32 = 17;
+pub const T_LT: u32 = 15;
 #[allow(dead_code)]
-pub const T_FN: u32 = 25;
+pub const T_UNDERSCORE: u32 = 34;
 #[allow(dead_code)]
-pub const T_MUL: u32 = 3;
+pub const T_BOOL: u32 = 50;
 #[allow(dead_code)]
-pub const T_SEMI: u32 = 0;
+pub const T_OR: u32 = 19;
 #[allow(dead_code)]
-pub const T_DIV: u32 = 4;
+pub const T_TEMPLATE_FALLBACK: u32 = 48;
 #[allow(dead_code)]
-pub const T_FALSE: u32 = 29;
+pub const T_XOR: u32 = 20;
 #[allow(dead_code)]
-pub const T_IDENT: u32 = 30;
+pub const T_SUB: u32 = 7;
 #[allow(dead_code)]
-pub const T_COMMA: u32 = 24;
+pub const T_POW: u32 = 11;
 #[allow(dead_code)]
-pub const T_TRUE: u32 = 28;
+pub const T_GT: u32 = 14;
 #[allow(dead_code)]
-pub const T_BOOL: u32 = 35;
+pub const T_STRING: u32 = 4;
 #[allow(dead_code)]
-pub const T_IF: u32 = 16;
+pub const T_LET: u32 = 39;
 #[allow(dead_code)]
-pub const T_TEMPLATE_FALLBACK: u32 = 33;
+pub const T_SEMI: u32 = 2;
 #[allow(dead_code)]
-pub const T_NE: u32 = 6;
+pub const T_VEC_BOOL: u32 = 52;
 #[allow(dead_code)]
-pub const T_COLON: u32 = 21;
+pub const T_FN: u32 = 41;
 #[allow(dead_code)]
-pub const T_VEC_BOOL: u32 = 37;
-}
+pub const T_INT: u32 = 49;
+#[allow(dead_code)]
+pub const T_COMMA: u32 = 32;
+#[allow(dead_code)]
+pub const T_ADD: u32 = 6;
+#[allow(dead_code)]
+pub const T_NUM: u32 = 36;
+#[allow(dead_code)]
+pub const T_IDENT: u32 = 46;
+#[allow(dead_code)]
+pub const T_DICE: u32 = 43;
+#[allow(dead_code)]
+pub const T_MUL: u32 = 8;
+#[allow(dead_code)]
+pub const T_DIV: u32 = 9;
+#[allow(dead_code)]
+pub const T_LE: u32 = 17;
+#[allow(dead_code)]
+pub const T_LEN: u32 = 24;
+#[allow(dead_code)]
+pub const T_TEMPLATE_START: u32 = 47;
+#[allow(dead_code)]
+pub const T_AND: u32 = 18;
+#[allow(dead_code)]
+pub const T_ELSE: u32 = 28;
+#[allow(dead_code)]
+pub const T_ANALYZE: u32 = 0;
+#[allow(dead_code)]
+pub const T_FALSE: u32 = 45;
+#[allow(dead_code)]
+pub const T_MATCH: u32 = 31;
+}
\ No newline at end of file
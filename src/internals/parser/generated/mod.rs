@@ -1,30 +1,46 @@
 use std::{hash::Hash, mem::replace};
 
 use lrlex::LRNonStreamingLexerDef;
-use lrpar::{LexParseError, Lexeme, NonStreamingLexer};
+use cfgrammar::TIdx;
+use lrpar::{LexParseError, Lexeme, NonStreamingLexer, ParseRepair};
 use num_traits::{PrimInt, Unsigned};
 use try_from::TryFrom;
 
 use crate::internals::parser::ast::statement::Body;
-use crate::internals::parser::span::{Span, Spanner};
+use crate::internals::parser::source_map::{SourceName, SOURCE_MAP};
+use crate::internals::parser::span::{with_file, Span, Spanner};
 use crate::internals::parser::traits::SyntaxError;
 
 pub mod lexer;
 pub use self::lexer::lexer_l::lexerdef;
+// the tables grmtools generates build their vectors one push at a time
+#[allow(clippy::vec_init_then_push)]
 pub mod parser;
 pub use self::parser::parser_y::{parse, token_epp};
 
-/// master function for parsing source code
+/// master function for parsing source code which isn't from a file
 #[allow(dead_code)]
 pub fn parse_code<E>(source: &str) -> Result<Body, Vec<E>>
 where
     E: SyntaxError,
 {
+    parse_named(SourceName::Memory("anon".to_string()), source)
+}
+
+/// parses source code, registering it within the `SOURCE_MAP` as `name`.
+///
+/// every span of the returned `Body` refers to the registered source.
+pub fn parse_named<E>(name: SourceName, source: &str) -> Result<Body, Vec<E>>
+where
+    E: SyntaxError,
+{
+    let file = SOURCE_MAP.add(name, source);
+    let source = SOURCE_MAP.get(file).get_text();
     let def: LRNonStreamingLexerDef<u32> = lexerdef();
-    {
+    with_file(file, || {
         let def2 = def.lexer(source);
-        parse_source(source, &def2, &parse)
-    }
+        parse_source(source, &def2, &parse, &token_epp)
+    })
 }
 
 /// master function for serializing source code
@@ -48,10 +64,15 @@ pub fn deserialize_ast(source: &str) -> Result<Body, String> {
 /// parse_source is a generic error handling function
 /// its type arguments can be adjusted to finalize the
 /// output.
+///
+/// Error recovery means the parser will keep going after
+/// a syntax error, so every error within the source is
+/// returned (not just the first).
 fn parse_source<'lexer, 'input, U, P, E>(
     source: &'input str,
     def2: &'lexer dyn NonStreamingLexer<'input, U>,
     parser: &P,
+    epp: &dyn Fn(TIdx<U>) -> Option<&'static str>,
 ) -> Result<Body, Vec<E>>
 where
     'input: 'lexer,
@@ -73,7 +94,8 @@ where
             }
             LexParseError::ParseError(parse_err) => {
                 let span = Span::new_panic(def2, parse_err.lexeme());
-                E::parse_error(def2, source, &span)
+                let repairs = describe_repairs(def2, epp, parse_err.repairs());
+                E::parse_error(def2, source, &span, &repairs)
             }
         })
         .collect();
@@ -81,9 +103,18 @@ where
     // extract a result
     let mut return_value: Option<Body> = None;
     match output {
+        Option::Some(Err(lex)) if is_dice_name(&lex) => {
+            // the `Name` rule refused a dice literal, see parser.y
+            let span = Span::new_panic(def2, lex);
+            errors_out.push(E::dice_as_name(def2, source, &span));
+        }
+        Option::Some(Err(_)) if !errors_out.is_empty() => {
+            // a lexeme inserted by error recovery reached an action,
+            // the error which caused the insertion was already reported.
+        }
         Option::Some(Err(lex)) => {
             let span = Span::new_panic(def2, lex);
-            errors_out.push(E::parse_error(def2, source, &span));
+            errors_out.push(E::parse_error(def2, source, &span, &[]));
         }
         Option::Some(Ok(result)) => {
             return_value = Some(result);
@@ -94,7 +125,7 @@ where
     };
 
     // determine final result
-    if errors_out.len() > 0 {
+    if !errors_out.is_empty() {
         Err(errors_out)
     } else if return_value.is_some() {
         Ok(return_value.unwrap())
@@ -103,34 +134,243 @@ where
     }
 }
 
+/// is `lex` a dice literal the grammar found in the place of a declared
+/// name, rather than a lexeme inserted by error recovery.
+fn is_dice_name<U: PrimInt>(lex: &Lexeme<U>) -> bool {
+    !lex.inserted() && num_traits::cast::<u32, U>(lexer::lexer_l::T_DICE) == Some(lex.tok_id())
+}
+
+/// converts the repair sequences found by error recovery into
+/// something a human can read. `Shift` is the parser simply
+/// continuing, so only the inserts & deletes are reported.
+fn describe_repairs<'input, U>(
+    lexer: &dyn NonStreamingLexer<'input, U>,
+    epp: &dyn Fn(TIdx<U>) -> Option<&'static str>,
+    repairs: &[Vec<ParseRepair<U>>],
+) -> Vec<String>
+where
+    U: Eq + Copy + Unsigned + PrimInt + Hash + 'static,
+{
+    let mut output: Vec<String> = Vec::with_capacity(repairs.len());
+    for sequence in repairs {
+        let description = sequence
+            .iter()
+            .filter_map(|repair| match repair {
+                ParseRepair::Insert(tidx) => {
+                    Some(format!("insert `{}`", epp(*tidx).unwrap_or("?")))
+                }
+                ParseRepair::Delete(lexeme) => {
+                    Some(format!("delete `{}`", lexer.span_str(lexeme.span())))
+                }
+                ParseRepair::Shift(_) => None,
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        if !description.is_empty() && !output.contains(&description) {
+            output.push(description);
+        }
+    }
+    output
+}
+
 #[cfg(test)]
 mod test {
     use super::{lexerdef, parse};
-    use crate::internals::parser::ast::statement::Body;
+    use crate::internals::parser::{
+        ast::{
+            expr::{Expr, Expression},
+            statement::{Body, StateTrait},
+        },
+        source_map::{SourceName, SOURCE_MAP},
+        span::{with_file, Spanner},
+    };
 
-    const SAMPLES: &'static [&'static str] = &[
+    const SAMPLES: &[&str] = &[
         include_str!("samples/example1.fx"),
         include_str!("samples/example2.fx"),
+        include_str!("samples/example3.fx"),
+        include_str!("samples/example4.fx"),
+        include_str!("samples/example5.fx"),
+        include_str!("samples/example6.fx"),
+        include_str!("samples/example7.fx"),
     ];
 
-    fn parse_text<'a>(text: &'a str) -> Option<Body> {
+    fn parse_text(text: &str) -> Option<Body> {
+        let file = SOURCE_MAP.add(SourceName::Memory("test".to_string()), text);
+        let text = SOURCE_MAP.get(file).get_text();
         let def = lexerdef();
-        {
-            match parse(&def.lexer(text)) {
-                (Option::Some(Ok(output)), _) => Some(output),
-                _ => None,
-            }
-        }
+        with_file(file, || match parse(&def.lexer(text)) {
+            (Option::Some(Ok(output)), ref errors) if errors.is_empty() => Some(output),
+            _ => None,
+        })
     }
 
     #[test]
     fn parse_code() {
         for (i, sample) in SAMPLES.iter().enumerate() {
-            match parse_text(sample) {
-                Option::None => {
-                    panic!("failed to parse sample:{}", i + 1);
+            if parse_text(sample).is_none() {
+                panic!("failed to parse sample:{}", i + 1);
+            };
+        }
+    }
+
+    #[test]
+    fn dice_literals() {
+        let cases: &[(&str, Option<&str>, &str)] = &[
+            ("d6", None, "6"),
+            ("3d6", Some("3"), "6"),
+            ("10d20", Some("10"), "20"),
+        ];
+        for (text, count, sides) in cases.iter() {
+            let body = match parse_text(text) {
+                Option::Some(body) => body,
+                Option::None => panic!("failed to parse dice:{}", text),
+            };
+            let term = body.body[0].get_term().unwrap();
+            let dice = match term.kind.as_ref() {
+                Expr::Dice(dice) => dice,
+                anything_else => panic!("expected dice, found:{:?}", anything_else),
+            };
+            assert_eq!(dice.get_span(), *text);
+            assert_eq!(dice.count.as_ref().map(|c| c.get_span()), *count);
+            assert_eq!(dice.sides.get_span(), *sides);
+        }
+    }
+
+    #[test]
+    fn comments_and_docs() {
+        let body = parse_text(SAMPLES[5]).unwrap();
+        assert_eq!(body.body.len(), 4);
+
+        // a plain `//` comment is not documentation
+        assert!(body.body[0].get_dec().unwrap().doc.is_none());
+
+        let func = body.body[1].get_func().unwrap();
+        let doc = func.doc.as_ref().unwrap();
+        assert_eq!(doc.text(), "Rolls to hit.\n`roll` is the natural die result.");
+
+        // `////` is an ordinary comment, and block comments may appear anywhere
+        assert!(parse_text("//// not a doc\n/* a /* b ** */ 1 + /**/ 2").is_some());
+        assert!(parse_text("/* unterminated\n1").is_none());
+    }
+
+    #[test]
+    fn recovers_every_syntax_error() {
+        use crate::internals::errors::term_errors::HumanReadable;
+
+        let source = "let a = ;\nlet b = 3 3;\na + b\n";
+        match super::parse_code::<HumanReadable>(source) {
+            Ok(_) => panic!("invalid source parsed"),
+            Err(errors) => assert_eq!(errors.len(), 2, "{:?}", errors),
+        };
+    }
+
+    #[test]
+    fn dice_are_never_names() {
+        use crate::internals::errors::term_errors::HumanReadable;
+
+        let first_error = |source: &str| match super::parse_code::<HumanReadable>(source) {
+            Ok(_) => panic!("`{}` parsed", source),
+            Err(errors) => errors[0].to_string(),
+        };
+        let sources = [
+            "let d6 = 1;\n2",
+            "const 2d6 = 3;\n2",
+            "fn d20() int { 1 }\n2",
+            "fn f(a: int, d6: int) int { 1 }\n2",
+            "let /* a roll */ d6 = 1;\n2",
+            "cm d8(0, roll, +) int;\n2",
+            "analyze 4d6 = d6 + 1;",
+        ];
+        for source in sources.iter() {
+            let err = first_error(source);
+            assert!(err.contains("is a dice literal, not a name"), "{}", err);
+        }
+        let err = first_error("let x = 1 1;\nd6");
+        assert!(err.contains("Parse error"), "{}", err);
+        // a longer name is an identifier
+        assert!(parse_text("let d6x = 1;\nd6x + d6").is_some());
+    }
+
+    #[test]
+    fn match_arms() {
+        let body = parse_text("match a + 1 { 1 => b * 2, -3..=- 1 => c, _ => 0, }").unwrap();
+        assert_eq!(
+            render(body.body[0].get_term().unwrap()),
+            "match (a + 1) { 1 => (b * 2), -3..=- 1 => c, _ => 0 }"
+        );
+        assert!(parse_text("match a { 1 => 0 } + 1").is_some());
+        assert!(parse_text("match a { }").is_none());
+        assert!(parse_text("match a { 1..= => 0 }").is_none());
+    }
+
+    /// renders an expression with every operation parenthesized
+    /// so the shape of the tree can be compared as text.
+    fn render(e: &Expression) -> String {
+        match e.kind.as_ref() {
+            Expr::Op(op) => format!(
+                "({} {} {})",
+                render(&op.left),
+                op.op.symbol(),
+                render(&op.right)
+            ),
+            Expr::Unary(unary) => format!("({}{})", unary.op.symbol(), render(&unary.expr)),
+            Expr::Parens(inner) => render(inner),
+            Expr::Index(index) => {
+                format!("{}[{}]", render(&index.collection), render(&index.index))
+            }
+            Expr::Invoke(invoke) => format!(
+                "{}({})",
+                invoke.name.get_span(),
+                invoke
+                    .args
+                    .iter()
+                    .map(render)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Expr::Cond(cond) => format!(
+                "if {} {{ {} }} else {{ {} }}",
+                render(&cond.condition),
+                render(&cond.true_case),
+                render(&cond.false_case)
+            ),
+            Expr::Match(m) => format!(
+                "match {} {{ {} }}",
+                render(&m.scrutinee),
+                m.arms
+                    .iter()
+                    .map(|arm| format!("{} => {}", arm.pattern.get_span(), render(&arm.expr)))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            _ => e.get_span().to_string(),
+        }
+    }
+
+    #[test]
+    fn operator_precedence() {
+        let corpus = include_str!("samples/precedence.txt");
+        for line in corpus.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            let mut parts = line.split(" => ");
+            let source = parts.next().unwrap();
+            let expected = parts.next().unwrap();
+            match (parse_text(source), expected) {
+                (Option::None, "error") => {}
+                (Option::Some(body), "error") => {
+                    panic!("`{}` should not parse, found:{:?}", source, body);
+                }
+                (Option::None, _) => {
+                    panic!("`{}` failed to parse", source);
+                }
+                (Option::Some(body), expected) => {
+                    let found = render(body.body[0].get_term().unwrap());
+                    assert_eq!(found, expected, "for `{}`", source);
                 }
-                Option::Some(_) => {}
             };
         }
     }
@@ -1,7 +1,7 @@
 pub mod parser_y {
     #![allow(clippy::type_complexity)]
-#[allow(dead_code)] const __GRM_DATA: &[u8] = &[23,0,0,0,23,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,94,13,0,0,0,0,0,0,0,80,114,111,103,114,97,109,80,97,114,115,101,114,11,0,0,0,0,0,0,0,83,116,116,109,110,116,115,67,111,108,108,7,0,0,0,0,0,0,0,83,116,116,109,110,116,115,6,0,0,0,0,0,0,0,83,116,116,109,110,116,4,0,0,0,0,0,0,0,84,101,114,109,4,0,0,0,0,0,0,0,69,120,112,114,4,0,0,0,0,0,0,0,67,111,110,100,10,0,0,0,0,0,0,0,65,115,115,105,103,110,109,101,110,116,10,0,0,0,0,0,0,0,68,101,99,70,117,110,99,65,114,103,14,0,0,0,0,0,0,0,70,117,110,99,65,114,103,68,101,99,76,105,115,116,11,0,0,0,0,0,0,0,68,101,99,70,117,110,99,65,114,103,115,7,0,0,0,0,0,0,0,68,101,99,70,117,110,99,4,0,0,0,0,0,0,0,70,117,110,99,8,0,0,0,0,0,0,0,70,117,110,99,65,114,103,115,7,0,0,0,0,0,0,0,65,114,103,76,105,115,116,6,0,0,0,0,0,0,0,68,101,99,67,109,112,7,0,0,0,0,0,0,0,67,111,109,112,65,114,103,3,0,0,0,0,0,0,0,78,117,109,4,0,0,0,0,0,0,0,66,111,111,108,10,0,0,0,0,0,0,0,73,100,101,110,116,105,102,105,101,114,11,0,0,0,0,0,0,0,84,101,109,112,108,97,116,101,86,97,114,8,0,0,0,0,0,0,0,84,121,112,101,73,110,102,111,39,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,83,69,77,73,1,3,0,0,0,0,0,0,0,65,68,68,1,3,0,0,0,0,0,0,0,83,85,66,1,3,0,0,0,0,0,0,0,77,85,76,1,3,0,0,0,0,0,0,0,68,73,86,1,2,0,0,0,0,0,0,0,69,81,1,2,0,0,0,0,0,0,0,78,69,1,2,0,0,0,0,0,0,0,71,84,1,2,0,0,0,0,0,0,0,76,84,1,2,0,0,0,0,0,0,0,71,69,1,2,0,0,0,0,0,0,0,76,69,1,3,0,0,0,0,0,0,0,65,78,68,1,2,0,0,0,0,0,0,0,79,82,1,3,0,0,0,0,0,0,0,88,79,82,1,4,0,0,0,0,0,0,0,76,80,65,82,1,4,0,0,0,0,0,0,0,82,80,65,82,1,2,0,0,0,0,0,0,0,73,70,1,6,0,0,0,0,0,0,0,82,66,82,65,67,69,1,6,0,0,0,0,0,0,0,76,66,82,65,67,69,1,4,0,0,0,0,0,0,0,69,76,83,69,1,5,0,0,0,0,0,0,0,67,79,78,83,84,1,5,0,0,0,0,0,0,0,67,79,76,79,78,1,6,0,0,0,0,0,0,0,65,83,83,73,71,78,1,3,0,0,0,0,0,0,0,76,69,84,1,5,0,0,0,0,0,0,0,67,79,77,77,65,1,2,0,0,0,0,0,0,0,70,78,1,4,0,0,0,0,0,0,0,67,79,77,80,1,3,0,0,0,0,0,0,0,78,85,77,1,4,0,0,0,0,0,0,0,84,82,85,69,1,5,0,0,0,0,0,0,0,70,65,76,83,69,1,5,0,0,0,0,0,0,0,73,68,69,78,84,1,14,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,83,84,65,82,84,1,15,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,65,83,83,73,71,78,1,17,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,70,65,76,76,66,65,67,75,1,3,0,0,0,0,0,0,0,73,78,84,1,4,0,0,0,0,0,0,0,66,79,79,76,1,7,0,0,0,0,0,0,0,86,69,67,95,73,78,84,1,8,0,0,0,0,0,0,0,86,69,67,95,66,79,79,76,0,39,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,0,0,0,0,1,2,0,0,0,0,0,0,0,0,0,0,0,1,3,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,6,0,0,0,0,0,0,0,0,0,0,0,1,7,0,0,0,0,0,0,0,0,0,0,0,1,8,0,0,0,0,0,0,0,0,0,0,0,1,9,0,0,0,0,0,0,0,0,0,0,0,1,10,0,0,0,0,0,0,0,0,0,0,0,1,11,0,0,0,0,0,0,0,0,0,0,0,1,12,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,39,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,83,69,77,73,1,3,0,0,0,0,0,0,0,65,68,68,1,3,0,0,0,0,0,0,0,83,85,66,1,3,0,0,0,0,0,0,0,77,85,76,1,3,0,0,0,0,0,0,0,68,73,86,1,2,0,0,0,0,0,0,0,69,81,1,2,0,0,0,0,0,0,0,78,69,1,2,0,0,0,0,0,0,0,71,84,1,2,0,0,0,0,0,0,0,76,84,1,2,0,0,0,0,0,0,0,71,69,1,2,0,0,0,0,0,0,0,76,69,1,3,0,0,0,0,0,0,0,65,78,68,1,2,0,0,0,0,0,0,0,79,82,1,3,0,0,0,0,0,0,0,88,79,82,1,4,0,0,0,0,0,0,0,76,80,65,82,1,4,0,0,0,0,0,0,0,82,80,65,82,1,2,0,0,0,0,0,0,0,73,70,1,6,0,0,0,0,0,0,0,82,66,82,65,67,69,1,6,0,0,0,0,0,0,0,76,66,82,65,67,69,1,4,0,0,0,0,0,0,0,69,76,83,69,1,5,0,0,0,0,0,0,0,67,79,78,83,84,1,5,0,0,0,0,0,0,0,67,79,76,79,78,1,6,0,0,0,0,0,0,0,65,83,83,73,71,78,1,3,0,0,0,0,0,0,0,76,69,84,1,5,0,0,0,0,0,0,0,67,79,77,77,65,1,2,0,0,0,0,0,0,0,70,78,1,4,0,0,0,0,0,0,0,67,79,77,80,1,3,0,0,0,0,0,0,0,78,85,77,1,4,0,0,0,0,0,0,0,84,82,85,69,1,5,0,0,0,0,0,0,0,70,65,76,83,69,1,5,0,0,0,0,0,0,0,73,68,69,78,84,1,14,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,83,84,65,82,84,1,15,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,65,83,83,73,71,78,1,17,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,70,65,76,76,66,65,67,75,1,3,0,0,0,0,0,0,0,73,78,84,1,4,0,0,0,0,0,0,0,66,79,79,76,1,7,0,0,0,0,0,0,0,86,69,67,95,73,78,84,1,8,0,0,0,0,0,0,0,86,69,67,95,66,79,79,76,0,39,0,0,0,38,0,0,0,69,0,0,0,68,0,0,0,69,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,3,0,0,0,0,0,0,0,5,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,5,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,3,0,0,0,0,0,0,0,4,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,1,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,12,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,1,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,2,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,3,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,4,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,5,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,6,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,7,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,8,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,9,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,10,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,11,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,12,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,13,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,14,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,15,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,7,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,13,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,18,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,21,0,0,0,9,0,0,0,0,0,0,0,1,0,0,0,16,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,17,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,18,0,0,0,1,0,0,0,19,0,0,0,1,0,0,0,17,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,18,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,20,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,21,0,0,0,0,0,0,0,22,0,0,0,1,0,0,0,5,0,0,0,0,0,0,0,6,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,20,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,22,0,0,0,0,0,0,0,6,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,23,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,21,0,0,0,0,0,0,0,22,0,0,0,1,0,0,0,5,0,0,0,0,0,0,0,6,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,23,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,22,0,0,0,0,0,0,0,6,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,21,0,0,0,0,0,0,0,22,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,24,0,0,0,0,0,0,0,9,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,9,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,14,0,0,0,1,0,0,0,15,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,14,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,15,0,0,0,7,0,0,0,0,0,0,0,1,0,0,0,25,0,0,0,0,0,0,0,20,0,0,0,0,0,0,0,11,0,0,0,0,0,0,0,22,0,0,0,1,0,0,0,17,0,0,0,0,0,0,0,2,0,0,0,1,0,0,0,18,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,0,0,0,0,14,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,14,0,0,0,1,0,0,0,15,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,14,0,0,0,0,0,0,0,15,0,0,0,1,0,0,0,15,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,15,0,0,0,1,0,0,0,24,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,10,0,0,0,0,0,0,0,1,0,0,0,26,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,14,0,0,0,0,0,0,0,17,0,0,0,1,0,0,0,24,0,0,0,0,0,0,0,17,0,0,0,1,0,0,0,24,0,0,0,0,0,0,0,17,0,0,0,1,0,0,0,15,0,0,0,0,0,0,0,22,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,19,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,18,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,21,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,1,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,2,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,3,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,11,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,12,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,13,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,27,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,28,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,29,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,30,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,32,0,0,0,0,0,0,0,21,0,0,0,1,0,0,0,17,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,32,0,0,0,1,0,0,0,27,0,0,0,1,0,0,0,17,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,33,0,0,0,0,0,0,0,21,0,0,0,1,0,0,0,17,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,33,0,0,0,1,0,0,0,27,0,0,0,1,0,0,0,17,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,17,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,34,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,35,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,36,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,37,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,23,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,68,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,0,0,0,0,3,0,0,0,4,0,0,0,3,0,0,0,0,0,0,0,5,0,0,0,6,0,0,0,7,0,0,0,1,0,0,0,0,0,0,0,8,0,0,0,19,0,0,0,0,0,0,0,9,0,0,0,10,0,0,0,11,0,0,0,12,0,0,0,13,0,0,0,14,0,0,0,15,0,0,0,16,0,0,0,17,0,0,0,18,0,0,0,19,0,0,0,20,0,0,0,21,0,0,0,22,0,0,0,23,0,0,0,24,0,0,0,25,0,0,0,26,0,0,0,27,0,0,0,1,0,0,0,0,0,0,0,28,0,0,0,4,0,0,0,0,0,0,0,29,0,0,0,30,0,0,0,31,0,0,0,32,0,0,0,1,0,0,0,0,0,0,0,33,0,0,0,2,0,0,0,0,0,0,0,34,0,0,0,35,0,0,0,2,0,0,0,0,0,0,0,36,0,0,0,37,0,0,0,1,0,0,0,0,0,0,0,38,0,0,0,1,0,0,0,0,0,0,0,39,0,0,0,2,0,0,0,0,0,0,0,40,0,0,0,41,0,0,0,2,0,0,0,0,0,0,0,42,0,0,0,43,0,0,0,1,0,0,0,0,0,0,0,44,0,0,0,10,0,0,0,0,0,0,0,45,0,0,0,46,0,0,0,47,0,0,0,48,0,0,0,49,0,0,0,50,0,0,0,51,0,0,0,52,0,0,0,53,0,0,0,54,0,0,0,1,0,0,0,0,0,0,0,55,0,0,0,2,0,0,0,0,0,0,0,56,0,0,0,57,0,0,0,1,0,0,0,0,0,0,0,58,0,0,0,5,0,0,0,0,0,0,0,59,0,0,0,60,0,0,0,61,0,0,0,62,0,0,0,63,0,0,0,4,0,0,0,0,0,0,0,64,0,0,0,65,0,0,0,66,0,0,0,67,0,0,0,69,0,0,0,0,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,3,0,0,0,3,0,0,0,4,0,0,0,4,0,0,0,4,0,0,0,5,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,7,0,0,0,8,0,0,0,8,0,0,0,8,0,0,0,8,0,0,0,9,0,0,0,10,0,0,0,10,0,0,0,11,0,0,0,11,0,0,0,12,0,0,0,13,0,0,0,14,0,0,0,14,0,0,0,15,0,0,0,15,0,0,0,16,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,18,0,0,0,19,0,0,0,19,0,0,0,20,0,0,0,21,0,0,0,21,0,0,0,21,0,0,0,21,0,0,0,21,0,0,0,22,0,0,0,22,0,0,0,22,0,0,0,22,0,0,0,0,0,0,0,69,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,0,0,0,0,1,2,0,0,0,0,0,0,0,0,0,0,0,1,3,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,6,0,0,0,0,0,0,0,0,0,0,0,1,7,0,0,0,0,0,0,0,0,0,0,0,1,8,0,0,0,0,0,0,0,0,0,0,0,1,9,0,0,0,0,0,0,0,0,0,0,0,1,10,0,0,0,0,0,0,0,0,0,0,0,1,11,0,0,0,0,0,0,0,0,0,0,0,1,12,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,0,0,0,0,1,2,0,0,0,0,0,0,0,0,0,0,0,1,10,0,0,0,0,0,0,0,0,0,0,0,1,11,0,0,0,0,0,0,0,0,0,0,0,1,12,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,69,0,0,0,0,0,0,0,1,40,0,0,0,0,0,0,0,66,111,100,121,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,37,0,0,0,0,0,0,0,123,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,50,63,41,59,32,79,107,40,118,41,125,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,38,0,0,0,0,0,0,0,123,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,50,63,41,59,32,79,107,40,118,41,32,125,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,65,68,68,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,83,85,66,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,77,85,76,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,68,73,86,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,69,81,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,78,69,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,71,84,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,76,84,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,71,69,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,76,69,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,65,78,68,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,79,82,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,88,79,82,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,58,0,0,0,0,0,0,0,67,111,110,100,105,116,105,111,110,97,108,58,58,110,101,119,40,36,50,63,44,32,36,52,63,44,32,36,56,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,58,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,116,114,117,101,44,32,36,52,63,44,32,36,54,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,116,114,117,101,44,32,78,111,110,101,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,102,97,108,115,101,44,32,36,52,63,44,32,36,54,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,60,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,102,97,108,115,101,44,32,78,111,110,101,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,50,0,0,0,0,0,0,0,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,35,0,0,0,0,0,0,0,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,51,63,41,59,32,79,107,40,118,41,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,14,0,0,0,0,0,0,0,79,107,40,86,101,99,58,58,110,101,119,40,41,41,1,7,0,0,0,0,0,0,0,79,107,40,36,50,63,41,1,58,0,0,0,0,0,0,0,70,117,110,99,116,105,111,110,68,101,99,58,58,110,101,119,40,36,50,63,44,36,51,63,44,36,54,63,44,36,52,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,47,0,0,0,0,0,0,0,73,110,118,111,107,101,58,58,110,101,119,40,36,49,63,44,32,36,50,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,14,0,0,0,0,0,0,0,79,107,40,86,101,99,58,58,110,101,119,40,41,41,1,7,0,0,0,0,0,0,0,79,107,40,36,50,63,41,1,35,0,0,0,0,0,0,0,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,51,63,41,59,32,79,107,40,118,41,1,15,0,0,0,0,0,0,0,79,107,40,32,118,101,99,33,91,36,49,63,93,32,41,1,73,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,58,58,110,101,119,40,36,50,63,44,36,52,63,44,36,54,63,44,36,56,63,44,36,49,48,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,65,68,68,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,83,85,66,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,77,85,76,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,65,78,68,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,62,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,79,82,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,88,79,82,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,35,0,0,0,0,0,0,0,79,107,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,41,1,33,0,0,0,0,0,0,0,79,107,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,78,111,110,101,44,36,115,112,97,110,41,63,41,1,33,0,0,0,0,0,0,0,79,107,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,78,111,110,101,44,36,115,112,97,110,41,63,41,1,51,0,0,0,0,0,0,0,79,107,40,32,73,100,101,110,116,58,58,110,101,119,40,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,32,41,32,41,1,86,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,97,115,115,105,103,110,40,36,52,63,41,41,41,1,111,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,97,115,115,105,103,110,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,52,44,32,78,111,110,101,41,63,41,41,41,1,88,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,102,97,108,108,98,97,99,107,40,36,52,63,41,41,41,1,113,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,102,97,108,108,98,97,99,107,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,52,44,32,78,111,110,101,41,63,41,41,41,1,61,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,78,111,110,101,41,41,1,13,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,73,110,116,41,1,14,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,66,111,111,108,41,1,19,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,67,111,108,108,79,102,73,110,116,41,1,20,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,67,111,108,108,79,102,66,111,111,108,41,0,1,10,3,0,0,0,0,0,0,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,115,112,97,110,58,58,123,83,112,97,110,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,107,105,110,100,58,58,123,75,105,110,100,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,97,114,103,115,58,58,123,70,117,110,99,116,105,111,110,65,114,103,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,102,117,110,99,58,58,123,70,117,110,99,116,105,111,110,68,101,99,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,99,111,109,112,97,114,103,58,58,123,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,44,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,105,100,101,110,116,58,58,123,73,100,101,110,116,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,105,110,118,111,107,101,58,58,123,73,110,118,111,107,101,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,101,120,112,114,58,58,123,69,120,112,114,101,115,115,105,111,110,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,116,101,109,112,108,97,116,101,58,58,123,84,101,109,112,108,97,116,101,44,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,97,115,115,105,103,110,58,58,123,65,115,115,105,103,110,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,111,112,58,58,123,79,112,44,79,112,101,114,97,116,105,111,110,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,115,116,97,116,101,109,101,110,116,58,58,123,83,116,97,116,101,109,101,110,116,44,66,111,100,121,125,59,13,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,99,111,110,100,105,116,105,111,110,58,58,123,67,111,110,100,105,116,105,111,110,97,108,125,59,13,10,23,0,0,0,0,0,0,0,0,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,66,111,100,121,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,41,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,83,116,97,116,101,109,101,110,116,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,41,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,83,116,97,116,101,109,101,110,116,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,36,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,116,97,116,101,109,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,36,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,116,97,116,101,109,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,37,0,0,0,0,0,0,0,82,101,115,117,108,116,60,69,120,112,114,101,115,115,105,111,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,38,0,0,0,0,0,0,0,82,101,115,117,108,116,60,67,111,110,100,105,116,105,111,110,97,108,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,33,0,0,0,0,0,0,0,82,101,115,117,108,116,60,65,115,115,105,103,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,38,0,0,0,0,0,0,0,82,101,115,117,108,116,60,70,117,110,99,116,105,111,110,65,114,103,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,43,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,70,117,110,99,116,105,111,110,65,114,103,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,43,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,70,117,110,99,116,105,111,110,65,114,103,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,38,0,0,0,0,0,0,0,82,101,115,117,108,116,60,70,117,110,99,116,105,111,110,68,101,99,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,33,0,0,0,0,0,0,0,82,101,115,117,108,116,60,73,110,118,111,107,101,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,42,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,69,120,112,114,101,115,115,105,111,110,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,42,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,69,120,112,114,101,115,115,105,111,110,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,48,0,0,0,0,0,0,0,82,101,115,117,108,116,60,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,51,0,0,0,0,0,0,0,82,101,115,117,108,116,60,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,112,97,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,112,97,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,32,0,0,0,0,0,0,0,82,101,115,117,108,116,60,73,100,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,35,0,0,0,0,0,0,0,82,101,115,117,108,116,60,84,101,109,112,108,97,116,101,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,75,105,110,100,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,0,];
-#[allow(dead_code)] const __STABLE_DATA: &[u8] = &[139,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,17,0,0,0,0,0,0,0,78,0,0,0,0,0,0,0,19,0,0,0,0,0,0,0,39,0,0,0,0,0,0,0,30,0,0,0,0,0,0,0,32,1,0,0,0,0,0,0,131,4,0,0,0,0,0,0,78,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,117,0,0,0,0,0,0,0,23,0,0,0,0,0,0,0,117,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,156,0,0,0,0,0,0,0,195,0,0,0,0,0,0,0,234,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,156,0,0,0,0,0,0,0,17,1,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,179,4,0,0,0,0,0,0,82,0,0,0,0,0,0,0,131,4,0,0,0,0,0,0,4,0,0,0,0,0,0,0,195,0,0,0,0,0,0,0,58,0,0,0,0,0,0,0,34,1,0,0,0,0,0,0,234,0,0,0,0,0,0,0,6,0,0,0,0,0,0,0,59,0,0,0,0,0,0,0,131,4,0,0,0,0,0,0,56,1,0,0,0,0,0,0,5,0,0,0,0,0,0,0,95,1,0,0,0,0,0,0,134,1,0,0,0,0,0,0,173,1,0,0,0,0,0,0,212,1,0,0,0,0,0,0,251,1,0,0,0,0,0,0,34,2,0,0,0,0,0,0,73,2,0,0,0,0,0,0,112,2,0,0,0,0,0,0,151,2,0,0,0,0,0,0,190,2,0,0,0,0,0,0,229,2,0,0,0,0,0,0,12,3,0,0,0,0,0,0,51,3,0,0,0,0,0,0,90,3,0,0,0,0,0,0,129,3,0,0,0,0,0,0,34,0,0,0,0,0,0,0,35,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,250,4,0,0,0,0,0,0,168,3,0,0,0,0,0,0,44,0,0,0,0,0,0,0,59,0,0,0,0,0,0,0,83,0,0,0,0,0,0,0,90,0,0,0,0,0,0,0,121,0,0,0,0,0,0,0,155,4,0,0,0,0,0,0,195,4,0,0,0,0,0,0,134,0,0,0,0,0,0,0,3,5,0,0,0,0,0,0,11,5,0,0,0,0,0,0,14,5,0,0,0,0,0,0,32,5,0,0,0,0,0,0,76,0,0,0,0,0,0,0,82,0,0,0,0,0,0,0,125,0,0,0,0,0,0,0,121,0,0,0,0,0,0,0,136,0,0,0,0,0,0,0,149,0,0,0,0,0,0,0,209,4,0,0,0,0,0,0,160,0,0,0,0,0,0,0,199,0,0,0,0,0,0,0,238,0,0,0,0,0,0,0,21,1,0,0,0,0,0,0,60,1,0,0,0,0,0,0,61,1,0,0,0,0,0,0,62,1,0,0,0,0,0,0,63,1,0,0,0,0,0,0,64,1,0,0,0,0,0,0,66,1,0,0,0,0,0,0,153,0,0,0,0,0,0,0,67,1,0,0,0,0,0,0,68,1,0,0,0,0,0,0,155,4,0,0,0,0,0,0,207,3,0,0,0,0,0,0,246,3,0,0,0,0,0,0,29,4,0,0,0,0,0,0,68,4,0,0,0,0,0,0,159,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,115,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,250,4,0,0,0,0,0,0,168,0,0,0,0,0,0,0,223,4,0,0,0,0,0,0,94,1,0,0,0,0,0,0,96,1,0,0,0,0,0,0,170,0,0,0,0,0,0,0,237,4,0,0,0,0,0,0,165,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,17,1,0,0,0,0,0,0,250,4,0,0,0,0,0,0,237,4,0,0,0,0,0,0,175,0,0,0,0,0,0,0,107,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,191,0,0,0,0,0,0,0,39,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45,21,0,0,0,0,0,0,85,0,0,0,0,0,0,0,255,191,110,49,255,255,255,255,255,223,255,175,91,204,223,255,255,255,255,15,0,144,239,255,251,255,255,255,255,253,255,239,255,255,2,128,247,255,127,0,64,249,254,191,255,95,255,155,255,255,255,255,239,255,255,215,255,230,255,255,255,255,251,7,0,202,247,255,249,255,255,255,255,255,127,221,98,254,255,255,255,191,255,255,95,183,152,255,255,255,255,239,255,255,255,255,247,15,0,148,239,255,3,0,202,247,255,1,0,229,251,255,0,0,50,253,121,255,191,110,49,127,0,160,124,255,223,255,143,255,205,255,255,215,255,230,255,255,235,127,243,255,255,245,191,249,255,255,250,223,252,255,127,253,111,254,255,191,254,55,255,255,95,255,155,255,255,175,255,205,255,255,215,255,230,255,255,235,127,243,255,255,245,191,249,255,255,250,223,252,255,127,253,111,254,1,64,255,255,255,255,255,254,127,126,0,112,255,255,255,255,255,243,255,255,255,235,22,243,255,255,253,255,255,255,255,239,255,255,254,127,221,98,254,255,255,159,255,255,255,223,255,255,127,0,208,191,255,31,0,40,223,255,247,255,247,239,255,7,0,202,247,255,1,0,229,251,255,0,128,242,253,127,0,64,249,254,63,0,160,124,255,31,0,80,190,255,15,0,40,223,255,7,0,148,239,255,3,0,202,247,255,1,0,229,251,255,0,128,242,253,127,0,64,249,254,63,0,160,124,255,31,0,80,190,255,15,0,40,223,255,247,255,255,127,247,255,255,255,191,251,255,255,250,223,252,255,127,253,111,254,255,255,255,255,195,255,191,255,223,255,255,255,255,255,240,255,255,255,127,248,255,235,127,243,143,63,254,63,248,3,0,229,251,255,254,127,253,111,254,255,255,253,255,255,255,255,254,255,255,255,127,255,255,255,255,191,255,255,31,0,188,255,255,7,0,254,255,255,127,255,255,255,255,189,255,250,253,255,222,127,253,254,127,239,191,126,255,191,247,95,191,255,255,255,255,255,127,248,255,247,239,255,255,255,255,254,255,255,255,253,251,255,255,255,251,255,255,223,255,255,255,127,0,224,255,255,255,255,223,191,255,255,255,239,223,255,255,255,247,239,255,255,255,251,247,255,255,255,253,251,255,255,255,254,253,255,255,127,255,254,255,255,191,127,255,255,255,223,191,255,255,255,239,223,255,255,255,255,239,255,255,255,251,247,255,255,255,253,251,255,3,128,254,253,255,0,64,249,254,63,0,160,124,255,31,0,80,190,255,15,0,40,223,255,247,255,127,255,255,255,255,245,191,249,255,255,255,255,15,255,255,255,127,255,255,255,255,255,195,255,95,183,152,255,255,175,255,205,63,254,248,255,224,255,255,223,255,255,7,0,254,255,255,255,255,253,251,255,255,255,254,253,255,255,255,251,255,127,0,224,255,255,255,255,255,191,255,255,255,215,255,230,255,255,235,22,243,143,63,254,63,248,7,0,239,255,255,255,255,254,255,255,0,64,249,254,191,255,255,255,255,161,255,255,255,255,31,0,0,71,5,0,0,0,0,0,0,212,0,0,0,0,0,0,0,163,142,58,234,168,163,142,58,58,234,168,163,142,58,234,168,168,163,78,91,234,168,163,142,146,58,177,168,163,78,78,97,93,116,209,83,76,45,117,20,83,86,90,93,133,165,142,58,26,106,168,161,134,26,106,168,168,161,134,26,106,168,161,134,134,26,3,168,145,134,26,106,106,36,21,84,11,205,148,160,17,132,0,73,164,144,66,12,112,41,116,81,83,73,33,52,120,227,141,55,222,120,163,70,141,55,222,120,227,141,55,222,222,72,224,141,4,222,120,227,35,65,113,13,73,144,157,55,4,201,117,39,129,4,18,120,85,36,137,36,146,72,34,129,134,24,98,136,33,134,55,17,98,136,33,134,24,98,136,33,225,129,24,30,136,33,134,24,7,213,53,231,129,35,98,136,132,231,136,7,30,120,32,134,158,37,150,88,98,137,7,30,94,120,225,133,23,98,164,87,225,133,23,94,120,225,133,23,6,94,104,224,133,23,94,120,196,215,158,6,182,120,225,133,160,45,26,104,160,129,23,26,178,148,88,97,132,26,104,208,97,134,25,102,120,1,0,0,25,102,152,97,134,25,102,152,152,97,129,25,102,152,97,134,0,0,22,248,98,134,25,22,190,88,96,129,5,102,88,0,0,0,0,0,88,96,1,0,27,110,184,97,6,0,0,0,184,225,134,27,110,184,225,134,134,3,110,184,225,134,27,110,0,56,160,139,27,110,56,224,226,128,3,14,184,225,0,0,0,0,0,224,128,3,0,232,120,226,137,27,0,0,0,0,137,39,158,120,226,137,39,158,154,120,226,137,39,158,120,226,160,137,53,158,120,162,137,39,38,154,104,226,137,38,0,0,40,0,128,38,154,0,96,141,138,40,158,0,0,128,1,0,162,136,34,138,40,162,136,34,32,138,40,162,136,34,138,40,54,210,136,34,138,1,162,40,152,163,140,40,206,8,99,140,128,51,194,24,163,141,52,226,82,136,2,128,56,230,40,3,33,133,20,82,72,33,133,20,28,82,72,33,133,20,82,72,0,32,133,20,134,72,161,72,128,33,82,40,2,0,0,0,0,0,0,0,0,0,0,0,33,5,0,0,0,0,0,0,21,86,88,97,133,21,86,88,88,97,133,21,86,88,97,133,128,21,86,0,96,5,0,86,0,88,1,0,0,0,0,0,0,0,0,0,0,0,0,0,21,0,0,0,0,0,0,0,8,33,132,16,66,8,33,132,70,32,125,228,33,132,16,66,66,8,1,128,16,0,196,209,32,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,132,17,70,24,97,132,17,66,129,244,97,132,17,70,24,97,97,4,0,70,0,16,71,27,17,0,0,0,0,0,0,24,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,74,40,161,132,18,74,24,1,162,132,18,74,40,161,132,18,18,0,40,1,64,28,109,4,0,0,0,0,0,0,160,132,0,0,0,0,0,0,0,74,0,0,0,0,0,0,0,0,225,132,19,78,56,161,4,0,19,78,56,225,132,19,78,56,0,224,4,0,113,180,225,132,0,0,0,0,0,128,19,78,0,0,0,0,0,0,56,1,0,0,0,0,0,0,0,0,12,50,200,32,131,19,0,0,228,81,71,36,141,36,34,131,128,12,0,196,209,70,32,125,0,0,0,0,0,50,200,0,0,0,0,0,0,32,3,0,0,0,0,0,0,0,0,0,216,96,131,13,50,0,0,0,71,29,145,52,98,131,13,54,54,0,16,71,27,129,244,145,0,0,0,0,216,96,3,0,0,0,0,0,128,13,0,0,0,0,0,0,0,0,0,0,131,14,58,216,0,0,0,0,117,68,162,131,14,58,232,160,0,64,28,109,4,210,71,30,0,0,0,160,131,14,0,232,0,0,0,0,58,0,0,0,0,0,0,0,0,0,0,0,62,248,160,3,0,0,0,0,225,131,15,62,248,224,131,15,0,113,180,17,72,31,121,212,0,0,128,15,62,0,224,3,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,96,130,15,0,0,0,0,0,36,141,36,82,72,39,153,152,196,209,70,32,125,228,81,71,0,0,38,152,0,128,9,0,0,0,96,2,0,0,0,0,0,0,0,0,0,0,0,0,10,38,0,0,0,0,0,0,52,146,72,33,157,168,160,130,71,27,129,244,145,71,29,145,0,168,160,2,0,42,0,16,0,128,10,0,0,0,0,0,0,0,0,0,0,0,0,0,168,0,0,0,0,0,0,0,72,34,133,184,224,130,11,46,109,4,210,71,30,117,68,210,224,130,11,0,184,0,64,28,0,46,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,90,104,161,133,22,90,104,225,161,133,22,90,104,161,133,22,22,90,0,160,5,0,90,104,104,1,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,227,143,63,254,248,227,143,22,63,254,248,227,143,63,254,248,248,3,128,63,0,248,227,143,15,0,0,0,0,0,0,254,0,0,0,0,0,0,0,224,0,0,0,0,0,0,0,0,41,166,152,98,138,41,254,0,152,98,138,41,166,152,98,138,10,0,166,0,96,138,41,166,0,0,0,0,0,0,152,98,0,0,0,0,0,0,128,41,0,0,0,0,0,0,0,0,232,163,143,62,250,152,2,0,143,62,250,232,163,143,62,250,0,232,3,128,62,250,232,163,0,0,0,0,0,160,143,62,0,0,0,0,0,0,250,0,0,0,0,0,0,0,0,0,143,61,246,216,163,15,0,0,246,216,99,143,61,246,216,99,96,15,0,246,216,99,143,61,0,0,0,0,128,61,246,0,0,0,0,0,0,216,3,0,0,0,0,0,0,0,0,0,242,200,35,143,61,0,0,0,35,143,60,242,200,35,143,60,60,0,200,35,143,60,242,200,0,0,0,0,242,200,3,128,0,0,0,0,32,15,0,0,0,0,0,0,0,0,0,0,227,142,59,242,0,0,0,0,59,238,184,227,142,59,238,184,0,224,142,59,238,184,227,142,0,0,0,184,227,14,0,238,0,0,0,128,59,0,0,0,0,0,0,0,0,0,0,0,28,114,184,3,0,0,0,0,200,33,135,28,114,200,33,135,128,28,114,200,33,135,28,114,0,0,32,135,28,0,200,1,146,73,37,114,0,0,0,0,30,117,68,210,72,34,133,116,184,34,71,28,109,4,210,71,0,0,0,0,32,66,70,0,133,116,146,73,37,174,0,0,210,71,30,117,68,210,72,34,0,0,168,34,66,28,109,4,0,0,0,0,0,0,16,29,72,34,133,116,146,73,37,170,109,4,210,71,30,117,68,210,146,73,37,122,36,4,64,28,30,117,68,210,72,34,133,116,84,34,72,28,109,4,210,71,71,36,141,36,82,72,39,153,118,196,209,70,32,125,228,81,210,72,34,133,116,146,73,37,28,109,4,210,71,30,117,68,36,82,72,39,153,84,226,71,70,32,125,228,81,71,36,141,33,2,144,90,107,161,197,209,150,25,0,6,1,0,0,0,0,2,1,0,128,65,157,85,25,4,128,64,0,56,4,0,66,23,181,229,22,130,65,14,14,1,224,144,64,6,9,148,164,16,0,2,1,0,128,66,0,0,0,0,0,0,0,56,0,160,16,0,10,1,0,0,128,66,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45,21,0,0,0,0,0,0,85,0,0,0,0,0,0,0,0,64,145,206,0,0,0,0,0,32,0,80,164,51,32,0,0,0,0,240,255,111,16,0,4,0,0,0,0,2,0,16,0,0,253,127,8,0,128,255,191,6,1,64,0,160,0,100,0,0,0,0,16,0,0,40,0,25,0,0,0,0,4,248,255,53,8,0,6,0,0,0,0,0,128,34,157,1,0,0,0,64,0,0,160,72,103,0,0,0,0,16,0,0,0,0,8,240,255,107,16,0,252,255,53,8,0,254,255,26,4,0,255,255,205,2,134,0,64,145,206,128,255,95,131,0,32,0,112,0,50,0,0,40,0,25,0,0,20,128,12,0,0,10,64,6,0,0,5,32,3,0,128,2,144,1,0,64,1,200,0,0,160,0,100,0,0,80,0,50,0,0,40,0,25,0,0,20,128,12,0,0,10,64,6,0,0,5,32,3,0,128,2,144,1,254,191,0,0,0,0,0,1,128,129,255,143,0,0,0,0,0,12,0,0,0,20,233,12,0,0,2,0,0,0,0,16,0,0,1,128,34,157,1,0,0,96,0,0,0,32,0,0,128,255,47,64,0,224,255,215,32,0,8,0,8,16,0,248,255,53,8,0,254,255,26,4,0,255,127,13,2,128,255,191,6,1,192,255,95,131,0,224,255,175,65,0,240,255,215,32,0,248,255,107,16,0,252,255,53,8,0,254,255,26,4,0,255,127,13,2,128,255,191,6,1,192,255,95,131,0,224,255,175,65,0,240,255,215,32,0,8,0,0,128,8,0,0,0,64,4,0,0,5,32,3,0,128,2,144,1,0,0,0,0,60,0,64,0,32,0,0,0,0,0,15,0,0,0,128,7,0,20,128,12,112,192,1,192,7,252,255,26,4,0,1,128,2,144,1,0,0,2,0,0,0,0,1,0,0,0,128,0,0,0,0,64,0,0,224,255,67,0,0,248,255,1,0,0,128,0,0,0,0,66,0,5,2,0,33,128,2,1,128,16,64,129,0,64,8,160,64,0,0,0,0,0,128,7,0,8,16,0,0,0,0,1,0,0,0,2,4,0,0,0,4,0,0,32,0,0,0,128,255,31,0,0,0,0,32,64,0,0,0,16,32,0,0,0,8,16,0,0,0,4,8,0,0,0,2,4,0,0,0,1,2,0,0,128,0,1,0,0,64,128,0,0,0,32,64,0,0,0,16,32,0,0,0,0,16,0,0,0,4,8,0,0,0,2,4,0,252,127,1,2,0,255,191,6,1,192,255,95,131,0,224,255,175,65,0,240,255,215,32,0,8,0,128,0,0,0,0,10,64,6,0,0,0,0,240,0,0,0,128,0,0,0,0,0,60,0,160,72,103,0,0,80,0,50,192,1,7,0,31,0,0,32,0,0,248,255,1,0,0,0,0,2,4,0,0,0,1,2,0,0,0,4,0,128,255,31,0,0,0,0,0,64,0,0,0,40,0,25,0,0,20,233,12,112,192,1,192,7,248,255,16,0,0,0,0,1,0,0,255,191,6,1,64,0,0,0,0,94,0,0,0,0,0,0,0,139,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,58,0,0,0,0,0,0,0,9,0,0,0,0,0,0,0,62,0,0,0,0,0,0,0,11,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,38,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,17,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,42,0,0,0,0,0,0,0,78,0,0,0,0,0,0,0,82,0,0,0,0,0,0,0,98,0,0,0,0,0,0,0,102,0,0,0,0,0,0,0,118,0,0,0,0,0,0,0,122,0,0,0,0,0,0,0,138,0,0,0,0,0,0,0,142,0,0,0,0,0,0,0,158,0,0,0,0,0,0,0,162,0,0,0,0,0,0,0,178,0,0,0,0,0,0,0,182,0,0,0,0,0,0,0,198,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18,0,0,0,0,0,0,0,26,0,0,0,0,0,0,0,202,0,0,0,0,0,0,0,218,0,0,0,0,0,0,0,30,0,0,0,0,0,0,0,57,0,0,0,0,0,0,0,31,0,0,0,0,0,0,0,39,0,0,0,0,0,0,0,222,0,0,0,0,0,0,0,11,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,238,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,242,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,0,0,0,0,48,0,0,0,0,0,0,0,20,0,0,0,0,0,0,0,2,1,0,0,0,0,0,0,16,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,6,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,21,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,50,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,23,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,125,12,0,0,0,0,0,0,50,0,0,0,0,0,0,0,1,206,202,255,255,255,255,255,255,255,255,255,255,251,255,255,255,255,255,255,255,255,255,255,255,255,159,239,229,255,255,251,231,123,249,255,255,254,255,255,255,255,255,255,255,255,255,255,239,7,103,229,255,255,251,255,255,253,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,207,215,242,231,123,249,243,189,252,249,94,254,124,47,127,190,151,63,223,203,159,239,229,207,247,242,231,123,249,243,189,252,249,94,254,124,47,127,190,151,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,191,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,253,255,255,254,124,47,127,190,151,255,255,191,255,252,247,255,255,239,255,255,247,243,189,252,255,15,254,255,255,127,190,151,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,249,94,254,255,255,255,251,223,255,255,191,1,103,229,207,247,242,255,63,248,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,231,123,249,255,255,255,255,15,254,255,255,255,255,255,255,255,255,255,255,223,255,255,31,44,1,0,0,0,0,0,0,38,0,0,0,0,0,0,0,21,8,7,3,18,6,2,0,0,26,14,16,75,0,0,15,18,129,23,5,127,22,0,4,44,94,42,15,21,8,7,3,82,22,50,4,49,46,14,16,84,15,21,8,47,48,23,5,14,4,96,87,14,16,21,51,23,5,97,22,23,5,53,22,14,128,21,43,93,95,21,41,23,5,94,22,14,0,0,139,0,0,21,54,23,5,0,22,14,0,0,0,14,0,21,55,23,5,0,22,23,5,0,22,14,0,21,57,0,0,21,56,23,5,0,22,14,0,0,0,0,0,21,58,23,5,0,22,14,0,0,0,14,0,21,59,23,5,0,22,23,5,0,22,14,0,21,61,0,0,21,60,23,5,0,22,14,0,0,0,0,0,21,62,23,5,0,22,14,0,0,0,14,0,21,63,23,5,0,22,23,5,0,22,14,0,21,65,0,0,21,64,23,5,0,22,14,0,0,0,0,0,21,66,23,5,0,22,14,0,0,0,14,0,21,85,23,5,0,22,23,5,0,22,14,0,21,98,0,0,21,86,23,5,0,22,14,0,0,0,0,0,21,112,23,5,0,22,14,0,0,0,14,0,21,126,23,5,0,22,23,5,0,22,14,0,21,135,0,0,21,130,23,5,0,22,14,0,0,0,106,99,101,109,23,5,0,22,101,136,100,106,99,101,131,100,0,0,0,0,0,100,106,99,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,119,37,0,0,0,0,0,0,150,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,2,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,32,0,64,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,16,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,64,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,45,21,0,0,0,0,0,0,85,0,0,0,0,0,0,0,0,64,145,206,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,252,127,0,0,0,0,0,0,0,0,0,160,0,100,0,0,0,0,16,0,0,40,0,25,0,0,0,0,4,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,160,72,103,0,0,0,0,16,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,112,0,50,0,0,40,0,25,0,0,20,128,12,0,0,10,64,6,0,0,5,32,3,0,128,2,144,1,0,64,1,200,0,0,160,0,100,0,0,80,0,50,0,0,40,0,25,0,0,20,128,12,0,0,10,64,6,0,0,5,32,3,0,128,2,144,1,254,191,0,0,0,0,0,1,128,129,255,143,0,0,0,0,0,12,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,96,0,0,0,32,0,0,128,255,15,0,0,0,0,0,0,0,0,0,8,16,0,0,0,1,0,0,0,0,0,0,0,0,124,0,0,0,0,60,0,0,0,0,28,0,0,0,0,12,0,0,0,252,7,0,0,0,252,3,0,0,0,252,1,0,0,0,252,0,0,0,248,127,0,0,0,248,63,0,0,0,248,31,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,8,0,0,0,64,4,0,0,5,32,3,0,128,2,144,1,0,0,0,0,60,0,64,0,32,0,0,0,0,0,15,0,0,0,128,7,0,20,128,12,112,192,1,192,7,0,0,0,0,0,0,128,2,144,1,0,0,2,0,0,0,0,1,0,0,0,128,0,0,0,0,64,0,0,224,255,67,0,0,240,255,1,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,16,0,0,0,0,1,0,0,0,0,0,0,0,0,4,0,0,32,0,0,0,0,255,31,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,252,127,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,10,64,6,0,0,0,0,0,0,0,0,128,0,0,0,0,0,60,0,160,72,103,0,0,80,0,50,192,1,7,0,31,0,0,32,0,0,240,255,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,255,31,0,0,0,0,0,64,0,0,0,40,0,25,0,0,0,0,0,112,192,1,192,7,248,255,16,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,30,0,0,0,0,0,0,0,139,0,0,0,0,0,0,0,3,0,0,0,0,0,0,0,100,161,240,3,0,208,72,0,12,64,128,79,252,111,79,192,16,5,0,0,0,0,0,0,69,0,0,0,39,0,0,0,0,1,0,];
+#[allow(dead_code)] const __GRM_DATA: &[u8] = &[36,0,0,0,36,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,94,13,0,0,0,0,0,0,0,80,114,111,103,114,97,109,80,97,114,115,101,114,10,0,0,0,0,0,0,0,84,111,112,83,116,116,109,110,116,115,9,0,0,0,0,0,0,0,84,111,112,83,116,116,109,110,116,11,0,0,0,0,0,0,0,83,116,116,109,110,116,115,67,111,108,108,7,0,0,0,0,0,0,0,83,116,116,109,110,116,115,6,0,0,0,0,0,0,0,83,116,116,109,110,116,4,0,0,0,0,0,0,0,68,111,99,115,8,0,0,0,0,0,0,0,68,111,99,76,105,110,101,115,4,0,0,0,0,0,0,0,84,101,114,109,4,0,0,0,0,0,0,0,69,120,112,114,4,0,0,0,0,0,0,0,67,111,110,100,3,0,0,0,0,0,0,0,65,114,109,5,0,0,0,0,0,0,0,66,108,111,99,107,5,0,0,0,0,0,0,0,77,97,116,99,104,9,0,0,0,0,0,0,0,77,97,116,99,104,65,114,109,115,8,0,0,0,0,0,0,0,77,97,116,99,104,65,114,109,7,0,0,0,0,0,0,0,80,97,116,116,101,114,110,6,0,0,0,0,0,0,0,80,97,116,78,117,109,10,0,0,0,0,0,0,0,65,115,115,105,103,110,109,101,110,116,10,0,0,0,0,0,0,0,68,101,99,70,117,110,99,65,114,103,14,0,0,0,0,0,0,0,70,117,110,99,65,114,103,68,101,99,76,105,115,116,11,0,0,0,0,0,0,0,68,101,99,70,117,110,99,65,114,103,115,7,0,0,0,0,0,0,0,68,101,99,70,117,110,99,4,0,0,0,0,0,0,0,70,117,110,99,8,0,0,0,0,0,0,0,70,117,110,99,65,114,103,115,7,0,0,0,0,0,0,0,65,114,103,76,105,115,116,6,0,0,0,0,0,0,0,68,101,99,67,109,112,7,0,0,0,0,0,0,0,67,111,109,112,65,114,103,3,0,0,0,0,0,0,0,78,117,109,4,0,0,0,0,0,0,0,68,105,99,101,4,0,0,0,0,0,0,0,66,111,111,108,10,0,0,0,0,0,0,0,73,100,101,110,116,105,102,105,101,114,4,0,0,0,0,0,0,0,78,97,109,101,11,0,0,0,0,0,0,0,84,101,109,112,108,97,116,101,86,97,114,8,0,0,0,0,0,0,0,84,121,112,101,73,110,102,111,54,0,0,0,0,0,0,0,1,7,0,0,0,0,0,0,0,65,78,65,76,89,90,69,1,6,0,0,0,0,0,0,0,65,83,83,73,71,78,1,4,0,0,0,0,0,0,0,83,69,77,73,1,6,0,0,0,0,0,0,0,73,77,80,79,82,84,1,6,0,0,0,0,0,0,0,83,84,82,73,78,71,1,3,0,0,0,0,0,0,0,68,79,67,1,3,0,0,0,0,0,0,0,65,68,68,1,3,0,0,0,0,0,0,0,83,85,66,1,3,0,0,0,0,0,0,0,77,85,76,1,3,0,0,0,0,0,0,0,68,73,86,1,3,0,0,0,0,0,0,0,77,79,68,1,3,0,0,0,0,0,0,0,80,79,87,1,2,0,0,0,0,0,0,0,69,81,1,2,0,0,0,0,0,0,0,78,69,1,2,0,0,0,0,0,0,0,71,84,1,2,0,0,0,0,0,0,0,76,84,1,2,0,0,0,0,0,0,0,71,69,1,2,0,0,0,0,0,0,0,76,69,1,3,0,0,0,0,0,0,0,65,78,68,1,2,0,0,0,0,0,0,0,79,82,1,3,0,0,0,0,0,0,0,88,79,82,1,3,0,0,0,0,0,0,0,78,79,84,1,8,0,0,0,0,0,0,0,76,66,82,65,67,75,69,84,1,8,0,0,0,0,0,0,0,82,66,82,65,67,75,69,84,1,3,0,0,0,0,0,0,0,76,69,78,1,4,0,0,0,0,0,0,0,76,80,65,82,1,4,0,0,0,0,0,0,0,82,80,65,82,1,2,0,0,0,0,0,0,0,73,70,1,4,0,0,0,0,0,0,0,69,76,83,69,1,6,0,0,0,0,0,0,0,82,66,82,65,67,69,1,6,0,0,0,0,0,0,0,76,66,82,65,67,69,1,5,0,0,0,0,0,0,0,77,65,84,67,72,1,5,0,0,0,0,0,0,0,67,79,77,77,65,1,5,0,0,0,0,0,0,0,65,82,82,79,87,1,10,0,0,0,0,0,0,0,85,78,68,69,82,83,67,79,82,69,1,10,0,0,0,0,0,0,0,82,65,78,71,69,95,73,78,67,76,1,3,0,0,0,0,0,0,0,78,85,77,1,5,0,0,0,0,0,0,0,67,79,78,83,84,1,5,0,0,0,0,0,0,0,67,79,76,79,78,1,3,0,0,0,0,0,0,0,76,69,84,1,15,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,65,83,83,73,71,78,1,2,0,0,0,0,0,0,0,70,78,1,4,0,0,0,0,0,0,0,67,79,77,80,1,4,0,0,0,0,0,0,0,68,73,67,69,1,4,0,0,0,0,0,0,0,84,82,85,69,1,5,0,0,0,0,0,0,0,70,65,76,83,69,1,5,0,0,0,0,0,0,0,73,68,69,78,84,1,14,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,83,84,65,82,84,1,17,0,0,0,0,0,0,0,84,69,77,80,76,65,84,69,95,70,65,76,76,66,65,67,75,1,3,0,0,0,0,0,0,0,73,78,84,1,4,0,0,0,0,0,0,0,66,79,79,76,1,7,0,0,0,0,0,0,0,86,69,67,95,73,78,84,1,8,0,0,0,0,0,0,0,86,69,67,95,66,79,79,76,0,54,0,0,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,7,0,0,0,0,0,0,0,1,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,2,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,0,0,0,0,1,6,0,0,0,0,0,0,0,1,0,0,0,1,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,54,0,0,0,0,0,0,0,1,7,0,0,0,0,0,0,0,97,110,97,108,121,122,101,1,1,0,0,0,0,0,0,0,61,1,1,0,0,0,0,0,0,0,59,1,6,0,0,0,0,0,0,0,105,109,112,111,114,116,1,6,0,0,0,0,0,0,0,115,116,114,105,110,103,1,11,0,0,0,0,0,0,0,100,111,99,32,99,111,109,109,101,110,116,1,1,0,0,0,0,0,0,0,43,1,1,0,0,0,0,0,0,0,45,1,1,0,0,0,0,0,0,0,42,1,1,0,0,0,0,0,0,0,47,1,1,0,0,0,0,0,0,0,37,1,2,0,0,0,0,0,0,0,42,42,1,2,0,0,0,0,0,0,0,61,61,1,2,0,0,0,0,0,0,0,33,61,1,1,0,0,0,0,0,0,0,62,1,1,0,0,0,0,0,0,0,60,1,2,0,0,0,0,0,0,0,62,61,1,2,0,0,0,0,0,0,0,60,61,1,1,0,0,0,0,0,0,0,38,1,1,0,0,0,0,0,0,0,124,1,1,0,0,0,0,0,0,0,94,1,1,0,0,0,0,0,0,0,33,1,1,0,0,0,0,0,0,0,91,1,1,0,0,0,0,0,0,0,93,1,3,0,0,0,0,0,0,0,108,101,110,1,1,0,0,0,0,0,0,0,40,1,1,0,0,0,0,0,0,0,41,1,2,0,0,0,0,0,0,0,105,102,1,4,0,0,0,0,0,0,0,101,108,115,101,1,1,0,0,0,0,0,0,0,123,1,1,0,0,0,0,0,0,0,125,1,5,0,0,0,0,0,0,0,109,97,116,99,104,1,1,0,0,0,0,0,0,0,44,1,2,0,0,0,0,0,0,0,61,62,1,1,0,0,0,0,0,0,0,95,1,3,0,0,0,0,0,0,0,46,46,61,1,6,0,0,0,0,0,0,0,110,117,109,98,101,114,1,5,0,0,0,0,0,0,0,99,111,110,115,116,1,1,0,0,0,0,0,0,0,58,1,3,0,0,0,0,0,0,0,108,101,116,1,2,0,0,0,0,0,0,0,58,61,1,2,0,0,0,0,0,0,0,102,110,1,2,0,0,0,0,0,0,0,99,109,1,4,0,0,0,0,0,0,0,100,105,99,101,1,4,0,0,0,0,0,0,0,116,114,117,101,1,5,0,0,0,0,0,0,0,102,97,108,115,101,1,10,0,0,0,0,0,0,0,105,100,101,110,116,105,102,105,101,114,1,2,0,0,0,0,0,0,0,36,123,1,2,0,0,0,0,0,0,0,58,45,1,3,0,0,0,0,0,0,0,105,110,116,1,4,0,0,0,0,0,0,0,98,111,111,108,1,8,0,0,0,0,0,0,0,118,101,99,60,105,110,116,62,1,9,0,0,0,0,0,0,0,118,101,99,60,98,111,111,108,62,0,54,0,0,0,53,0,0,0,112,0,0,0,111,0,0,0,112,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,9,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,9,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,3,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,3,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,1,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,2,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,3,0,0,0,1,0,0,0,4,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,5,0,0,0,0,0,0,0,9,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,9,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,5,0,0,0,0,0,0,0,6,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,6,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,19,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,27,0,0,0,1,0,0,0,2,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,23,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,7,0,0,0,0,0,0,0,19,0,0,0,1,0,0,0,2,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,7,0,0,0,0,0,0,0,27,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,7,0,0,0,0,0,0,0,23,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,1,0,0,0,5,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,5,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,6,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,7,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,8,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,9,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,10,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,11,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,12,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,13,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,14,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,15,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,16,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,17,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,18,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,19,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,20,0,0,0,0,0,0,0,10,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,21,0,0,0,0,0,0,0,10,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,7,0,0,0,0,0,0,0,10,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,22,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,23,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,24,0,0,0,1,0,0,0,25,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,26,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,22,0,0,0,0,0,0,0,26,0,0,0,1,0,0,0,23,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,25,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,26,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,13,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,24,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,29,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,30,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,34,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,27,0,0,0,0,0,0,0,10,0,0,0,0,0,0,0,12,0,0,0,1,0,0,0,28,0,0,0,0,0,0,0,12,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,27,0,0,0,0,0,0,0,10,0,0,0,0,0,0,0,12,0,0,0,1,0,0,0,28,0,0,0,0,0,0,0,11,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,29,0,0,0,0,0,0,0,4,0,0,0,1,0,0,0,30,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,29,0,0,0,0,0,0,0,4,0,0,0,1,0,0,0,30,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,29,0,0,0,0,0,0,0,15,0,0,0,1,0,0,0,30,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,31,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,29,0,0,0,0,0,0,0,15,0,0,0,1,0,0,0,32,0,0,0,1,0,0,0,30,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,15,0,0,0,1,0,0,0,32,0,0,0,0,0,0,0,16,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,17,0,0,0,1,0,0,0,33,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,34,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,18,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,18,0,0,0,1,0,0,0,35,0,0,0,0,0,0,0,18,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,36,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,7,0,0,0,1,0,0,0,36,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,37,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,38,0,0,0,0,0,0,0,35,0,0,0,1,0,0,0,12,0,0,0,0,0,0,0,10,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,37,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,1,0,0,0,0,0,0,0,10,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,39,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,38,0,0,0,0,0,0,0,35,0,0,0,1,0,0,0,12,0,0,0,0,0,0,0,10,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,39,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,1,0,0,0,0,0,0,0,10,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,37,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,38,0,0,0,0,0,0,0,35,0,0,0,1,0,0,0,40,0,0,0,0,0,0,0,10,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,37,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,40,0,0,0,0,0,0,0,10,0,0,0,6,0,0,0,0,0,0,0,1,0,0,0,39,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,38,0,0,0,0,0,0,0,35,0,0,0,1,0,0,0,40,0,0,0,0,0,0,0,10,0,0,0,4,0,0,0,0,0,0,0,1,0,0,0,39,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,40,0,0,0,0,0,0,0,10,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,38,0,0,0,0,0,0,0,35,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,21,0,0,0,1,0,0,0,32,0,0,0,0,0,0,0,20,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,25,0,0,0,1,0,0,0,26,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,25,0,0,0,0,0,0,0,21,0,0,0,1,0,0,0,26,0,0,0,7,0,0,0,0,0,0,0,1,0,0,0,41,0,0,0,0,0,0,0,33,0,0,0,0,0,0,0,22,0,0,0,0,0,0,0,35,0,0,0,1,0,0,0,29,0,0,0,0,0,0,0,4,0,0,0,1,0,0,0,30,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,25,0,0,0,2,0,0,0,0,0,0,0,1,0,0,0,25,0,0,0,1,0,0,0,26,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,25,0,0,0,0,0,0,0,26,0,0,0,1,0,0,0,26,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,26,0,0,0,1,0,0,0,32,0,0,0,0,0,0,0,10,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,10,0,0,0,0,0,0,0,1,0,0,0,42,0,0,0,0,0,0,0,33,0,0,0,1,0,0,0,25,0,0,0,0,0,0,0,28,0,0,0,1,0,0,0,32,0,0,0,0,0,0,0,28,0,0,0,1,0,0,0,32,0,0,0,0,0,0,0,28,0,0,0,1,0,0,0,26,0,0,0,0,0,0,0,35,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,31,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,29,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,34,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,6,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,7,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,8,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,18,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,19,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,20,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,36,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,43,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,44,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,45,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,46,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,43,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,47,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,40,0,0,0,0,0,0,0,34,0,0,0,1,0,0,0,30,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,47,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,40,0,0,0,1,0,0,0,36,0,0,0,1,0,0,0,30,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,47,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,48,0,0,0,0,0,0,0,34,0,0,0,1,0,0,0,30,0,0,0,5,0,0,0,0,0,0,0,1,0,0,0,47,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,48,0,0,0,1,0,0,0,36,0,0,0,1,0,0,0,30,0,0,0,3,0,0,0,0,0,0,0,1,0,0,0,47,0,0,0,0,0,0,0,32,0,0,0,1,0,0,0,30,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,49,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,50,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,51,0,0,0,1,0,0,0,0,0,0,0,1,0,0,0,52,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,36,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,111,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,0,0,0,0,3,0,0,0,4,0,0,0,3,0,0,0,0,0,0,0,5,0,0,0,6,0,0,0,7,0,0,0,2,0,0,0,0,0,0,0,8,0,0,0,9,0,0,0,2,0,0,0,0,0,0,0,10,0,0,0,11,0,0,0,6,0,0,0,0,0,0,0,12,0,0,0,13,0,0,0,14,0,0,0,15,0,0,0,16,0,0,0,17,0,0,0,1,0,0,0,0,0,0,0,18,0,0,0,2,0,0,0,0,0,0,0,19,0,0,0,20,0,0,0,1,0,0,0,0,0,0,0,21,0,0,0,29,0,0,0,0,0,0,0,22,0,0,0,23,0,0,0,24,0,0,0,25,0,0,0,26,0,0,0,27,0,0,0,28,0,0,0,29,0,0,0,30,0,0,0,31,0,0,0,32,0,0,0,33,0,0,0,34,0,0,0,35,0,0,0,36,0,0,0,37,0,0,0,38,0,0,0,39,0,0,0,40,0,0,0,41,0,0,0,42,0,0,0,43,0,0,0,44,0,0,0,45,0,0,0,46,0,0,0,47,0,0,0,48,0,0,0,49,0,0,0,50,0,0,0,2,0,0,0,0,0,0,0,51,0,0,0,52,0,0,0,1,0,0,0,0,0,0,0,53,0,0,0,1,0,0,0,0,0,0,0,54,0,0,0,2,0,0,0,0,0,0,0,55,0,0,0,56,0,0,0,2,0,0,0,0,0,0,0,57,0,0,0,58,0,0,0,1,0,0,0,0,0,0,0,59,0,0,0,3,0,0,0,0,0,0,0,60,0,0,0,61,0,0,0,62,0,0,0,2,0,0,0,0,0,0,0,63,0,0,0,64,0,0,0,8,0,0,0,0,0,0,0,65,0,0,0,66,0,0,0,67,0,0,0,68,0,0,0,69,0,0,0,70,0,0,0,71,0,0,0,72,0,0,0,1,0,0,0,0,0,0,0,73,0,0,0,2,0,0,0,0,0,0,0,74,0,0,0,75,0,0,0,2,0,0,0,0,0,0,0,76,0,0,0,77,0,0,0,1,0,0,0,0,0,0,0,78,0,0,0,1,0,0,0,0,0,0,0,79,0,0,0,2,0,0,0,0,0,0,0,80,0,0,0,81,0,0,0,2,0,0,0,0,0,0,0,82,0,0,0,83,0,0,0,1,0,0,0,0,0,0,0,84,0,0,0,10,0,0,0,0,0,0,0,85,0,0,0,86,0,0,0,87,0,0,0,88,0,0,0,89,0,0,0,90,0,0,0,91,0,0,0,92,0,0,0,93,0,0,0,94,0,0,0,1,0,0,0,0,0,0,0,95,0,0,0,1,0,0,0,0,0,0,0,96,0,0,0,2,0,0,0,0,0,0,0,97,0,0,0,98,0,0,0,1,0,0,0,0,0,0,0,99,0,0,0,2,0,0,0,0,0,0,0,100,0,0,0,101,0,0,0,5,0,0,0,0,0,0,0,102,0,0,0,103,0,0,0,104,0,0,0,105,0,0,0,106,0,0,0,4,0,0,0,0,0,0,0,107,0,0,0,108,0,0,0,109,0,0,0,110,0,0,0,112,0,0,0,0,0,0,0,1,0,0,0,1,0,0,0,1,0,0,0,2,0,0,0,2,0,0,0,3,0,0,0,3,0,0,0,3,0,0,0,4,0,0,0,4,0,0,0,5,0,0,0,5,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,6,0,0,0,7,0,0,0,8,0,0,0,8,0,0,0,9,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,10,0,0,0,11,0,0,0,11,0,0,0,12,0,0,0,13,0,0,0,14,0,0,0,14,0,0,0,15,0,0,0,15,0,0,0,16,0,0,0,17,0,0,0,17,0,0,0,17,0,0,0,18,0,0,0,18,0,0,0,19,0,0,0,19,0,0,0,19,0,0,0,19,0,0,0,19,0,0,0,19,0,0,0,19,0,0,0,19,0,0,0,20,0,0,0,21,0,0,0,21,0,0,0,22,0,0,0,22,0,0,0,23,0,0,0,24,0,0,0,25,0,0,0,25,0,0,0,26,0,0,0,26,0,0,0,27,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,28,0,0,0,29,0,0,0,30,0,0,0,31,0,0,0,31,0,0,0,32,0,0,0,33,0,0,0,33,0,0,0,34,0,0,0,34,0,0,0,34,0,0,0,34,0,0,0,34,0,0,0,35,0,0,0,35,0,0,0,35,0,0,0,35,0,0,0,0,0,0,0,112,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,7,0,0,0,0,0,0,0,1,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,1,2,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,0,0,0,0,1,6,0,0,0,0,0,0,0,1,0,0,0,1,6,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,0,1,3,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,4,0,0,0,0,0,0,0,0,0,0,0,1,5,0,0,0,0,0,0,0,0,0,0,0,1,2,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,1,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,112,0,0,0,0,0,0,0,1,70,0,0,0,0,0,0,0,123,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,50,63,41,59,32,66,111,100,121,58,58,110,101,119,40,118,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,125,1,40,0,0,0,0,0,0,0,66,111,100,121,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,66,111,100,121,58,58,110,101,119,40,118,101,99,33,91,36,49,63,93,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,38,0,0,0,0,0,0,0,123,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,50,63,41,59,32,79,107,40,118,41,32,125,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,2,0,0,0,0,0,0,0,36,49,1,89,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,65,110,97,108,121,122,101,58,58,110,101,119,40,36,50,63,44,36,52,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,107,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,73,109,112,111,114,116,58,58,110,101,119,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,36,50,44,78,111,110,101,41,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,37,0,0,0,0,0,0,0,123,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,50,63,41,59,32,79,107,40,118,41,125,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,38,0,0,0,0,0,0,0,123,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,50,63,41,59,32,79,107,40,118,41,32,125,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,50,63,46,119,105,116,104,95,100,111,99,40,36,49,63,41,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,50,63,46,119,105,116,104,95,100,111,99,40,36,49,63,41,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,50,63,46,119,105,116,104,95,100,111,99,40,36,49,63,41,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,39,0,0,0,0,0,0,0,68,111,99,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,60,0,0,0,0,0,0,0,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,50,44,32,78,111,110,101,41,63,41,59,32,79,107,40,118,41,1,38,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,49,44,32,78,111,110,101,41,63,93,41,1,45,0,0,0,0,0,0,0,83,116,97,116,101,109,101,110,116,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,65,68,68,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,83,85,66,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,77,85,76,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,68,73,86,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,77,79,68,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,80,79,87,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,69,81,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,78,69,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,71,84,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,76,84,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,71,69,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,76,69,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,65,78,68,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,79,82,32,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,100,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,36,49,63,44,79,112,58,58,88,79,82,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,106,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,85,110,97,114,121,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,85,110,97,114,121,79,112,58,58,78,111,116,44,36,50,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,106,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,85,110,97,114,121,79,112,101,114,97,116,105,111,110,58,58,110,101,119,40,85,110,97,114,121,79,112,58,58,78,101,103,44,36,50,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,88,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,73,110,100,101,120,58,58,110,101,119,40,36,49,63,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,85,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,76,101,110,103,116,104,58,58,110,101,119,40,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,96,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,67,111,108,108,101,99,116,105,111,110,76,105,116,101,114,97,108,58,58,110,101,119,40,36,50,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,46,0,0,0,0,0,0,0,69,120,112,114,101,115,115,105,111,110,58,58,110,101,119,40,36,49,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,58,0,0,0,0,0,0,0,67,111,110,100,105,116,105,111,110,97,108,58,58,110,101,119,40,36,50,63,44,32,36,51,63,44,32,36,53,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,89,0,0,0,0,0,0,0,67,111,110,100,105,116,105,111,110,97,108,58,58,110,101,119,40,36,50,63,44,32,36,51,63,44,32,67,111,110,100,105,116,105,111,110,97,108,58,58,105,110,116,111,95,101,120,112,114,101,115,115,105,111,110,40,36,53,63,41,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,47,0,0,0,0,0,0,0,66,108,111,99,107,58,58,105,110,116,111,95,97,114,109,40,36,50,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,42,0,0,0,0,0,0,0,66,108,111,99,107,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,47,0,0,0,0,0,0,0,77,97,116,99,104,58,58,110,101,119,40,36,50,63,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,47,0,0,0,0,0,0,0,77,97,116,99,104,58,58,110,101,119,40,36,50,63,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,35,0,0,0,0,0,0,0,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,51,63,41,59,32,79,107,40,118,41,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,50,0,0,0,0,0,0,0,77,97,116,99,104,65,114,109,58,58,110,101,119,40,36,49,63,44,32,36,51,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,62,0,0,0,0,0,0,0,80,97,116,116,101,114,110,58,58,110,101,119,40,80,97,116,116,101,114,110,75,105,110,100,58,58,87,105,108,100,99,97,114,100,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,74,0,0,0,0,0,0,0,80,97,116,116,101,114,110,58,58,110,101,119,40,80,97,116,116,101,114,110,75,105,110,100,58,58,86,97,108,117,101,40,66,111,120,58,58,110,101,119,40,36,49,63,41,41,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,89,0,0,0,0,0,0,0,80,97,116,116,101,114,110,58,58,110,101,119,40,80,97,116,116,101,114,110,75,105,110,100,58,58,82,97,110,103,101,40,66,111,120,58,58,110,101,119,40,36,49,63,41,44,32,66,111,120,58,58,110,101,119,40,36,51,63,41,41,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,32,36,115,112,97,110,41,41,1,30,0,0,0,0,0,0,0,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,1,30,0,0,0,0,0,0,0,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,1,58,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,116,114,117,101,44,32,36,52,63,44,32,36,54,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,116,114,117,101,44,32,78,111,110,101,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,102,97,108,115,101,44,32,36,52,63,44,32,36,54,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,60,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,102,97,108,115,101,44,32,78,111,110,101,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,58,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,116,114,117,101,44,32,36,52,63,44,32,36,54,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,116,114,117,101,44,32,78,111,110,101,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,102,97,108,115,101,44,32,36,52,63,44,32,36,54,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,60,0,0,0,0,0,0,0,65,115,115,105,103,110,58,58,110,101,119,40,36,50,63,44,32,102,97,108,115,101,44,32,78,111,110,101,44,32,36,52,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,50,0,0,0,0,0,0,0,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,36,51,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,35,0,0,0,0,0,0,0,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,51,63,41,59,32,79,107,40,118,41,1,13,0,0,0,0,0,0,0,79,107,40,118,101,99,33,91,36,49,63,93,41,1,14,0,0,0,0,0,0,0,79,107,40,86,101,99,58,58,110,101,119,40,41,41,1,2,0,0,0,0,0,0,0,36,50,1,58,0,0,0,0,0,0,0,70,117,110,99,116,105,111,110,68,101,99,58,58,110,101,119,40,36,50,63,44,36,51,63,44,36,54,63,44,36,52,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,47,0,0,0,0,0,0,0,73,110,118,111,107,101,58,58,110,101,119,40,36,49,63,44,32,36,50,63,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,14,0,0,0,0,0,0,0,79,107,40,86,101,99,58,58,110,101,119,40,41,41,1,2,0,0,0,0,0,0,0,36,50,1,35,0,0,0,0,0,0,0,108,101,116,32,109,117,116,32,118,32,61,32,36,49,63,59,32,118,46,112,117,115,104,40,36,51,63,41,59,32,79,107,40,118,41,1,15,0,0,0,0,0,0,0,79,107,40,32,118,101,99,33,91,36,49,63,93,32,41,1,73,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,58,58,110,101,119,40,36,50,63,44,36,52,63,44,36,54,63,44,36,56,63,44,36,49,48,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,59,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,36,49,63,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,65,68,68,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,83,85,66,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,77,85,76,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,65,78,68,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,62,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,79,82,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,63,0,0,0,0,0,0,0,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,58,58,110,101,119,40,79,112,58,58,88,79,82,44,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,30,0,0,0,0,0,0,0,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,1,47,0,0,0,0,0,0,0,68,105,99,101,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,49,44,32,83,112,97,110,58,58,105,110,116,111,40,36,108,101,120,101,114,44,36,115,112,97,110,41,41,1,30,0,0,0,0,0,0,0,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,1,30,0,0,0,0,0,0,0,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,1,51,0,0,0,0,0,0,0,79,107,40,32,73,100,101,110,116,58,58,110,101,119,40,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,32,41,32,41,1,2,0,0,0,0,0,0,0,36,49,1,39,0,0,0,0,0,0,0,69,114,114,40,36,49,46,117,110,119,114,97,112,95,111,114,95,101,108,115,101,40,124,108,101,120,101,109,101,124,32,108,101,120,101,109,101,41,41,1,86,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,97,115,115,105,103,110,40,36,52,63,41,41,41,1,111,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,97,115,115,105,103,110,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,52,44,32,78,111,110,101,41,63,41,41,41,1,88,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,102,97,108,108,98,97,99,107,40,36,52,63,41,41,41,1,113,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,58,58,102,97,108,108,98,97,99,107,40,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,36,52,44,32,78,111,110,101,41,63,41,41,41,1,61,0,0,0,0,0,0,0,79,107,40,84,101,109,112,108,97,116,101,58,58,110,101,119,40,36,50,63,44,32,83,112,97,110,58,58,110,101,119,40,36,108,101,120,101,114,44,32,78,111,110,101,44,32,36,115,112,97,110,41,63,44,32,78,111,110,101,41,41,1,13,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,73,110,116,41,1,14,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,66,111,111,108,41,1,19,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,67,111,108,108,79,102,73,110,116,41,1,20,0,0,0,0,0,0,0,79,107,40,75,105,110,100,58,58,67,111,108,108,79,102,66,111,111,108,41,0,1,183,4,0,0,0,0,0,0,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,115,112,97,110,58,58,123,83,112,97,110,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,107,105,110,100,58,58,123,75,105,110,100,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,97,110,97,108,121,122,101,58,58,123,65,110,97,108,121,122,101,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,97,114,103,115,58,58,123,70,117,110,99,116,105,111,110,65,114,103,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,102,117,110,99,58,58,123,70,117,110,99,116,105,111,110,68,101,99,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,99,111,109,112,97,114,103,58,58,123,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,44,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,105,100,101,110,116,58,58,123,73,100,101,110,116,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,105,110,118,111,107,101,58,58,123,73,110,118,111,107,101,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,101,120,112,114,58,58,123,69,120,112,114,101,115,115,105,111,110,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,116,101,109,112,108,97,116,101,58,58,123,84,101,109,112,108,97,116,101,44,84,101,109,112,108,97,116,101,66,101,104,97,118,105,111,114,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,97,115,115,105,103,110,58,58,123,65,115,115,105,103,110,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,111,112,58,58,123,79,112,44,79,112,101,114,97,116,105,111,110,44,85,110,97,114,121,79,112,44,85,110,97,114,121,79,112,101,114,97,116,105,111,110,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,115,116,97,116,101,109,101,110,116,58,58,123,83,116,97,116,101,109,101,110,116,44,66,111,100,121,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,99,111,110,100,105,116,105,111,110,58,58,123,67,111,110,100,105,116,105,111,110,97,108,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,98,108,111,99,107,58,58,123,66,108,111,99,107,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,109,97,116,99,104,105,110,103,58,58,123,77,97,116,99,104,44,77,97,116,99,104,65,114,109,44,80,97,116,116,101,114,110,44,80,97,116,116,101,114,110,75,105,110,100,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,99,111,108,108,101,99,116,105,111,110,58,58,123,67,111,108,108,101,99,116,105,111,110,76,105,116,101,114,97,108,44,73,110,100,101,120,44,76,101,110,103,116,104,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,100,105,99,101,58,58,123,68,105,99,101,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,100,111,99,58,58,123,68,111,99,125,59,10,117,115,101,32,99,114,97,116,101,58,58,105,110,116,101,114,110,97,108,115,58,58,112,97,114,115,101,114,58,58,97,115,116,58,58,105,109,112,111,114,116,58,58,123,73,109,112,111,114,116,125,59,10,36,0,0,0,0,0,0,0,0,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,66,111,100,121,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,41,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,83,116,97,116,101,109,101,110,116,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,36,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,116,97,116,101,109,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,41,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,83,116,97,116,101,109,101,110,116,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,41,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,83,116,97,116,101,109,101,110,116,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,36,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,116,97,116,101,109,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,30,0,0,0,0,0,0,0,82,101,115,117,108,116,60,68,111,99,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,36,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,83,112,97,110,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,36,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,116,97,116,101,109,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,37,0,0,0,0,0,0,0,82,101,115,117,108,116,60,69,120,112,114,101,115,115,105,111,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,38,0,0,0,0,0,0,0,82,101,115,117,108,116,60,67,111,110,100,105,116,105,111,110,97,108,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,37,0,0,0,0,0,0,0,82,101,115,117,108,116,60,69,120,112,114,101,115,115,105,111,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,32,0,0,0,0,0,0,0,82,101,115,117,108,116,60,66,108,111,99,107,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,32,0,0,0,0,0,0,0,82,101,115,117,108,116,60,77,97,116,99,104,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,40,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,77,97,116,99,104,65,114,109,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,35,0,0,0,0,0,0,0,82,101,115,117,108,116,60,77,97,116,99,104,65,114,109,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,34,0,0,0,0,0,0,0,82,101,115,117,108,116,60,80,97,116,116,101,114,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,112,97,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,33,0,0,0,0,0,0,0,82,101,115,117,108,116,60,65,115,115,105,103,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,38,0,0,0,0,0,0,0,82,101,115,117,108,116,60,70,117,110,99,116,105,111,110,65,114,103,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,43,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,70,117,110,99,116,105,111,110,65,114,103,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,43,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,70,117,110,99,116,105,111,110,65,114,103,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,38,0,0,0,0,0,0,0,82,101,115,117,108,116,60,70,117,110,99,116,105,111,110,68,101,99,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,33,0,0,0,0,0,0,0,82,101,115,117,108,116,60,73,110,118,111,107,101,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,42,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,69,120,112,114,101,115,115,105,111,110,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,42,0,0,0,0,0,0,0,82,101,115,117,108,116,60,86,101,99,60,69,120,112,114,101,115,115,105,111,110,62,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,48,0,0,0,0,0,0,0,82,101,115,117,108,116,60,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,51,0,0,0,0,0,0,0,82,101,115,117,108,116,60,67,111,109,112,111,115,105,116,105,111,110,97,108,70,117,110,99,116,105,111,110,65,114,103,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,112,97,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,68,105,99,101,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,83,112,97,110,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,32,0,0,0,0,0,0,0,82,101,115,117,108,116,60,73,100,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,32,0,0,0,0,0,0,0,82,101,115,117,108,116,60,73,100,101,110,116,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,35,0,0,0,0,0,0,0,82,101,115,117,108,116,60,84,101,109,112,108,97,116,101,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,1,31,0,0,0,0,0,0,0,82,101,115,117,108,116,60,75,105,110,100,44,108,114,112,97,114,58,58,76,101,120,101,109,101,60,117,51,50,62,62,0,];
+#[allow(dead_code)] const __STABLE_DATA: &[u8] = &[215,0,0,0,0,0,0,0,249,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,6,7,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,106,0,0,0,0,0,0,0,28,0,0,0,0,0,0,0,159,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,48,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,204,4,0,0,0,0,0,0,249,4,0,0,0,0,0,0,1,0,0,0,0,0,0,0,135,0,0,0,0,0,0,0,6,0,0,0,0,0,0,0,146,4,0,0,0,0,0,0,10,0,0,0,0,0,0,0,164,0,0,0,0,0,0,0,193,0,0,0,0,0,0,0,146,4,0,0,0,0,0,0,38,5,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,222,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,251,0,0,0,0,0,0,0,180,4,0,0,0,0,0,0,17,0,0,0,0,0,0,0,146,4,0,0,0,0,0,0,24,1,0,0,0,0,0,0,53,1,0,0,0,0,0,0,146,4,0,0,0,0,0,0,249,4,0,0,0,0,0,0,82,1,0,0,0,0,0,0,111,1,0,0,0,0,0,0,123,4,0,0,0,0,0,0,198,4,0,0,0,0,0,0,26,5,0,0,0,0,0,0,117,4,0,0,0,0,0,0,34,0,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,235,6,0,0,0,0,0,0,1,0,0,0,0,0,0,0,11,0,0,0,0,0,0,0,140,1,0,0,0,0,0,0,146,4,0,0,0,0,0,0,149,5,0,0,0,0,0,0,57,0,0,0,0,0,0,0,83,5,0,0,0,0,0,0,128,5,0,0,0,0,0,0,146,4,0,0,0,0,0,0,235,6,0,0,0,0,0,0,43,0,0,0,0,0,0,0,173,5,0,0,0,0,0,0,129,0,0,0,0,0,0,0,6,7,0,0,0,0,0,0,71,5,0,0,0,0,0,0,218,5,0,0,0,0,0,0,135,0,0,0,0,0,0,0,166,0,0,0,0,0,0,0,169,1,0,0,0,0,0,0,60,7,0,0,0,0,0,0,249,4,0,0,0,0,0,0,159,0,0,0,0,0,0,0,167,0,0,0,0,0,0,0,76,7,0,0,0,0,0,0,164,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,140,4,0,0,0,0,0,0,198,1,0,0,0,0,0,0,161,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,227,1,0,0,0,0,0,0,0,2,0,0,0,0,0,0,118,7,0,0,0,0,0,0,147,7,0,0,0,0,0,0,176,7,0,0,0,0,0,0,205,7,0,0,0,0,0,0,29,2,0,0,0,0,0,0,58,2,0,0,0,0,0,0,87,2,0,0,0,0,0,0,116,2,0,0,0,0,0,0,145,2,0,0,0,0,0,0,33,7,0,0,0,0,0,0,234,7,0,0,0,0,0,0,7,8,0,0,0,0,0,0,174,2,0,0,0,0,0,0,203,2,0,0,0,0,0,0,146,4,0,0,0,0,0,0,232,2,0,0,0,0,0,0,224,8,0,0,0,0,0,0,5,3,0,0,0,0,0,0,1,0,0,0,0,0,0,0,35,8,0,0,0,0,0,0,249,4,0,0,0,0,0,0,190,0,0,0,0,0,0,0,7,6,0,0,0,0,0,0,52,6,0,0,0,0,0,0,68,0,0,0,0,0,0,0,146,4,0,0,0,0,0,0,164,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,148,4,0,0,0,0,0,0,164,4,0,0,0,0,0,0,97,6,0,0,0,0,0,0,34,3,0,0,0,0,0,0,196,0,0,0,0,0,0,0,51,8,0,0,0,0,0,0,63,3,0,0,0,0,0,0,232,4,0,0,0,0,0,0,32,0,0,0,0,0,0,0,21,5,0,0,0,0,0,0,66,5,0,0,0,0,0,0,111,5,0,0,0,0,0,0,235,6,0,0,0,0,0,0,60,7,0,0,0,0,0,0,217,0,0,0,0,0,0,0,225,0,0,0,0,0,0,0,242,0,0,0,0,0,0,0,254,0,0,0,0,0,0,0,93,8,0,0,0,0,0,0,92,3,0,0,0,0,0,0,6,7,0,0,0,0,0,0,13,1,0,0,0,0,0,0,2,0,0,0,0,0,0,0,50,0,0,0,0,0,0,0,101,0,0,0,0,0,0,0,108,0,0,0,0,0,0,0,130,0,0,0,0,0,0,0,137,0,0,0,0,0,0,0,159,0,0,0,0,0,0,0,166,0,0,0,0,0,0,0,188,0,0,0,0,0,0,0,195,0,0,0,0,0,0,0,217,0,0,0,0,0,0,0,224,0,0,0,0,0,0,0,121,3,0,0,0,0,0,0,150,3,0,0,0,0,0,0,27,1,0,0,0,0,0,0,249,0,0,0,0,0,0,0,19,1,0,0,0,0,0,0,48,1,0,0,0,0,0,0,38,1,0,0,0,0,0,0,74,1,0,0,0,0,0,0,53,1,0,0,0,0,0,0,103,1,0,0,0,0,0,0,70,1,0,0,0,0,0,0,112,8,0,0,0,0,0,0,99,0,0,0,0,0,0,0,131,8,0,0,0,0,0,0,216,4,0,0,0,0,0,0,253,0,0,0,0,0,0,0,77,1,0,0,0,0,0,0,26,1,0,0,0,0,0,0,103,1,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,179,3,0,0,0,0,0,0,208,3,0,0,0,0,0,0,237,3,0,0,0,0,0,0,10,4,0,0,0,0,0,0,142,6,0,0,0,0,0,0,224,8,0,0,0,0,0,0,77,0,0,0,0,0,0,0,39,4,0,0,0,0,0,0,68,4,0,0,0,0,0,0,68,0,0,0,0,0,0,0,97,4,0,0,0,0,0,0,132,1,0,0,0,0,0,0,68,0,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,146,4,0,0,0,0,0,0,5,5,0,0,0,0,0,0,0,0,0,0,0,0,0,0,164,4,0,0,0,0,0,0,249,4,0,0,0,0,0,0,150,8,0,0,0,0,0,0,169,8,0,0,0,0,0,0,107,1,0,0,0,0,0,0,126,4,0,0,0,0,0,0,164,1,0,0,0,0,0,0,111,1,0,0,0,0,0,0,33,7,0,0,0,0,0,0,188,8,0,0,0,0,0,0,207,8,0,0,0,0,0,0,55,1,0,0,0,0,0,0,84,1,0,0,0,0,0,0,115,1,0,0,0,0,0,0,224,8,0,0,0,0,0,0,187,6,0,0,0,0,0,0,135,1,0,0,0,0,0,0,164,4,0,0,0,0,0,0,166,1,0,0,0,0,0,0,54,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,90,45,0,0,0,0,0,0,182,0,0,0,0,0,0,0,86,255,159,84,79,49,255,223,255,39,213,251,205,255,255,255,255,255,127,251,255,255,255,255,255,255,254,255,255,255,255,255,183,255,15,0,232,239,255,255,151,3,0,146,233,235,239,253,255,255,255,255,255,127,59,0,32,155,254,255,223,255,255,255,255,255,255,103,245,255,73,245,20,243,253,253,127,82,189,223,252,255,255,255,255,255,183,255,14,0,72,166,255,255,247,253,255,255,255,149,255,91,253,127,82,61,197,124,86,255,159,84,79,49,223,254,255,255,255,255,255,191,3,0,178,233,255,255,253,255,255,247,255,255,255,127,255,159,84,239,55,255,255,255,255,255,87,254,191,3,0,178,233,255,255,237,0,128,108,250,255,127,127,255,159,84,239,55,191,213,255,39,213,83,204,247,255,255,255,255,127,251,239,255,255,255,255,255,255,59,0,32,155,254,255,223,255,255,255,255,255,237,191,3,0,178,233,255,255,125,255,255,255,127,229,255,239,255,255,255,255,255,255,223,255,39,213,251,205,191,3,0,178,233,255,255,237,0,128,108,250,255,127,127,255,159,84,239,55,255,215,255,39,213,83,204,191,3,0,178,233,255,255,237,0,128,108,250,255,127,253,255,255,255,191,254,127,255,255,127,255,175,255,223,255,255,223,255,235,255,255,255,255,255,254,251,251,253,255,255,255,255,255,255,223,255,39,213,251,205,255,247,255,73,245,126,243,255,253,127,82,189,223,252,127,255,159,84,239,55,255,223,255,39,213,251,205,255,247,255,73,245,126,243,255,253,127,82,189,223,252,127,255,159,84,239,55,255,223,255,39,213,251,205,255,247,255,73,245,126,243,255,253,127,82,189,223,252,127,255,159,84,239,55,255,223,255,39,213,251,205,255,247,255,73,245,126,243,255,253,127,82,189,223,252,127,255,159,84,239,55,255,15,0,200,190,255,255,255,255,255,247,239,255,255,255,255,255,247,255,255,255,59,0,32,155,254,255,223,223,255,39,212,251,205,255,253,255,255,255,149,255,255,255,255,255,255,255,127,86,255,159,84,79,49,159,213,255,39,213,83,204,247,247,255,73,245,126,243,255,0,128,126,255,255,255,251,255,255,255,255,255,191,213,255,39,213,83,204,183,255,255,255,255,255,255,255,0,128,126,255,255,255,253,255,255,255,191,254,191,213,255,39,213,83,204,247,255,255,223,255,255,255,239,255,255,255,255,255,255,59,0,32,155,254,255,223,15,0,232,254,255,255,255,245,255,73,245,20,243,255,255,255,255,254,255,255,255,255,255,191,255,255,255,215,255,39,213,83,204,255,255,255,255,255,255,31,254,253,127,82,189,223,252,127,255,159,84,239,55,255,255,255,255,255,251,223,191,3,0,178,233,255,255,253,255,255,255,191,255,253,127,255,159,84,239,55,255,14,0,200,166,255,255,183,3,0,178,233,255,255,237,192,143,108,250,255,127,59,240,35,155,254,255,223,14,252,200,166,255,255,183,3,63,178,233,255,255,237,0,128,108,250,255,127,59,0,32,155,254,255,223,14,0,200,166,255,255,183,3,0,178,233,255,255,237,0,128,108,250,255,127,63,0,32,255,255,255,255,14,252,200,166,255,255,183,3,63,178,233,255,255,237,0,128,108,250,255,127,59,0,32,155,254,255,223,223,255,39,213,251,205,191,3,0,178,233,255,255,253,248,143,255,191,63,252,59,0,32,155,254,255,223,255,255,255,190,255,255,255,3,0,186,255,255,255,127,253,127,82,61,197,252,255,255,255,239,255,255,191,213,255,39,213,83,204,103,245,255,73,245,20,243,253,253,255,255,175,255,255,127,255,159,84,239,55,255,255,255,255,255,255,127,248,247,255,73,245,126,243,255,255,255,239,255,223,254,255,255,255,255,255,255,161,213,255,39,213,83,204,183,3,0,178,233,255,255,253,255,255,255,254,255,255,95,255,159,84,79,49,255,14,0,200,166,255,255,183,255,254,191,237,239,255,255,191,255,255,255,251,255,251,239,255,219,254,254,255,254,251,255,182,191,255,191,255,254,191,237,239,255,239,0,128,254,255,255,255,59,0,160,255,255,255,255,255,255,255,239,255,255,255,255,255,255,251,255,255,255,255,255,255,254,255,255,255,255,255,191,255,255,255,14,0,232,255,255,255,191,3,0,178,233,255,255,253,0,128,236,251,255,255,255,255,255,255,254,255,255,255,255,255,190,255,255,255,255,255,191,239,255,255,255,255,255,239,251,255,255,255,255,255,251,254,255,255,255,255,255,190,255,255,255,255,255,191,239,255,255,255,255,255,239,251,255,255,255,255,255,251,254,255,255,255,255,255,190,255,255,255,255,255,191,239,255,255,255,255,255,239,251,255,255,255,255,255,251,254,255,255,14,0,200,166,255,255,183,3,0,178,233,255,255,253,255,255,255,254,255,255,255,255,255,215,255,255,255,255,255,255,175,255,255,255,255,255,255,235,255,255,255,255,255,255,191,255,255,255,255,255,255,245,255,255,255,255,255,127,255,255,255,255,255,255,95,255,255,255,255,255,255,247,255,255,59,0,160,255,255,255,255,255,251,255,255,191,255,191,3,0,250,255,255,255,255,255,255,255,255,255,135,255,255,255,251,254,255,255,255,255,255,255,239,255,255,255,255,191,239,255,255,255,255,255,127,255,255,255,127,255,159,84,239,55,255,223,255,39,213,251,205,191,3,0,178,233,255,255,237,0,128,108,250,255,127,59,0,32,155,254,255,223,14,0,200,166,255,255,103,245,255,73,245,20,243,253,248,143,255,191,63,252,59,0,32,139,254,255,223,14,0,200,166,255,255,183,3,0,178,233,255,255,253,253,255,255,174,255,255,59,0,32,155,254,255,223,255,255,255,127,253,255,255,247,255,255,255,254,255,255,253,127,82,189,223,252,127,255,159,84,239,55,255,223,255,39,213,251,205,255,255,255,255,255,255,31,254,255,255,255,255,223,254,255,255,255,255,255,255,225,215,255,39,213,83,204,191,3,0,250,255,255,255,239,0,128,254,255,255,255,255,255,255,255,254,255,255,14,0,200,166,255,255,247,255,255,255,235,255,255,255,255,255,255,247,255,255,63,0,160,191,254,255,255,14,0,232,255,255,255,191,3,0,250,255,255,255,255,255,255,239,251,255,255,255,255,255,251,254,255,255,255,255,255,239,255,255,255,227,63,254,255,254,240,91,253,127,82,61,197,124,255,255,255,251,255,255,255,255,255,255,255,255,127,184,255,255,255,255,255,255,3,0,0,0,0,22,9,0,0,0,0,0,0,108,1,0,0,0,0,0,0,150,80,77,21,57,230,24,0,99,142,57,230,152,99,142,57,57,230,152,99,142,57,230,152,88,110,142,57,214,147,99,142,25,57,230,152,97,117,58,230,166,68,111,113,25,86,155,99,177,169,40,161,132,99,74,56,24,147,128,99,105,100,80,93,140,49,198,24,227,88,12,117,198,24,99,140,49,198,24,99,163,128,49,198,24,99,140,49,98,141,26,83,80,176,198,24,40,54,128,49,214,24,99,140,141,53,214,88,99,141,53,214,214,88,99,141,53,214,88,99,106,140,53,214,148,108,141,53,45,214,88,99,77,166,214,84,216,98,139,45,5,11,96,141,139,45,182,216,98,139,45,182,86,217,98,139,45,182,216,98,101,149,45,217,89,99,139,45,87,1,219,34,139,45,182,120,200,34,139,44,178,200,34,139,139,44,178,200,34,139,44,178,182,200,34,139,86,178,200,34,35,139,44,118,105,37,75,123,48,194,8,99,87,125,178,8,8,35,140,48,194,8,35,140,151,48,194,8,35,140,48,194,114,9,99,130,44,194,8,35,85,160,48,126,9,35,140,94,95,126,249,229,151,95,126,233,249,229,151,95,126,249,229,151,140,95,126,153,229,151,95,126,126,249,37,150,89,126,5,42,226,139,47,98,137,224,151,47,47,190,248,226,139,47,190,248,249,226,139,47,190,248,226,139,150,47,201,250,229,139,47,110,205,250,226,138,47,190,168,229,226,138,43,174,184,226,138,90,43,174,184,226,138,43,174,184,184,226,74,180,174,184,226,138,138,43,9,55,231,74,20,190,130,9,214,112,181,174,8,230,38,152,96,130,9,38,152,96,96,130,9,38,152,96,130,9,8,166,142,43,130,9,214,173,174,96,186,8,38,152,75,234,186,232,162,139,46,186,184,20,162,139,46,186,232,162,139,46,46,186,212,171,139,46,186,232,232,162,82,187,186,196,43,152,140,50,42,201,163,139,50,186,202,40,163,140,50,202,40,163,163,140,50,202,40,163,140,50,50,246,232,162,140,50,253,42,43,99,137,50,202,152,148,175,137,37,150,88,98,137,73,17,150,88,98,137,37,150,100,99,18,78,197,150,88,98,137,37,37,73,251,99,137,63,202,88,249,212,180,62,150,248,100,137,147,79,62,249,228,147,79,62,62,249,228,147,79,62,249,228,36,144,37,62,249,84,181,79,79,154,248,228,147,84,2,249,104,162,137,38,154,0,0,128,137,38,154,104,162,73,54,154,225,0,160,137,38,154,104,162,2,128,57,154,152,227,147,38,106,0,0,160,137,106,154,104,169,166,154,106,170,169,166,154,154,106,170,169,166,154,106,170,0,104,162,154,106,0,168,166,166,133,106,170,1,0,128,106,52,205,104,161,5,0,0,168,104,161,133,22,90,100,83,77,14,0,90,104,161,133,22,90,0,0,160,5,0,170,105,17,1,0,0,90,120,161,133,22,23,217,84,19,77,51,94,120,120,225,133,23,94,120,225,133,128,22,94,132,3,128,23,94,98,120,225,5,0,0,120,1,33,134,24,98,0,0,128,23,24,98,136,33,70,54,98,136,0,32,134,24,98,136,33,134,0,0,98,0,224,133,24,225,0,0,32,134,25,98,136,1,145,141,25,102,152,97,134,25,25,102,152,97,134,25,102,152,136,97,70,56,0,152,97,134,134,25,102,0,0,128,25,0,106,168,161,6,0,0,152,161,161,134,26,106,100,163,134,26,0,106,168,161,134,26,106,168,0,160,6,0,102,168,17,14,0,0,106,184,161,134,26,0,217,184,225,134,27,110,184,1,225,134,27,110,184,225,134,27,26,110,132,3,128,27,110,184,184,225,6,0,0,184,1,128,76,46,181,0,0,128,27,146,197,4,211,75,54,213,68,211,32,137,36,237,164,83,78,50,0,146,0,224,134,36,225,0,0,32,137,34,146,72,2,0,77,53,209,52,147,75,45,0,233,148,147,76,49,193,244,146,162,72,56,0,40,162,136,34,34,138,0,0,128,34,0,72,228,82,11,0,0,40,226,136,76,48,189,100,83,77,52,205,221,56,210,78,58,229,36,83,224,8,0,138,56,18,14,0,0,142,152,226,136,35,0,0,152,98,138,41,166,152,2,0,138,41,166,152,98,138,41,166,166,152,2,128,41,166,152,98,98,10,0,0,152,2,128,35,80,66,1,0,128,41,66,153,9,37,148,80,66,9,37,148,148,80,66,9,37,148,80,66,66,1,96,138,80,66,1,32,32,148,42,66,9,5,0,0,42,170,168,162,138,42,0,0,168,162,138,42,170,168,162,138,138,42,0,168,162,138,42,170,170,0,0,128,42,0,8,165,163,13,0,0,168,162,141,42,54,218,104,163,141,54,218,104,104,163,141,54,218,104,163,141,13,0,170,104,163,13,0,218,218,120,162,141,54,0,0,160,226,137,39,158,120,2,0,0,39,158,120,226,137,39,158,120,120,2,128,39,158,120,226,137,9,0,0,120,2,128,54,158,70,1,0,128,39,70,121,226,101,148,81,70,25,101,148,81,81,70,25,101,148,81,70,25,1,224,137,81,70,1,96,148,148,40,70,25,5,0,0,70,162,136,34,138,40,0,0,96,34,138,40,162,136,34,138,40,40,0,136,34,138,40,162,136,0,0,128,40,0,24,37,138,25,0,0,136,162,153,40,162,154,105,166,153,102,154,105,166,166,153,102,154,105,166,153,102,0,162,104,166,25,0,154,105,121,166,153,102,0,0,160,25,153,103,158,121,6,0,0,154,158,121,230,153,103,158,121,230,6,128,103,158,121,230,153,103,0,0,120,6,128,102,158,121,1,0,128,103,162,121,230,25,154,104,162,137,38,154,104,162,162,137,38,154,104,162,137,38,224,153,104,162,1,32,154,104,105,162,137,6,0,0,162,1,153,102,154,105,0,0,32,154,154,105,166,153,102,154,105,166,0,152,102,154,105,166,153,102,0,128,105,0,136,102,154,105,0,0,152,230,140,105,166,1,56,227,140,51,206,56,227,12,140,51,206,56,227,140,51,206,166,57,227,12,0,206,56,227,227,140,51,0,0,224,12,0,52,210,72,3,0,0,206,72,72,35,141,52,210,72,35,141,128,52,210,72,35,141,52,210,0,72,3,128,51,210,72,3,0,128,52,222,72,35,13,0,55,222,120,227,141,55,222,0,120,227,141,55,222,120,227,141,141,55,222,0,224,141,55,222,222,120,3,64,87,222,0,32,35,142,56,0,0,224,141,56,56,226,136,35,142,56,226,136,137,35,142,56,226,136,35,142,136,56,0,120,35,142,56,105,58,136,83,150,56,226,0,80,208,150,14,97,233,144,21,0,112,145,52,1,64,11,5,232,0,16,70,138,149,180,26,69,133,14,58,116,161,131,14,226,169,232,144,136,14,13,232,32,16,163,14,58,100,208,64,6,5,0,232,160,131,14,146,233,0,96,65,3,22,232,160,131,69,133,9,70,152,161,14,22,146,73,33,5,0,82,0,32,96,129,5,0,88,96,1,0,100,22,216,102,1,0,22,0,0,96,129,5,0,72,6,128,1,0,22,88,96,129,109,22,0,244,1,64,32,22,88,16,96,27,0,0,216,102,65,33,76,50,201,36,147,109,0,0,68,210,132,109,45,20,0,128,64,24,178,85,2,64,20,0,0,164,209,5,0,150,1,0,0,64,34,49,212,33,91,2,0,18,0,32,65,3,25,72,201,6,0,0,178,25,32,1,147,77,54,217,36,27,0,150,18,72,32,155,4,18,0,96,32,129,110,18,88,38,129,101,0,18,72,0,64,122,0,0,0,32,129,4,18,232,38,1,128,3,0,56,32,129,4,14,186,1,0,128,110,18,56,0,0,0,0,0,232,6,0,0,3,14,232,230,128,3,0,0,56,224,154,3,225,57,80,158,128,3,14,0,0,0,0,0,0,56,224,128,3,174,57,0,96,3,0,54,56,224,128,13,107,0,0,224,154,3,54,0,0,0,0,0,174,1,0,128,131,13,174,217,96,3,0,0,54,0,96,3,0,54,56,97,96,131,13,0,0,0,0,0,0,54,216,96,3,0,54,0,24,1,128,17,54,216,96,4,0,0,0,0,96,131,17,0,78,56,1,128,19,0,56,1,97,4,0,70,24,1,0,0,17,0,24,1,128,17,0,24,24,97,4,0,0,0,0,128,128,17,70,24,1,128,17,0,50,0,32,131,17,70,200,0,0,0,0,0,24,33,3,0,0,0,0,0,0,0,0,0,200,0,128,12,50,0,0,0,3,0,50,0,32,3,0,50,50,200,0,0,0,0,0,32,32,131,12,50,0,32,3,0,16,0,8,33,131,12,66,0,0,0,0,0,50,8,1,128,0,0,0,0,0,0,0,0,66,0,32,132,16,0,0,0,1,128,16,0,8,1,128,16,16,66,0,0,0,0,0,8,8,33,132,16,0,8,1,128,3,0,62,8,33,132,15,0,0,0,0,128,16,62,0,224,0,0,0,0,0,0,0,0,15,0,248,224,3,0,0,0,0,224,3,0,62,0,224,131,131,15,0,0,0,0,0,62,62,248,224,3,0,62,0,224,0,128,7,62,248,224,1,0,0,0,0,224,131,7,0,120,0,0,0,0,0,0,0,0,1,0,30,120,0,0,0,0,0,120,0,128,7,0,120,224,224,1,0,0,0,0,128,7,7,30,120,0,128,7,0,120,0,160,129,7,30,104,0,128,0,0,0,120,160,1,0,26,0,0,0,0,0,0,0,0,0,128,6,26,0,0,0,0,0,26,0,160,1,0,26,104,104,0,0,0,0,0,160,1,129,6,26,0,160,1,0,26,0,232,164,129,6,58,1,160,0,0,0,26,232,4,128,78,0,0,0,0,0,0,0,0,1,160,147,78,0,0,0,0,128,78,0,232,4,128,78,58,58,1,0,0,0,0,232,4,164,147,78,0,232,4,128,78,0,0,232,164,19,0,0,232,228,82,139,78,0,0,32,17,76,48,189,100,83,77,52,205,221,196,211,78,58,229,36,83,224,20,0,0,56,21,14,0,45,78,1,0,64,115,0,0,244,146,77,53,209,52,147,75,79,59,233,148,147,76,49,193,0,0,160,84,56,0,116,19,5,128,21,221,1,0,128,82,54,213,68,211,76,46,181,40,164,83,78,50,197,4,211,75,64,142,225,0,208,77,60,237,238,136,4,0,0,86,0,0,19,77,51,185,212,226,14,0,57,201,20,19,76,47,217,84,132,227,66,55,241,180,147,78,0,0,0,228,7,0,0,46,0,0,0,0,0,0,0,0,224,130,11,0,184,224,2,0,0,46,0,224,2,0,46,0,0,224,130,11,0,0,0,0,128,28,46,184,224,2,0,46,209,52,147,75,45,46,184,0,0,0,0,0,0,144,77,53,56,0,200,33,135,28,0,0,0,0,128,28,0,0,32,71,11,0,0,200,97,135,28,114,0,100,83,77,52,205,228,82,97,7,0,0,0,0,0,0,0,114,216,17,14,0,118,216,232,97,135,29,0,0,96,7,77,51,185,212,2,0,0,118,0,0,0,0,0,217,84,19,3,128,30,122,232,1,0,0,0,0,232,1,128,29,122,132,0,0,128,30,126,232,161,7,64,54,213,68,211,76,46,181,126,0,0,0,0,0,0,0,160,135,31,225,0,224,135,31,32,126,248,1,0,0,126,0,52,147,75,45,0,0,224,135,0,0,0,0,144,77,53,209,0,8,34,136,32,0,0,0,0,128,32,0,248,33,72,56,0,0,8,98,136,32,130,0,100,83,77,52,205,228,82,11,8,0,0,0,0,0,0,0,130,24,18,14,0,134,24,98,96,136,33,0,0,96,8,0,52,205,228,82,11,0,134,0,36,83,76,48,189,100,83,77,142,10,221,196,211,78,58,229,0,0,144,167,33,0,168,16,0,0,0,0,0,0,0,0,10,42,0,160,130,10,0,0,168,0,128,10,0,168,0,128,128,10,42,0,0,0,0,0,217,170,160,130,10,0,168,0,211,76,46,181,168,160,2,0,50,197,4,211,75,54,213,68,104,212,77,60,237,164,83,78,77,51,185,212,2,0,0,225,201,20,19,76,47,217,84,19,163,80,55,241,180,147,78,57,52,205,228,82,11,0,0,132,36,83,76,48,189,100,83,77,142,71,221,196,211,78,58,229,209,52,147,75,45,0,0,16,147,76,49,193,244,146,77,53,56,14,117,19,79,59,233,148,68,211,76,46,181,0,0,64,78,50,197,4,211,75,54,213,225,88,212,77,60,237,164,83,19,77,51,185,212,2,0,0,57,201,20,19,76,47,217,84,132,99,80,55,241,180,147,78,77,52,205,228,82,11,0,0,229,36,83,76,48,189,100,83,25,14,0,221,196,211,78,58,0,0,0,0,64,155,113,150,118,9,0,0,0,0,0,0,0,0,0,0,0,64,152,89,0,0,0,0,0,0,0,0,0,64,23,0,0,0,0,0,145,0,0,0,0,0,0,0,0,0,0,0,13,100,144,100,0,0,0,0,0,0,0,0,10,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,90,45,0,0,0,0,0,0,182,0,0,0,0,0,0,0,169,0,96,171,176,206,0,32,0,216,42,4,50,0,0,0,0,0,128,4,0,0,0,0,0,0,1,0,0,0,0,0,72,0,240,255,23,16,0,0,104,252,255,109,22,20,16,2,0,0,0,0,0,128,196,255,223,100,1,0,32,0,0,0,0,0,0,152,10,0,182,10,235,12,2,2,128,173,66,32,3,0,0,0,0,0,72,0,241,255,183,89,0,0,8,2,0,0,0,106,0,164,2,128,173,194,58,131,169,0,96,171,176,206,32,1,0,0,0,0,0,64,252,255,77,22,0,0,2,0,0,8,0,0,0,128,0,96,171,16,200,0,0,0,0,0,168,1,64,252,255,77,22,0,0,18,255,127,147,5,0,128,128,0,96,171,16,200,64,42,0,216,42,172,51,8,0,0,0,0,128,4,16,0,0,0,0,0,0,196,255,223,100,1,0,32,0,0,0,0,0,18,64,252,255,77,22,0,0,130,0,0,0,128,26,0,16,0,0,0,0,0,0,32,0,216,42,4,50,64,252,255,77,22,0,0,18,255,127,147,5,0,128,128,0,96,171,16,200,0,40,0,216,42,172,51,64,252,255,77,22,0,0,18,255,127,147,5,0,128,2,0,0,0,64,1,128,0,0,128,0,80,0,32,0,0,32,0,20,0,0,0,0,0,1,4,4,2,0,0,0,0,0,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,240,255,55,65,0,0,0,0,0,8,16,0,0,0,0,0,8,0,0,0,196,255,223,100,1,0,32,32,0,216,43,4,50,0,2,0,0,0,106,0,0,0,0,0,0,0,128,169,0,96,171,176,206,96,42,0,216,42,172,51,8,8,0,182,10,129,12,0,255,127,129,0,0,0,4,0,0,0,0,0,64,42,0,216,42,172,51,72,0,0,0,0,0,0,0,255,127,129,0,0,0,2,0,0,0,64,1,64,42,0,216,42,172,51,8,0,0,32,0,0,0,16,0,0,0,0,0,0,196,255,223,100,1,0,32,240,255,23,1,0,0,0,10,0,182,10,235,12,0,0,0,0,1,0,0,0,0,0,64,0,0,0,40,0,216,42,172,51,0,0,0,0,0,0,224,1,2,128,173,66,32,3,128,0,96,171,16,200,0,0,0,0,0,4,32,64,252,255,77,22,0,0,2,0,0,0,64,0,2,128,0,96,171,16,200,0,241,255,55,89,0,0,72,252,255,77,22,0,0,18,255,127,147,5,0,128,196,255,223,100,1,0,32,241,255,55,89,0,0,72,252,255,77,22,0,0,18,255,127,147,5,0,128,196,255,223,100,1,0,32,241,255,55,89,0,0,72,252,255,77,22,0,0,18,255,127,147,5,0,128,192,255,223,0,0,0,0,241,255,55,89,0,0,72,252,255,77,22,0,0,18,255,127,147,5,0,128,196,255,223,100,1,0,32,32,0,216,42,4,50,64,252,255,77,22,0,0,2,7,112,0,64,192,3,196,255,223,100,1,0,32,0,0,0,65,0,0,0,252,255,69,0,0,0,128,2,128,173,194,58,3,0,0,0,16,0,0,64,42,0,216,42,172,51,152,10,0,182,10,235,12,2,2,0,0,80,0,0,128,0,96,171,16,200,0,0,0,0,0,0,128,7,8,0,182,10,129,12,0,0,0,16,0,32,1,0,0,0,0,0,0,94,42,0,216,42,172,51,72,252,255,77,22,0,0,2,0,0,0,1,0,0,160,0,96,171,176,206,0,241,255,55,89,0,0,72,0,1,64,18,16,0,0,64,0,0,0,4,0,4,16,0,36,1,1,0,1,4,0,73,64,0,64,0,1,64,18,16,0,16,255,127,1,0,0,0,196,255,95,0,0,0,0,0,0,0,16,0,0,0,0,0,0,4,0,0,0,0,0,0,1,0,0,0,0,0,64,0,0,0,241,255,23,0,0,0,64,252,255,77,22,0,0,2,255,127,19,4,0,0,0,0,0,0,1,0,0,0,0,0,65,0,0,0,0,0,64,16,0,0,0,0,0,16,4,0,0,0,0,0,4,1,0,0,0,0,0,65,0,0,0,0,0,64,16,0,0,0,0,0,16,4,0,0,0,0,0,4,1,0,0,0,0,0,65,0,0,0,0,0,64,16,0,0,0,0,0,16,4,0,0,0,0,0,4,1,0,0,241,255,55,89,0,0,72,252,255,77,22,0,0,2,0,0,0,1,0,0,0,0,0,40,0,0,0,0,0,0,80,0,0,0,0,0,0,20,0,0,0,0,0,0,64,0,0,0,0,0,0,10,0,0,0,0,0,128,0,0,0,0,0,0,160,0,0,0,0,0,0,8,0,0,196,255,95,0,0,0,0,0,4,0,0,64,0,64,252,255,5,0,0,0,0,0,0,0,0,0,120,0,0,0,4,1,0,0,0,0,0,0,16,0,0,0,0,64,16,0,0,0,0,0,128,0,0,0,128,0,96,171,16,200,0,32,0,216,42,4,50,64,252,255,77,22,0,0,18,255,127,147,5,0,128,196,255,223,100,1,0,32,241,255,55,89,0,0,152,10,0,182,10,235,12,2,7,112,0,64,192,3,196,255,223,116,1,0,32,241,255,55,89,0,0,72,252,255,77,22,0,0,2,2,0,0,81,0,0,196,255,223,100,1,0,32,0,0,0,128,2,0,0,8,0,0,0,1,0,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,0,0,0,0,0,224,1,0,0,0,0,32,1,0,0,0,0,0,0,30,40,0,216,42,172,51,64,252,255,5,0,0,0,16,255,127,1,0,0,0,0,0,0,0,1,0,0,241,255,55,89,0,0,8,0,0,0,20,0,0,0,0,0,0,8,0,0,192,255,95,64,1,0,0,241,255,23,0,0,0,64,252,255,5,0,0,0,0,0,0,16,4,0,0,0,0,0,4,1,0,0,0,0,0,16,0,0,0,28,192,1,0,1,15,164,2,128,173,194,58,131,0,0,0,4,0,0,0,0,0,0,0,0,128,71,0,0,0,0,0,0,0,0,0,0,0,215,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,191,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,159,0,0,0,0,0,0,0,24,0,0,0,0,0,0,0,3,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,123,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,216,0,0,0,0,0,0,0,24,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,223,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,45,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,47,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,248,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,255,0,0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,24,1,0,0,0,0,0,0,31,1,0,0,0,0,0,0,56,1,0,0,0,0,0,0,63,1,0,0,0,0,0,0,88,1,0,0,0,0,0,0,95,1,0,0,0,0,0,0,120,1,0,0,0,0,0,0,127,1,0,0,0,0,0,0,152,1,0,0,0,0,0,0,159,1,0,0,0,0,0,0,184,1,0,0,0,0,0,0,191,1,0,0,0,0,0,0,216,1,0,0,0,0,0,0,223,1,0,0,0,0,0,0,248,1,0,0,0,0,0,0,255,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,184,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,24,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,9,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,0,0,0,0,31,2,0,0,0,0,0,0,56,2,0,0,0,0,0,0,25,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,0,0,0,0,0,0,0,63,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,88,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,210,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,62,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,95,2,0,0,0,0,0,0,29,0,0,0,0,0,0,0,120,2,0,0,0,0,0,0,179,0,0,0,0,0,0,0,39,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,127,2,0,0,0,0,0,0,152,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,242,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,159,2,0,0,0,0,0,0,184,2,0,0,0,0,0,0,191,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,108,0,0,0,0,0,0,0,48,0,0,0,0,0,0,0,93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,36,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,60,30,0,0,0,0,0,0,121,0,0,0,0,0,0,0,49,144,119,150,250,63,249,239,169,255,255,255,255,252,255,255,255,239,255,255,255,255,252,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,63,249,175,169,255,255,255,255,252,255,255,223,255,255,255,255,255,255,255,255,255,255,55,144,119,150,250,255,255,255,255,255,255,255,255,255,255,255,255,255,255,147,255,158,250,255,127,119,255,255,255,255,255,255,255,255,255,255,255,147,255,158,250,255,255,255,255,255,255,255,255,252,255,255,255,255,255,255,255,255,255,255,255,255,207,255,255,255,255,255,255,255,255,255,255,255,255,255,255,63,249,239,169,255,255,255,255,255,255,255,255,255,255,147,255,158,250,0,121,103,169,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,63,249,239,169,255,147,255,158,250,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,63,249,175,169,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,147,255,158,250,255,254,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,191,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,63,144,119,150,250,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,247,63,249,239,169,255,147,255,158,250,255,255,255,191,255,255,255,255,255,255,255,255,191,255,147,255,158,250,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,63,249,239,169,255,255,255,255,255,255,255,255,164,255,255,255,255,255,255,255,255,255,255,255,255,255,255,0,121,103,169,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,135,255,255,255,147,255,158,250,255,255,255,127,255,147,255,158,250,255,255,252,207,255,255,255,255,247,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,231,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,147,255,158,250,63,249,239,169,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,164,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,143,255,255,255,255,255,255,255,255,255,255,255,255,255,251,255,255,63,249,239,169,255,147,255,158,250,63,249,239,169,255,255,255,255,255,255,255,254,207,255,255,255,255,247,0,121,103,169,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,79,250,255,255,255,255,255,255,255,255,255,255,255,255,127,255,255,255,255,15,227,2,0,0,0,0,0,0,93,0,0,0,0,0,0,0,22,16,0,0,26,17,10,0,163,19,9,117,31,6,8,15,11,165,168,203,28,165,168,162,125,23,29,65,18,186,187,36,15,22,86,83,85,39,44,14,74,132,19,9,75,31,6,84,36,11,45,42,73,28,41,42,14,140,23,29,138,18,64,42,84,15,22,86,83,160,39,170,42,77,42,19,9,176,31,6,0,36,11,215,209,204,28,79,0,14,0,23,29,0,18,0,6,84,15,22,86,83,210,39,0,0,0,0,19,9,0,31,0,0,36,11,0,0,0,28,39,69,14,0,23,29,0,18,0,31,6,68,15,22,16,208,0,28,174,42,0,0,19,9,0,18,0,0,36,11,0,0,22,129,39,0,14,0,23,29,0,19,9,0,31,6,128,15,11,0,19,9,28,31,62,0,36,23,29,0,18,0,0,36,14,0,23,29,0,39,63,14,175,19,9,0,31,62,39,0,0,0,19,9,0,31,40,173,36,23,29,174,42,114,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,72,39,0,150,145,19,9,0,31,76,0,36,23,29,149,0,155,148,36,14,0,23,29,0,39,0,14,0,19,9,0,31,81,39,0,150,201,19,9,0,31,82,0,36,23,29,149,0,155,148,36,14,0,23,29,0,39,0,14,0,19,9,0,31,94,39,0,150,213,19,9,0,31,95,0,36,23,29,149,0,155,148,36,14,0,23,29,0,39,0,14,0,19,9,0,31,96,39,0,0,0,19,9,0,31,97,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,98,39,0,0,0,19,9,0,31,99,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,100,39,0,0,0,19,9,0,31,101,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,102,39,0,0,0,19,9,0,31,103,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,104,39,0,0,0,19,9,0,31,105,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,106,39,0,0,0,19,9,0,31,107,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,108,39,0,0,0,19,9,0,31,109,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,115,39,0,0,0,19,9,0,31,136,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,137,39,0,0,0,19,9,0,31,142,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,144,39,0,0,0,19,9,0,31,169,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,171,39,0,0,0,19,9,0,31,199,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,200,39,0,0,0,19,9,0,31,205,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,19,9,0,31,206,39,0,0,0,19,9,0,31,207,0,36,23,29,0,0,0,0,36,14,0,23,29,0,39,0,14,0,0,0,0,0,0,39,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,94,0,0,0,0,0,0,121,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,2,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,90,45,0,0,0,0,0,0,182,0,0,0,0,0,0,0,169,0,96,171,176,206,0,32,0,216,42,4,50,0,0,0,0,0,128,4,0,0,0,0,0,0,1,0,0,0,0,0,72,0,240,255,23,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,128,173,66,32,3,0,0,0,0,0,72,0,0,0,128,0,0,0,0,2,0,0,0,0,0,0,0,0,0,0,0,0,169,0,96,171,176,206,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,128,0,96,171,16,200,0,0,0,0,0,168,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,96,171,16,200,0,0,0,0,0,0,0,0,0,0,0,0,128,4,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,18,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,0,0,0,32,0,216,42,4,50,0,0,0,0,0,0,0,0,0,0,0,0,0,0,128,0,96,171,16,200,0,40,0,216,42,172,51,0,0,0,0,0,0,0,0,32,0,1,0,0,0,2,0,0,0,64,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,4,4,2,0,0,0,0,0,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,8,0,182,10,129,12,0,2,128,173,66,32,3,128,0,96,171,16,200,0,240,255,23,0,0,0,0,0,0,8,16,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,32,0,216,43,4,50,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,182,10,129,12,0,255,127,129,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,255,127,129,0,0,0,2,0,0,0,64,1,0,0,0,0,0,0,0,0,0,0,32,0,0,0,16,0,0,0,0,0,0,0,8,64,0,0,0,0,240,255,23,1,0,0,0,10,0,182,10,235,12,0,0,0,0,0,0,0,0,0,0,64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,224,1,2,128,173,66,32,3,128,0,96,171,16,200,0,0,0,0,0,4,32,0,0,0,0,0,0,0,0,0,0,0,64,0,2,128,0,96,171,16,200,0,192,3,16,0,0,0,0,240,0,4,0,0,0,0,63,0,1,0,0,0,192,15,64,0,0,0,0,240,3,16,0,0,0,0,252,0,4,0,0,0,0,32,0,1,0,0,0,0,8,64,0,0,0,0,0,2,16,0,0,0,0,128,0,4,0,0,0,0,255,31,1,0,0,0,192,255,223,0,0,0,0,240,3,16,0,0,0,0,252,0,4,0,0,0,0,255,15,1,0,0,0,192,255,87,0,0,0,0,32,0,216,42,4,50,0,0,0,0,0,0,0,0,7,112,0,64,192,3,0,0,0,0,0,0,0,0,0,0,65,0,0,0,252,255,69,0,0,0,128,2,128,173,194,58,3,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,80,0,0,128,0,96,171,16,200,0,0,0,0,0,0,128,7,8,0,182,10,129,12,0,0,0,16,0,32,1,0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,64,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,255,127,1,0,0,0,192,255,95,0,0,0,0,0,0,0,16,0,0,0,0,0,0,4,0,0,0,0,0,0,1,0,0,0,0,0,64,0,0,0,241,255,23,0,0,0,0,0,0,0,0,0,0,0,255,127,1,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,0,0,0,0,20,0,0,0,0,0,0,64,0,0,0,0,0,0,8,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,192,255,95,0,0,0,0,0,4,0,0,64,0,0,252,255,5,0,0,0,0,0,0,0,0,0,0,0,0,0,4,1,0,0,0,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,128,0,0,0,128,0,96,171,16,200,0,32,0,216,42,4,50,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,7,112,0,64,192,3,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,0,0,81,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,8,0,0,0,1,0,0,2,128,173,66,32,3,128,0,96,171,16,200,0,32,0,216,42,4,50,0,0,0,0,0,0,0,0,0,0,0,0,32,1,0,0,0,0,0,0,30,40,0,216,42,172,51,0,252,255,5,0,0,0,0,255,127,1,0,0,0,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,192,255,95,0,0,0,0,240,255,23,0,0,0,0,252,255,5,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,16,0,0,0,28,192,1,0,1,15,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,128,7,0,0,0,0,0,0,0,0,0,0,0,215,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,192,133,196,210,76,6,0,0,61,34,40,4,0,64,97,224,119,64,254,127,98,72,124,55,4,142,73,0,0,0,0,0,112,0,0,0,54,0,0,0,0,9,0,];
 
     #[allow(dead_code)]
     pub fn parse<'lexer, 'input: 'lexer>(lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>)
@@ -83,9 +83,52 @@ pub mod parser_y {
         actions.push(&__gt_wrapper_66);
         actions.push(&__gt_wrapper_67);
         actions.push(&__gt_wrapper_68);
+        actions.push(&__gt_wrapper_69);
+        actions.push(&__gt_wrapper_70);
+        actions.push(&__gt_wrapper_71);
+        actions.push(&__gt_wrapper_72);
+        actions.push(&__gt_wrapper_73);
+        actions.push(&__gt_wrapper_74);
+        actions.push(&__gt_wrapper_75);
+        actions.push(&__gt_wrapper_76);
+        actions.push(&__gt_wrapper_77);
+        actions.push(&__gt_wrapper_78);
+        actions.push(&__gt_wrapper_79);
+        actions.push(&__gt_wrapper_80);
+        actions.push(&__gt_wrapper_81);
+        actions.push(&__gt_wrapper_82);
+        actions.push(&__gt_wrapper_83);
+        actions.push(&__gt_wrapper_84);
+        actions.push(&__gt_wrapper_85);
+        actions.push(&__gt_wrapper_86);
+        actions.push(&__gt_wrapper_87);
+        actions.push(&__gt_wrapper_88);
+        actions.push(&__gt_wrapper_89);
+        actions.push(&__gt_wrapper_90);
+        actions.push(&__gt_wrapper_91);
+        actions.push(&__gt_wrapper_92);
+        actions.push(&__gt_wrapper_93);
+        actions.push(&__gt_wrapper_94);
+        actions.push(&__gt_wrapper_95);
+        actions.push(&__gt_wrapper_96);
+        actions.push(&__gt_wrapper_97);
+        actions.push(&__gt_wrapper_98);
+        actions.push(&__gt_wrapper_99);
+        actions.push(&__gt_wrapper_100);
+        actions.push(&__gt_wrapper_101);
+        actions.push(&__gt_wrapper_102);
+        actions.push(&__gt_wrapper_103);
+        actions.push(&__gt_wrapper_104);
+        actions.push(&__gt_wrapper_105);
+        actions.push(&__gt_wrapper_106);
+        actions.push(&__gt_wrapper_107);
+        actions.push(&__gt_wrapper_108);
+        actions.push(&__gt_wrapper_109);
+        actions.push(&__gt_wrapper_110);
+        actions.push(&__gt_wrapper_111);
 
         match ::lrpar::RTParserBuilder::new(&grm, &stable)
-            .recoverer(::lrpar::RecoveryKind::None)
+            .recoverer(::lrpar::RecoveryKind::CPCTPlus)
             .parse_actions(lexer, &actions) {
                 (Some(__GTActionsKind::AK1(x)), y) => (Some(x), y),
                 (None, y) => (None, y),
@@ -96,48 +139,74 @@ pub mod parser_y {
     #[allow(dead_code)]
     pub const R_PROGRAMPARSER: u32 = 1;
     #[allow(dead_code)]
-    pub const R_STTMNTSCOLL: u32 = 2;
+    pub const R_TOPSTTMNTS: u32 = 2;
     #[allow(dead_code)]
-    pub const R_STTMNTS: u32 = 3;
+    pub const R_TOPSTTMNT: u32 = 3;
     #[allow(dead_code)]
-    pub const R_STTMNT: u32 = 4;
+    pub const R_STTMNTSCOLL: u32 = 4;
     #[allow(dead_code)]
-    pub const R_TERM: u32 = 5;
+    pub const R_STTMNTS: u32 = 5;
     #[allow(dead_code)]
-    pub const R_EXPR: u32 = 6;
+    pub const R_STTMNT: u32 = 6;
     #[allow(dead_code)]
-    pub const R_COND: u32 = 7;
+    pub const R_DOCS: u32 = 7;
     #[allow(dead_code)]
-    pub const R_ASSIGNMENT: u32 = 8;
+    pub const R_DOCLINES: u32 = 8;
     #[allow(dead_code)]
-    pub const R_DECFUNCARG: u32 = 9;
+    pub const R_TERM: u32 = 9;
     #[allow(dead_code)]
-    pub const R_FUNCARGDECLIST: u32 = 10;
+    pub const R_EXPR: u32 = 10;
     #[allow(dead_code)]
-    pub const R_DECFUNCARGS: u32 = 11;
+    pub const R_COND: u32 = 11;
     #[allow(dead_code)]
-    pub const R_DECFUNC: u32 = 12;
+    pub const R_ARM: u32 = 12;
     #[allow(dead_code)]
-    pub const R_FUNC: u32 = 13;
+    pub const R_BLOCK: u32 = 13;
     #[allow(dead_code)]
-    pub const R_FUNCARGS: u32 = 14;
+    pub const R_MATCH: u32 = 14;
     #[allow(dead_code)]
-    pub const R_ARGLIST: u32 = 15;
+    pub const R_MATCHARMS: u32 = 15;
     #[allow(dead_code)]
-    pub const R_DECCMP: u32 = 16;
+    pub const R_MATCHARM: u32 = 16;
     #[allow(dead_code)]
-    pub const R_COMPARG: u32 = 17;
+    pub const R_PATTERN: u32 = 17;
     #[allow(dead_code)]
-    pub const R_NUM: u32 = 18;
+    pub const R_PATNUM: u32 = 18;
     #[allow(dead_code)]
-    pub const R_BOOL: u32 = 19;
+    pub const R_ASSIGNMENT: u32 = 19;
     #[allow(dead_code)]
-    pub const R_IDENTIFIER: u32 = 20;
+    pub const R_DECFUNCARG: u32 = 20;
     #[allow(dead_code)]
-    pub const R_TEMPLATEVAR: u32 = 21;
+    pub const R_FUNCARGDECLIST: u32 = 21;
     #[allow(dead_code)]
-    pub const R_TYPEINFO: u32 = 22;
-    const __GT_EPP: &[::std::option::Option<&str>] = &[Some("SEMI"), Some("ADD"), Some("SUB"), Some("MUL"), Some("DIV"), Some("EQ"), Some("NE"), Some("GT"), Some("LT"), Some("GE"), Some("LE"), Some("AND"), Some("OR"), Some("XOR"), Some("LPAR"), Some("RPAR"), Some("IF"), Some("RBRACE"), Some("LBRACE"), Some("ELSE"), Some("CONST"), Some("COLON"), Some("ASSIGN"), Some("LET"), Some("COMMA"), Some("FN"), Some("COMP"), Some("NUM"), Some("TRUE"), Some("FALSE"), Some("IDENT"), Some("TEMPLATE_START"), Some("TEMPLATE_ASSIGN"), Some("TEMPLATE_FALLBACK"), Some("INT"), Some("BOOL"), Some("VEC_INT"), Some("VEC_BOOL"), None];
+    pub const R_DECFUNCARGS: u32 = 22;
+    #[allow(dead_code)]
+    pub const R_DECFUNC: u32 = 23;
+    #[allow(dead_code)]
+    pub const R_FUNC: u32 = 24;
+    #[allow(dead_code)]
+    pub const R_FUNCARGS: u32 = 25;
+    #[allow(dead_code)]
+    pub const R_ARGLIST: u32 = 26;
+    #[allow(dead_code)]
+    pub const R_DECCMP: u32 = 27;
+    #[allow(dead_code)]
+    pub const R_COMPARG: u32 = 28;
+    #[allow(dead_code)]
+    pub const R_NUM: u32 = 29;
+    #[allow(dead_code)]
+    pub const R_DICE: u32 = 30;
+    #[allow(dead_code)]
+    pub const R_BOOL: u32 = 31;
+    #[allow(dead_code)]
+    pub const R_IDENTIFIER: u32 = 32;
+    #[allow(dead_code)]
+    pub const R_NAME: u32 = 33;
+    #[allow(dead_code)]
+    pub const R_TEMPLATEVAR: u32 = 34;
+    #[allow(dead_code)]
+    pub const R_TYPEINFO: u32 = 35;
+    const __GT_EPP: &[::std::option::Option<&str>] = &[Some("analyze"), Some("="), Some(";"), Some("import"), Some("string"), Some("doc comment"), Some("+"), Some("-"), Some("*"), Some("/"), Some("%"), Some("**"), Some("=="), Some("!="), Some(">"), Some("<"), Some(">="), Some("<="), Some("&"), Some("|"), Some("^"), Some("!"), Some("["), Some("]"), Some("len"), Some("("), Some(")"), Some("if"), Some("else"), Some("{"), Some("}"), Some("match"), Some(","), Some("=>"), Some("_"), Some("..="), Some("number"), Some("const"), Some(":"), Some("let"), Some(":="), Some("fn"), Some("cm"), Some("dice"), Some("true"), Some("false"), Some("identifier"), Some("${"), Some(":-"), Some("int"), Some("bool"), Some("vec<int>"), Some("vec<bool>"), None];
 
     /// Return the %epp entry for token `tidx` (where `None` indicates "the token has no
     /// pretty-printed value"). Panics if `tidx` doesn't exist.
@@ -157,7 +226,11 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK2(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK1(__gt_action_0(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK9(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK1(__gt_action_0(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
     fn __gt_wrapper_1<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
@@ -166,14 +239,10 @@ pub mod parser_y {
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK3(x)) => x,
-            _ => unreachable!()
-        };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK5(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK2(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK2(__gt_action_1(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        __GTActionsKind::AK1(__gt_action_1(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
     fn __gt_wrapper_2<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
@@ -182,10 +251,10 @@ pub mod parser_y {
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK5(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK9(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK2(__gt_action_2(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK1(__gt_action_2(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
     fn __gt_wrapper_3<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
@@ -194,14 +263,14 @@ pub mod parser_y {
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK3(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK2(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK4(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK3(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK3(__gt_action_3(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        __GTActionsKind::AK2(__gt_action_3(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
     fn __gt_wrapper_4<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
@@ -210,10 +279,10 @@ pub mod parser_y {
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK4(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK3(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK3(__gt_action_4(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK2(__gt_action_4(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
     fn __gt_wrapper_5<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
@@ -222,10 +291,46 @@ pub mod parser_y {
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK8(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
             _ => unreachable!()
         };
+        __GTActionsKind::AK3(__gt_action_5(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_6<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -235,17 +340,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK4(__gt_action_5(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        __GTActionsKind::AK3(__gt_action_6(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
     }
 
-    fn __gt_wrapper_6<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_7<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK16(x)) => x,
-            _ => unreachable!()
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
@@ -257,60 +368,64 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK4(__gt_action_6(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK3(__gt_action_7(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_7<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_8<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK12(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK5(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK9(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK4(__gt_action_7(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK4(__gt_action_8(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_8<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_9<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK9(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK5(__gt_action_8(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK4(__gt_action_9(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_9<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_10<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK5(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::Lexeme(l) => {
-                if l.inserted() {
-                    Err(l)
-                } else {
-                    Ok(l)
-                }
-            },
-            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
-        };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_9(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK5(__gt_action_10(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_10<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_11<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -319,6 +434,18 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
             _ => unreachable!()
         };
+        __GTActionsKind::AK5(__gt_action_11(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_12<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK19(x)) => x,
+            _ => unreachable!()
+        };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
@@ -329,20 +456,16 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK6(__gt_action_10(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK6(__gt_action_12(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_11<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_13<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK27(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -355,23 +478,35 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        __GTActionsKind::AK6(__gt_action_13(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+    }
+
+    fn __gt_wrapper_14<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK23(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_11(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK6(__gt_action_14(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_12<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_15<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK7(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK19(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -381,23 +516,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK6(__gt_action_12(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK6(__gt_action_15(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_13<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_16<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK7(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK27(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -407,20 +542,44 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        __GTActionsKind::AK6(__gt_action_16(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_17<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK7(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK23(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_13(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK6(__gt_action_17(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_14<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_18<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK8(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK7(__gt_action_18(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_19<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK8(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -433,23 +592,15 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK6(__gt_action_14(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK8(__gt_action_19(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_15<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_20<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -459,20 +610,28 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        __GTActionsKind::AK8(__gt_action_20(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_21<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_15(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK9(__gt_action_21(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_16<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_22<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -486,19 +645,19 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_16(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_22(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_17<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_23<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -512,19 +671,19 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_17(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_23(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_18<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_24<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -538,19 +697,19 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_18(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_24(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_19<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_25<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -564,19 +723,19 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_19(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_25(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_20<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_26<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -590,19 +749,19 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_20(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_26(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_21<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_27<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
@@ -616,18 +775,22 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_21(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_27(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_22<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_28<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -637,11 +800,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK10(__gt_action_28(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_29<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -651,75 +826,49 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_22(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
-    }
-
-    fn __gt_wrapper_23<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK7(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_23(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK10(__gt_action_29(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_24<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_30<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK13(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_24(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_25<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK18(x)) => x,
-            _ => unreachable!()
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_25(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_26<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_26(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK10(__gt_action_30(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_27<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_31<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK21(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK6(__gt_action_27(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_28<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -729,11 +878,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK10(__gt_action_31(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_32<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -743,21 +904,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_5 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::Lexeme(l) => {
-                if l.inserted() {
-                    Err(l)
-                } else {
-                    Ok(l)
-                }
-            },
-            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        __GTActionsKind::AK10(__gt_action_32(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_33<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
         };
-        let __gt_arg_6 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -767,7 +930,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_7 = match __gt_args.next().unwrap() {
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK10(__gt_action_33(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_34<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -777,11 +956,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_8 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_9 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK10(__gt_action_34(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_35<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -791,15 +982,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK7(__gt_action_28(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6, __gt_arg_7, __gt_arg_8, __gt_arg_9))
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK10(__gt_action_35(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_29<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_36<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -809,11 +1008,19 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK10(__gt_action_36(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_37<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -823,11 +1030,19 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK22(x)) => x,
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_5 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK10(__gt_action_37(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+    }
+
+    fn __gt_wrapper_38<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -837,19 +1052,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_6 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK8(__gt_action_29(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
+        __GTActionsKind::AK10(__gt_action_38(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_30<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_39<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -859,11 +1078,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -873,14 +1092,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK8(__gt_action_30(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
+        __GTActionsKind::AK10(__gt_action_39(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
     }
 
-    fn __gt_wrapper_31<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_40<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -896,10 +1111,6 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
-            _ => unreachable!()
-        };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -909,11 +1120,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK22(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_5 = match __gt_args.next().unwrap() {
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -923,14 +1134,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_6 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK8(__gt_action_31(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
+        __GTActionsKind::AK10(__gt_action_40(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
     }
 
-    fn __gt_wrapper_32<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_41<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -946,7 +1153,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK26(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -959,23 +1166,15 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK8(__gt_action_32(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
+        __GTActionsKind::AK10(__gt_action_41(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_33<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_42<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
-            _ => unreachable!()
-        };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -985,23 +1184,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK22(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK9(__gt_action_33(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
-    }
-
-    fn __gt_wrapper_34<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1011,54 +1198,106 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK9(x)) => x,
+        __GTActionsKind::AK10(__gt_action_42(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_43<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK11(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK10(__gt_action_34(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK10(__gt_action_43(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_35<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_44<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK9(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK14(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK10(__gt_action_35(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK10(__gt_action_44(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_36<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_45<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::Lexeme(l) => {
-                if l.inserted() {
-                    Err(l)
-                } else {
-                    Ok(l)
-                }
-            },
-            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK13(x)) => x,
+            _ => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::Lexeme(l) => {
-                if l.inserted() {
-                    Err(l)
-                } else {
-                    Ok(l)
-                }
-            },
-            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        __GTActionsKind::AK10(__gt_action_45(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_46<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK24(x)) => x,
+            _ => unreachable!()
         };
-        __GTActionsKind::AK11(__gt_action_36(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        __GTActionsKind::AK10(__gt_action_46(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_37<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_47<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK29(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK10(__gt_action_47(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_48<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK30(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK10(__gt_action_48(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_49<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK10(__gt_action_49(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_50<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK34(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK10(__gt_action_50(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_51<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1078,6 +1317,10 @@ pub mod parser_y {
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK12(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1087,10 +1330,14 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK11(__gt_action_37(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK12(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK11(__gt_action_51(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
     }
 
-    fn __gt_wrapper_38<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_52<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1106,18 +1353,14 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK11(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK12(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK22(x)) => x,
-            _ => unreachable!()
-        };
-        let __gt_arg_5 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1127,11 +1370,19 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_6 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK2(x)) => x,
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK11(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_7 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK11(__gt_action_52(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+    }
+
+    fn __gt_wrapper_53<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1141,26 +1392,24 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK12(__gt_action_38(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6, __gt_arg_7))
-    }
-
-    fn __gt_wrapper_39<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
-            _ => unreachable!()
-        };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK14(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK4(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK13(__gt_action_39(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK12(__gt_action_53(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_40<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_54<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1176,6 +1425,10 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK4(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1185,10 +1438,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK14(__gt_action_40(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+        __GTActionsKind::AK13(__gt_action_54(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_41<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_55<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1204,7 +1457,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK15(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -1217,19 +1470,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK14(__gt_action_41(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
-    }
-
-    fn __gt_wrapper_42<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK15(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1239,26 +1484,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK15(__gt_action_42(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
-    }
-
-    fn __gt_wrapper_43<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK6(x)) => x,
-            _ => unreachable!()
-        };
-        __GTActionsKind::AK15(__gt_action_43(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK14(__gt_action_55(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
     }
 
-    fn __gt_wrapper_44<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_56<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1274,7 +1503,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -1288,7 +1517,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK17(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK15(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_5 = match __gt_args.next().unwrap() {
@@ -1302,10 +1531,6 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_6 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK17(x)) => x,
-            _ => unreachable!()
-        };
-        let __gt_arg_7 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1315,11 +1540,19 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_8 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK17(x)) => x,
+        __GTActionsKind::AK14(__gt_action_56(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
+    }
+
+    fn __gt_wrapper_57<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK15(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_9 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1329,67 +1562,91 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_10 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK22(x)) => x,
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK16(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK16(__gt_action_44(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6, __gt_arg_7, __gt_arg_8, __gt_arg_9, __gt_arg_10))
+        __GTActionsKind::AK15(__gt_action_57(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_45<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_58<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK19(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK16(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_45(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK15(__gt_action_58(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_46<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_59<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK18(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK17(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_46(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK16(__gt_action_59(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_47<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_60<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK21(x)) => x,
-            _ => unreachable!()
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_47(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK17(__gt_action_60(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_48<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_61<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK18(x)) => x,
             _ => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_48(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK17(__gt_action_61(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_49<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_62<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK18(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1399,10 +1656,14 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_49(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK18(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK17(__gt_action_62(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_50<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_63<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1417,10 +1678,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_50(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK18(__gt_action_63(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
     }
 
-    fn __gt_wrapper_51<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_64<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1435,15 +1696,7 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_51(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_52<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1453,10 +1706,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_52(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK18(__gt_action_64(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_53<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_65<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1471,15 +1724,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_53(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_54<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1489,15 +1738,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK17(__gt_action_54(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_55<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1507,10 +1752,14 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK18(__gt_action_55(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        let __gt_arg_6 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_65(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
     }
 
-    fn __gt_wrapper_56<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_66<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1525,10 +1774,28 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK19(__gt_action_56(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_66(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
     }
 
-    fn __gt_wrapper_57<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_67<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1543,10 +1810,42 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK19(__gt_action_57(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_6 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_67(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
     }
 
-    fn __gt_wrapper_58<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_68<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1561,10 +1860,28 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK20(__gt_action_58(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_68(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
     }
 
-    fn __gt_wrapper_59<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_69<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1580,7 +1897,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -1594,7 +1911,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK21(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_5 = match __gt_args.next().unwrap() {
@@ -1607,10 +1924,14 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK21(__gt_action_59(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+        let __gt_arg_6 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_69(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
     }
 
-    fn __gt_wrapper_60<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_70<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1626,7 +1947,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -1640,6 +1961,32 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_70(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
+    }
+
+    fn __gt_wrapper_71<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1649,6 +1996,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
+            _ => unreachable!()
+        };
         let __gt_arg_5 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
@@ -1659,10 +2010,14 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK21(__gt_action_60(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+        let __gt_arg_6 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK19(__gt_action_71(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6))
     }
 
-    fn __gt_wrapper_61<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_72<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1678,7 +2033,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -1692,10 +2047,22 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_4 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK21(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_5 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK19(__gt_action_72(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4))
+    }
+
+    fn __gt_wrapper_73<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1705,15 +2072,23 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK21(__gt_action_61(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK20(__gt_action_73(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_62<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_74<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {
         let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK21(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1723,21 +2098,31 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_2 = match __gt_args.next().unwrap() {
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
             _ => unreachable!()
         };
-        let __gt_arg_3 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::Lexeme(l) => {
-                if l.inserted() {
-                    Err(l)
-                } else {
-                    Ok(l)
-                }
-            },
-            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        __GTActionsKind::AK21(__gt_action_74(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_75<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            _ => unreachable!()
         };
-        let __gt_arg_4 = match __gt_args.next().unwrap() {
+        __GTActionsKind::AK21(__gt_action_75(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_76<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1747,7 +2132,7 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        let __gt_arg_5 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1757,10 +2142,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK21(__gt_action_62(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+        __GTActionsKind::AK22(__gt_action_76(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
     }
 
-    fn __gt_wrapper_63<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_77<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1776,7 +2161,7 @@ pub mod parser_y {
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
         let __gt_arg_2 = match __gt_args.next().unwrap() {
-            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK20(x)) => x,
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK21(x)) => x,
             _ => unreachable!()
         };
         let __gt_arg_3 = match __gt_args.next().unwrap() {
@@ -1789,10 +2174,10 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK21(__gt_action_63(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+        __GTActionsKind::AK22(__gt_action_77(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
     }
 
-    fn __gt_wrapper_64<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_78<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1807,15 +2192,19 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK22(__gt_action_64(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_65<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK22(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1825,15 +2214,11 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK22(__gt_action_65(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_66<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
-                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
-                   -> __GTActionsKind<'input> {
-        let __gt_arg_1 = match __gt_args.next().unwrap() {
+        let __gt_arg_6 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK4(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_7 = match __gt_args.next().unwrap() {
             ::lrpar::parser::AStackType::Lexeme(l) => {
                 if l.inserted() {
                     Err(l)
@@ -1843,10 +2228,26 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK22(__gt_action_66(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+        __GTActionsKind::AK23(__gt_action_78(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6, __gt_arg_7))
     }
 
-    fn __gt_wrapper_67<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_wrapper_79<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK25(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK24(__gt_action_79(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+    }
+
+    fn __gt_wrapper_80<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                       __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                       __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
@@ -1861,12 +2262,746 @@ pub mod parser_y {
             },
             ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
         };
-        __GTActionsKind::AK22(__gt_action_67(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
-    }
-
-    fn __gt_wrapper_68<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
-                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
-                      __gt_span: ::lrpar::Span,
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK25(__gt_action_80(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2))
+    }
+
+    fn __gt_wrapper_81<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK26(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK25(__gt_action_81(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_82<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK26(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK26(__gt_action_82(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_83<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK10(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK26(__gt_action_83(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_84<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK33(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK28(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_6 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK28(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_7 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_8 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK28(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_9 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_10 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK35(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK27(__gt_action_84(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5, __gt_arg_6, __gt_arg_7, __gt_arg_8, __gt_arg_9, __gt_arg_10))
+    }
+
+    fn __gt_wrapper_85<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK31(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_85(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_86<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK29(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_86(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_87<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK34(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_87(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_88<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_88(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_89<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_89(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_90<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_90(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_91<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_91(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_92<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_92(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_93<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_93(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_94<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK28(__gt_action_94(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_95<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK29(__gt_action_95(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_96<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK30(__gt_action_96(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_97<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK31(__gt_action_97(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_98<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK31(__gt_action_98(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_99<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK32(__gt_action_99(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_100<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        __GTActionsKind::AK33(__gt_action_100(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_101<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK33(__gt_action_101(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_102<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK34(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK34(__gt_action_102(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+    }
+
+    fn __gt_wrapper_103<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK34(__gt_action_103(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+    }
+
+    fn __gt_wrapper_104<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK34(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK34(__gt_action_104(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+    }
+
+    fn __gt_wrapper_105<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_4 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_5 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK34(__gt_action_105(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3, __gt_arg_4, __gt_arg_5))
+    }
+
+    fn __gt_wrapper_106<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        let __gt_arg_2 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::ActionType(__GTActionsKind::AK32(x)) => x,
+            _ => unreachable!()
+        };
+        let __gt_arg_3 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK34(__gt_action_106(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1, __gt_arg_2, __gt_arg_3))
+    }
+
+    fn __gt_wrapper_107<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK35(__gt_action_107(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_108<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK35(__gt_action_108(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_109<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK35(__gt_action_109(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_110<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
+                      mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
+                   -> __GTActionsKind<'input> {
+        let __gt_arg_1 = match __gt_args.next().unwrap() {
+            ::lrpar::parser::AStackType::Lexeme(l) => {
+                if l.inserted() {
+                    Err(l)
+                } else {
+                    Ok(l)
+                }
+            },
+            ::lrpar::parser::AStackType::ActionType(_) => unreachable!()
+        };
+        __GTActionsKind::AK35(__gt_action_110(__gt_ridx, __gt_lexer, __gt_span, __gt_arg_1))
+    }
+
+    fn __gt_wrapper_111<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                      __gt_span: ::lrpar::Span,
                       mut __gt_args: ::std::vec::Drain<::lrpar::parser::AStackType<__GTActionsKind<'input>, u32>>)
                    -> __GTActionsKind<'input> {    unreachable!()
     }
@@ -1875,26 +3010,39 @@ pub mod parser_y {
     enum __GTActionsKind<'input> {
         AK1(Result<Body,lrpar::Lexeme<u32>>),
         AK2(Result<Vec<Statement>,lrpar::Lexeme<u32>>),
-        AK3(Result<Vec<Statement>,lrpar::Lexeme<u32>>),
-        AK4(Result<Statement,lrpar::Lexeme<u32>>),
-        AK5(Result<Statement,lrpar::Lexeme<u32>>),
-        AK6(Result<Expression,lrpar::Lexeme<u32>>),
-        AK7(Result<Conditional,lrpar::Lexeme<u32>>),
-        AK8(Result<Assign,lrpar::Lexeme<u32>>),
-        AK9(Result<FunctionArg,lrpar::Lexeme<u32>>),
-        AK10(Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>),
-        AK11(Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>),
-        AK12(Result<FunctionDec,lrpar::Lexeme<u32>>),
-        AK13(Result<Invoke,lrpar::Lexeme<u32>>),
-        AK14(Result<Vec<Expression>,lrpar::Lexeme<u32>>),
-        AK15(Result<Vec<Expression>,lrpar::Lexeme<u32>>),
-        AK16(Result<CompositionalFunction,lrpar::Lexeme<u32>>),
-        AK17(Result<CompositionalFunctionArg,lrpar::Lexeme<u32>>),
+        AK3(Result<Statement,lrpar::Lexeme<u32>>),
+        AK4(Result<Vec<Statement>,lrpar::Lexeme<u32>>),
+        AK5(Result<Vec<Statement>,lrpar::Lexeme<u32>>),
+        AK6(Result<Statement,lrpar::Lexeme<u32>>),
+        AK7(Result<Doc,lrpar::Lexeme<u32>>),
+        AK8(Result<Vec<Span>,lrpar::Lexeme<u32>>),
+        AK9(Result<Statement,lrpar::Lexeme<u32>>),
+        AK10(Result<Expression,lrpar::Lexeme<u32>>),
+        AK11(Result<Conditional,lrpar::Lexeme<u32>>),
+        AK12(Result<Expression,lrpar::Lexeme<u32>>),
+        AK13(Result<Block,lrpar::Lexeme<u32>>),
+        AK14(Result<Match,lrpar::Lexeme<u32>>),
+        AK15(Result<Vec<MatchArm>,lrpar::Lexeme<u32>>),
+        AK16(Result<MatchArm,lrpar::Lexeme<u32>>),
+        AK17(Result<Pattern,lrpar::Lexeme<u32>>),
         AK18(Result<Span,lrpar::Lexeme<u32>>),
-        AK19(Result<Span,lrpar::Lexeme<u32>>),
-        AK20(Result<Ident,lrpar::Lexeme<u32>>),
-        AK21(Result<Template,lrpar::Lexeme<u32>>),
-        AK22(Result<Kind,lrpar::Lexeme<u32>>),
+        AK19(Result<Assign,lrpar::Lexeme<u32>>),
+        AK20(Result<FunctionArg,lrpar::Lexeme<u32>>),
+        AK21(Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>),
+        AK22(Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>),
+        AK23(Result<FunctionDec,lrpar::Lexeme<u32>>),
+        AK24(Result<Invoke,lrpar::Lexeme<u32>>),
+        AK25(Result<Vec<Expression>,lrpar::Lexeme<u32>>),
+        AK26(Result<Vec<Expression>,lrpar::Lexeme<u32>>),
+        AK27(Result<CompositionalFunction,lrpar::Lexeme<u32>>),
+        AK28(Result<CompositionalFunctionArg,lrpar::Lexeme<u32>>),
+        AK29(Result<Span,lrpar::Lexeme<u32>>),
+        AK30(Result<Dice,lrpar::Lexeme<u32>>),
+        AK31(Result<Span,lrpar::Lexeme<u32>>),
+        AK32(Result<Ident,lrpar::Lexeme<u32>>),
+        AK33(Result<Ident,lrpar::Lexeme<u32>>),
+        AK34(Result<Template,lrpar::Lexeme<u32>>),
+        AK35(Result<Kind,lrpar::Lexeme<u32>>),
     ___GTActionsKindHidden(::std::marker::PhantomData<&'input ()>)
     }
 
@@ -1903,6 +3051,7 @@ pub mod parser_y {
 
 use crate::internals::parser::span::{Span};
 use crate::internals::parser::ast::kind::{Kind};
+use crate::internals::parser::ast::analyze::{Analyze};
 use crate::internals::parser::ast::args::{FunctionArg};
 use crate::internals::parser::ast::func::{FunctionDec};
 use crate::internals::parser::ast::comparg::{CompositionalFunctionArg,CompositionalFunction};
@@ -1911,345 +3060,815 @@ use crate::internals::parser::ast::invoke::{Invoke};
 use crate::internals::parser::ast::expr::{Expression};
 use crate::internals::parser::ast::template::{Template,TemplateBehavior};
 use crate::internals::parser::ast::assign::{Assign};
-use crate::internals::parser::ast::op::{Op,Operation};
+use crate::internals::parser::ast::op::{Op,Operation,UnaryOp,UnaryOperation};
 use crate::internals::parser::ast::statement::{Statement,Body};
 use crate::internals::parser::ast::condition::{Conditional};
+use crate::internals::parser::ast::block::{Block};
+use crate::internals::parser::ast::matching::{Match,MatchArm,Pattern,PatternKind};
+use crate::internals::parser::ast::collection::{CollectionLiteral,Index,Length};
+use crate::internals::parser::ast::dice::{Dice};
+use crate::internals::parser::ast::doc::{Doc};
+use crate::internals::parser::ast::import::{Import};
+
+    // User actions
+
+    // ProgramParser
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_0<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Body,lrpar::Lexeme<u32>> {
+{let mut v = __gt_arg_1?; v.push(__gt_arg_2?); Body::new(v, Span::into(__gt_lexer,__gt_span))}
+    }
+
+    // ProgramParser
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_1<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>) 
+->                  Result<Body,lrpar::Lexeme<u32>> {
+Body::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // ProgramParser
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_2<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Body,lrpar::Lexeme<u32>> {
+Body::new(vec![__gt_arg_1?], Span::into(__gt_lexer,__gt_span))
+    }
+
+    // TopSttmnts
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_3<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
+{let mut v = __gt_arg_1?; v.push(__gt_arg_2?); Ok(v) }
+    }
+
+    // TopSttmnts
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_4<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
+Ok(vec![__gt_arg_1?])
+    }
+
+    // TopSttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_5<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+__gt_arg_1
+    }
+
+    // TopSttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_6<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Ident,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_5: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(Analyze::new(__gt_arg_2?,__gt_arg_4?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // TopSttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_7<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(Import::new(Span::new(__gt_lexer,__gt_arg_2,None)?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // SttmntsColl
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_8<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
+{let mut v = __gt_arg_1?; v.push(__gt_arg_2?); Ok(v)}
+    }
+
+    // SttmntsColl
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_9<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
+Ok(vec![__gt_arg_1?])
+    }
+
+    // Sttmnts
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_10<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
+{let mut v = __gt_arg_1?; v.push(__gt_arg_2?); Ok(v) }
+    }
+
+    // Sttmnts
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_11<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
+Ok(vec![__gt_arg_1?])
+    }
+
+    // Sttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_12<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Assign,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Sttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_13<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<CompositionalFunction,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Sttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_14<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<FunctionDec,lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Sttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_15<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Doc,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Assign,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_2?.with_doc(__gt_arg_1?), Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Sttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_16<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Doc,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<CompositionalFunction,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_2?.with_doc(__gt_arg_1?), Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Sttmnt
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_17<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Doc,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<FunctionDec,lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_2?.with_doc(__gt_arg_1?), Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Docs
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_18<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Span>,lrpar::Lexeme<u32>>) 
+->                  Result<Doc,lrpar::Lexeme<u32>> {
+Doc::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // DocLines
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_19<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Vec<Span>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Span>,lrpar::Lexeme<u32>> {
+let mut v = __gt_arg_1?; v.push(Span::new(__gt_lexer, __gt_arg_2, None)?); Ok(v)
+    }
+
+    // DocLines
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_20<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Vec<Span>,lrpar::Lexeme<u32>> {
+Ok(vec![Span::new(__gt_lexer, __gt_arg_1, None)?])
+    }
+
+    // Term
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_21<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Statement,lrpar::Lexeme<u32>> {
+Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_22<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::ADD,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_23<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::SUB,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_24<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::MUL,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
 
-    // User actions
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_25<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::DIV,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
 
-    // ProgramParser
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_0<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_26<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>) 
-->                  Result<Body,lrpar::Lexeme<u32>> {
-Body::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::MOD,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // SttmntsColl
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_1<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_27<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: Result<Statement,lrpar::Lexeme<u32>>) 
-->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
-{let mut v = __gt_arg_1?; v.push(__gt_arg_2?); Ok(v)}
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::POW,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // SttmntsColl
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_2<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_28<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
-->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
-Ok(vec![__gt_arg_1?])
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::EQ ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Sttmnts
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_3<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_29<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: Result<Statement,lrpar::Lexeme<u32>>) 
-->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
-{let mut v = __gt_arg_1?; v.push(__gt_arg_2?); Ok(v) }
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::NE ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Sttmnts
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_4<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_30<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Statement,lrpar::Lexeme<u32>>) 
-->                  Result<Vec<Statement>,lrpar::Lexeme<u32>> {
-Ok(vec![__gt_arg_1?])
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::GT ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Sttmnt
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_5<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_31<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Assign,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
-->                  Result<Statement,lrpar::Lexeme<u32>> {
-Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::LT ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Sttmnt
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_6<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_32<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<CompositionalFunction,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
-->                  Result<Statement,lrpar::Lexeme<u32>> {
-Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::GE ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Sttmnt
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_7<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_33<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<FunctionDec,lrpar::Lexeme<u32>>) 
-->                  Result<Statement,lrpar::Lexeme<u32>> {
-Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::LE ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Term
+    // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_8<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_34<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Statement,lrpar::Lexeme<u32>> {
-Statement::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Operation::new(__gt_arg_1?,Op::AND,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
     // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_9<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_35<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
                      mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
                      mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
 ->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::ADD,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+Expression::new(Operation::new(__gt_arg_1?,Op::OR ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
     }
 
     // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_10<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_36<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
                      mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
                      mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
 ->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::SUB,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+Expression::new(Operation::new(__gt_arg_1?,Op::XOR,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_37<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(UnaryOperation::new(UnaryOp::Not,__gt_arg_2?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_38<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(UnaryOperation::new(UnaryOp::Neg,__gt_arg_2?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_39<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Index::new(__gt_arg_1?,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_40<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(Length::new(__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_41<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Vec<Expression>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(CollectionLiteral::new(__gt_arg_2?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_42<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_2?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_43<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Conditional,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_44<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Match,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_45<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Block,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_46<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Invoke,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_47<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Span,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_48<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Dice,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Expr
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_49<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Ident,lrpar::Lexeme<u32>>) 
+->                  Result<Expression,lrpar::Lexeme<u32>> {
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
     }
 
     // Expr
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_11<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_50<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+                     mut __gt_arg_1: Result<Template,lrpar::Lexeme<u32>>) 
 ->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::MUL,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Expr
+    // Cond
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_12<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_51<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::DIV,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_5: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Conditional,lrpar::Lexeme<u32>> {
+Conditional::new(__gt_arg_2?, __gt_arg_3?, __gt_arg_5?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Cond
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_13<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_52<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::EQ ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_5: Result<Conditional,lrpar::Lexeme<u32>>) 
+->                  Result<Conditional,lrpar::Lexeme<u32>> {
+Conditional::new(__gt_arg_2?, __gt_arg_3?, Conditional::into_expression(__gt_arg_5?)?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Arm
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_14<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_53<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
 ->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::NE ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+Block::into_arm(__gt_arg_2?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Block
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_15<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_54<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::GT ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Vec<Statement>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Block,lrpar::Lexeme<u32>> {
+Block::new(__gt_arg_2?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Match
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_16<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_55<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::LT ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Vec<MatchArm>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_5: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Match,lrpar::Lexeme<u32>> {
+Match::new(__gt_arg_2?, __gt_arg_4?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Match
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_17<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_56<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::GE ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Vec<MatchArm>,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_5: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_6: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Match,lrpar::Lexeme<u32>> {
+Match::new(__gt_arg_2?, __gt_arg_4?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // MatchArms
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_18<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_57<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_1: Result<Vec<MatchArm>,lrpar::Lexeme<u32>>,
                      mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::LE ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_3: Result<MatchArm,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<MatchArm>,lrpar::Lexeme<u32>> {
+let mut v = __gt_arg_1?; v.push(__gt_arg_3?); Ok(v)
     }
 
-    // Expr
+    // MatchArms
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_19<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_58<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::AND,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<MatchArm,lrpar::Lexeme<u32>>) 
+->                  Result<Vec<MatchArm>,lrpar::Lexeme<u32>> {
+Ok(vec![__gt_arg_1?])
     }
 
-    // Expr
+    // MatchArm
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_20<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_59<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_1: Result<Pattern,lrpar::Lexeme<u32>>,
                      mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
                      mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::OR ,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+->                  Result<MatchArm,lrpar::Lexeme<u32>> {
+MatchArm::new(__gt_arg_1?, __gt_arg_3?, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Pattern
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_21<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_60<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: Result<Expression,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(Operation::new(__gt_arg_1?,Op::XOR,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Pattern,lrpar::Lexeme<u32>> {
+Pattern::new(PatternKind::Wildcard, Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Pattern
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_22<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_61<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(__gt_arg_2?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Span,lrpar::Lexeme<u32>>) 
+->                  Result<Pattern,lrpar::Lexeme<u32>> {
+Pattern::new(PatternKind::Value(Box::new(__gt_arg_1?)), Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // Pattern
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_23<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_62<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Conditional,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: Result<Span,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: Result<Span,lrpar::Lexeme<u32>>) 
+->                  Result<Pattern,lrpar::Lexeme<u32>> {
+Pattern::new(PatternKind::Range(Box::new(__gt_arg_1?), Box::new(__gt_arg_3?)), Span::into(__gt_lexer, __gt_span))
     }
 
-    // Expr
+    // PatNum
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_24<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_63<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Invoke,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Span,lrpar::Lexeme<u32>> {
+Span::new(__gt_lexer, None, __gt_span)
     }
 
-    // Expr
+    // PatNum
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_25<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_64<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Span,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Span,lrpar::Lexeme<u32>> {
+Span::new(__gt_lexer, None, __gt_span)
     }
 
-    // Expr
+    // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_26<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_65<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Ident,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Ident,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Kind,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_5: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_6: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Assign,lrpar::Lexeme<u32>> {
+Assign::new(__gt_arg_2?, true, __gt_arg_4?, __gt_arg_6?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Expr
+    // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_27<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_66<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
-                     mut __gt_arg_1: Result<Template,lrpar::Lexeme<u32>>) 
-->                  Result<Expression,lrpar::Lexeme<u32>> {
-Expression::new(__gt_arg_1?, Span::into(__gt_lexer,__gt_span))
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Ident,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Assign,lrpar::Lexeme<u32>> {
+Assign::new(__gt_arg_2?, true, None, __gt_arg_4?, Span::into(__gt_lexer,__gt_span))
     }
 
-    // Cond
+    // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_28<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_67<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_2: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Ident,lrpar::Lexeme<u32>>,
                      mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_4: Result<Expression,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Kind,lrpar::Lexeme<u32>>,
                      mut __gt_arg_5: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_6: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_7: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
-                     mut __gt_arg_8: Result<Expression,lrpar::Lexeme<u32>>,
-                     mut __gt_arg_9: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
-->                  Result<Conditional,lrpar::Lexeme<u32>> {
-Conditional::new(__gt_arg_2?, __gt_arg_4?, __gt_arg_8?, Span::into(__gt_lexer, __gt_span))
+                     mut __gt_arg_6: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Assign,lrpar::Lexeme<u32>> {
+Assign::new(__gt_arg_2?, false, __gt_arg_4?, __gt_arg_6?, Span::into(__gt_lexer,__gt_span))
     }
 
     // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_29<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_68<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_2: Result<Ident,lrpar::Lexeme<u32>>,
+                     mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
+                     mut __gt_arg_4: Result<Expression,lrpar::Lexeme<u32>>) 
+->                  Result<Assign,lrpar::Lexeme<u32>> {
+Assign::new(__gt_arg_2?, false, None, __gt_arg_4?, Span::into(__gt_lexer,__gt_span))
+    }
+
+    // Assignment
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_69<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2264,7 +3883,7 @@ Assign::new(__gt_arg_2?, true, __gt_arg_4?, __gt_arg_6?, Span::into(__gt_lexer,_
 
     // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_30<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_70<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2277,7 +3896,7 @@ Assign::new(__gt_arg_2?, true, None, __gt_arg_4?, Span::into(__gt_lexer,__gt_spa
 
     // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_31<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_71<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2292,7 +3911,7 @@ Assign::new(__gt_arg_2?, false, __gt_arg_4?, __gt_arg_6?, Span::into(__gt_lexer,
 
     // Assignment
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_32<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_72<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2305,7 +3924,7 @@ Assign::new(__gt_arg_2?, false, None, __gt_arg_4?, Span::into(__gt_lexer,__gt_sp
 
     // DecFuncArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_33<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_73<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Ident,lrpar::Lexeme<u32>>,
@@ -2317,7 +3936,7 @@ FunctionArg::new(__gt_arg_1?,__gt_arg_3?,Span::into(__gt_lexer,__gt_span))
 
     // FuncArgDecList
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_34<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_74<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>,
@@ -2329,7 +3948,7 @@ let mut v = __gt_arg_1?; v.push(__gt_arg_3?); Ok(v)
 
     // FuncArgDecList
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_35<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_75<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<FunctionArg,lrpar::Lexeme<u32>>) 
@@ -2339,7 +3958,7 @@ Ok(vec![__gt_arg_1?])
 
     // DecFuncArgs
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_36<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_76<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2350,19 +3969,19 @@ Ok(Vec::new())
 
     // DecFuncArgs
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_37<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_77<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
                      mut __gt_arg_2: Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>,
                      mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
 ->                  Result<Vec<FunctionArg>,lrpar::Lexeme<u32>> {
-Ok(__gt_arg_2?)
+__gt_arg_2
     }
 
     // DecFunc
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_38<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_78<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2378,7 +3997,7 @@ FunctionDec::new(__gt_arg_2?,__gt_arg_3?,__gt_arg_6?,__gt_arg_4?,Span::into(__gt
 
     // Func
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_39<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_79<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Ident,lrpar::Lexeme<u32>>,
@@ -2389,7 +4008,7 @@ Invoke::new(__gt_arg_1?, __gt_arg_2?, Span::into(__gt_lexer,__gt_span))
 
     // FuncArgs
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_40<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_80<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2400,19 +4019,19 @@ Ok(Vec::new())
 
     // FuncArgs
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_41<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_81<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
                      mut __gt_arg_2: Result<Vec<Expression>,lrpar::Lexeme<u32>>,
                      mut __gt_arg_3: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
 ->                  Result<Vec<Expression>,lrpar::Lexeme<u32>> {
-Ok(__gt_arg_2?)
+__gt_arg_2
     }
 
     // ArgList
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_42<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_82<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Vec<Expression>,lrpar::Lexeme<u32>>,
@@ -2424,7 +4043,7 @@ let mut v = __gt_arg_1?; v.push(__gt_arg_3?); Ok(v)
 
     // ArgList
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_43<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_83<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Expression,lrpar::Lexeme<u32>>) 
@@ -2434,7 +4053,7 @@ Ok( vec![__gt_arg_1?] )
 
     // DecCmp
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_44<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_84<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2453,7 +4072,7 @@ CompositionalFunction::new(__gt_arg_2?,__gt_arg_4?,__gt_arg_6?,__gt_arg_8?,__gt_
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_45<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_85<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Span,lrpar::Lexeme<u32>>) 
@@ -2463,7 +4082,7 @@ CompositionalFunctionArg::new(__gt_arg_1?,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_46<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_86<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Span,lrpar::Lexeme<u32>>) 
@@ -2473,7 +4092,7 @@ CompositionalFunctionArg::new(__gt_arg_1?,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_47<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_87<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Template,lrpar::Lexeme<u32>>) 
@@ -2483,7 +4102,7 @@ CompositionalFunctionArg::new(__gt_arg_1?,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_48<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_88<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: Result<Ident,lrpar::Lexeme<u32>>) 
@@ -2493,7 +4112,7 @@ CompositionalFunctionArg::new(__gt_arg_1?,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_49<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_89<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2503,7 +4122,7 @@ CompositionalFunctionArg::new(Op::ADD,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_50<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_90<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2513,7 +4132,7 @@ CompositionalFunctionArg::new(Op::SUB,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_51<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_91<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2523,7 +4142,7 @@ CompositionalFunctionArg::new(Op::MUL,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_52<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_92<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2533,7 +4152,7 @@ CompositionalFunctionArg::new(Op::AND,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_53<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_93<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2543,7 +4162,7 @@ CompositionalFunctionArg::new(Op::OR,Span::into(__gt_lexer,__gt_span))
 
     // CompArg
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_54<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_94<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2553,37 +4172,47 @@ CompositionalFunctionArg::new(Op::XOR,Span::into(__gt_lexer,__gt_span))
 
     // Num
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_55<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_95<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
 ->                  Result<Span,lrpar::Lexeme<u32>> {
-Ok(Span::new(__gt_lexer, None, __gt_span)?)
+Span::new(__gt_lexer, None, __gt_span)
+    }
+
+    // Dice
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_96<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Dice,lrpar::Lexeme<u32>> {
+Dice::new(__gt_lexer, __gt_arg_1, Span::into(__gt_lexer,__gt_span))
     }
 
     // Bool
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_56<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_97<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
 ->                  Result<Span,lrpar::Lexeme<u32>> {
-Ok(Span::new(__gt_lexer,None,__gt_span)?)
+Span::new(__gt_lexer, None, __gt_span)
     }
 
     // Bool
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_57<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_98<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
 ->                  Result<Span,lrpar::Lexeme<u32>> {
-Ok(Span::new(__gt_lexer,None,__gt_span)?)
+Span::new(__gt_lexer, None, __gt_span)
     }
 
     // Identifier
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_58<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_99<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2591,9 +4220,29 @@ Ok(Span::new(__gt_lexer,None,__gt_span)?)
 Ok( Ident::new( Span::new(__gt_lexer, None, __gt_span)? ) )
     }
 
+    // Name
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_100<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: Result<Ident,lrpar::Lexeme<u32>>) 
+->                  Result<Ident,lrpar::Lexeme<u32>> {
+__gt_arg_1
+    }
+
+    // Name
+    #[allow(clippy::too_many_arguments)]
+    fn __gt_action_101<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+                     __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
+                     __gt_span: ::lrpar::Span,
+                     mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
+->                  Result<Ident,lrpar::Lexeme<u32>> {
+Err(__gt_arg_1.unwrap_or_else(|lexeme| lexeme))
+    }
+
     // TemplateVar
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_59<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_102<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2607,7 +4256,7 @@ Ok(Template::new(__gt_arg_2?, Span::new(__gt_lexer, None, __gt_span)?, TemplateB
 
     // TemplateVar
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_60<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_103<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2621,7 +4270,7 @@ Ok(Template::new(__gt_arg_2?, Span::new(__gt_lexer, None, __gt_span)?, TemplateB
 
     // TemplateVar
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_61<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_104<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2635,7 +4284,7 @@ Ok(Template::new(__gt_arg_2?, Span::new(__gt_lexer, None, __gt_span)?, TemplateB
 
     // TemplateVar
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_62<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_105<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2649,7 +4298,7 @@ Ok(Template::new(__gt_arg_2?, Span::new(__gt_lexer, None, __gt_span)?, TemplateB
 
     // TemplateVar
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_63<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_106<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>,
@@ -2661,7 +4310,7 @@ Ok(Template::new(__gt_arg_2?, Span::new(__gt_lexer, None, __gt_span)?, None))
 
     // TypeInfo
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_64<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_107<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2671,7 +4320,7 @@ Ok(Kind::Int)
 
     // TypeInfo
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_65<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_108<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2681,7 +4330,7 @@ Ok(Kind::Bool)
 
     // TypeInfo
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_66<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_109<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2691,7 +4340,7 @@ Ok(Kind::CollOfInt)
 
     // TypeInfo
     #[allow(clippy::too_many_arguments)]
-    fn __gt_action_67<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
+    fn __gt_action_110<'lexer, 'input: 'lexer>(__gt_ridx: ::cfgrammar::RIdx<u32>,
                      __gt_lexer: &'lexer dyn ::lrpar::NonStreamingLexer<'input, u32>,
                      __gt_span: ::lrpar::Span,
                      mut __gt_arg_1: ::std::result::Result<::lrpar::Lexeme<u32>, ::lrpar::Lexeme<u32>>) 
@@ -2703,48 +4352,63 @@ Ok(Kind::CollOfBool)
 
 
 /* CACHE INFORMATION
-   Build time: "2022-02-21T19:15:27.051279600+00:00"
+   Build time: "2026-10-18T10:54:59.722279753+00:00"
    Mod name: None
-   Recoverer: None
+   Recoverer: CPCTPlus
    YaccKind: Some(Grmtools)
    Error on conflicts: true
-   0 'SEMI'
-   1 'ADD'
-   2 'SUB'
-   3 'MUL'
-   4 'DIV'
-   5 'EQ'
-   6 'NE'
-   7 'GT'
-   8 'LT'
-   9 'GE'
-   10 'LE'
-   11 'AND'
-   12 'OR'
-   13 'XOR'
-   14 'LPAR'
-   15 'RPAR'
-   16 'IF'
-   17 'RBRACE'
-   18 'LBRACE'
-   19 'ELSE'
-   20 'CONST'
-   21 'COLON'
-   22 'ASSIGN'
-   23 'LET'
-   24 'COMMA'
-   25 'FN'
-   26 'COMP'
-   27 'NUM'
-   28 'TRUE'
-   29 'FALSE'
-   30 'IDENT'
-   31 'TEMPLATE_START'
-   32 'TEMPLATE_ASSIGN'
-   33 'TEMPLATE_FALLBACK'
-   34 'INT'
-   35 'BOOL'
-   36 'VEC_INT'
-   37 'VEC_BOOL'
-   38 <unknown>
+   0 'ANALYZE'
+   1 'ASSIGN'
+   2 'SEMI'
+   3 'IMPORT'
+   4 'STRING'
+   5 'DOC'
+   6 'ADD'
+   7 'SUB'
+   8 'MUL'
+   9 'DIV'
+   10 'MOD'
+   11 'POW'
+   12 'EQ'
+   13 'NE'
+   14 'GT'
+   15 'LT'
+   16 'GE'
+   17 'LE'
+   18 'AND'
+   19 'OR'
+   20 'XOR'
+   21 'NOT'
+   22 'LBRACKET'
+   23 'RBRACKET'
+   24 'LEN'
+   25 'LPAR'
+   26 'RPAR'
+   27 'IF'
+   28 'ELSE'
+   29 'RBRACE'
+   30 'LBRACE'
+   31 'MATCH'
+   32 'COMMA'
+   33 'ARROW'
+   34 'UNDERSCORE'
+   35 'RANGE_INCL'
+   36 'NUM'
+   37 'CONST'
+   38 'COLON'
+   39 'LET'
+   40 'TEMPLATE_ASSIGN'
+   41 'FN'
+   42 'COMP'
+   43 'DICE'
+   44 'TRUE'
+   45 'FALSE'
+   46 'IDENT'
+   47 'TEMPLATE_START'
+   48 'TEMPLATE_FALLBACK'
+   49 'INT'
+   50 'BOOL'
+   51 'VEC_INT'
+   52 'VEC_BOOL'
+   53 <unknown>
 */
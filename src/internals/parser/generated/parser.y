@@ -1,21 +1,97 @@
 %start ProgramParser
-%left 'ADD'
-%left 'SUB'
-%left 'MUL'
-%left 'DIV'
-%left 'EQ'
-%left 'NE'
-%left 'GT'
-%left 'LT'
-%left 'GE'
-%left 'LE'
-%left 'AND'
+%epp NUM "number"
+%epp DICE "dice"
+%epp IDENT "identifier"
+%epp DOC "doc comment"
+%epp ANALYZE "analyze"
+%epp IMPORT "import"
+%epp STRING "string"
+%epp COMP "cm"
+%epp FN "fn"
+%epp INT "int"
+%epp BOOL "bool"
+%epp TRUE "true"
+%epp FALSE "false"
+%epp VEC_INT "vec<int>"
+%epp VEC_BOOL "vec<bool>"
+%epp CONST "const"
+%epp LET "let"
+%epp IF "if"
+%epp ELSE "else"
+%epp MATCH "match"
+%epp UNDERSCORE "_"
+%epp ARROW "=>"
+%epp RANGE_INCL "..="
+%epp TEMPLATE_FALLBACK ":-"
+%epp TEMPLATE_ASSIGN ":="
+%epp TEMPLATE_START "${"
+%epp ASSIGN "="
+%epp RBRACE "{"
+%epp LBRACE "}"
+%epp LPAR "("
+%epp RPAR ")"
+%epp LBRACKET "["
+%epp RBRACKET "]"
+%epp LEN "len"
+%epp XOR "^"
+%epp OR "|"
+%epp AND "&"
+%epp COMMA ","
+%epp SEMI ";"
+%epp COLON ":"
+%epp ADD "+"
+%epp MUL "*"
+%epp SUB "-"
+%epp DIV "/"
+%epp MOD "%"
+%epp POW "**"
+%epp EQ "=="
+%epp NE "!="
+%epp NOT "!"
+%epp LT "<"
+%epp GT ">"
+%epp LE "<="
+%epp GE ">="
+
+/*
+ * Operator precedence, lowest binding first.
+ *
+ * This mirrors C/Rust: `a + b * c` is `a + (b * c)`,
+ * `x == 1 & y == 2` is `(x == 1) & (y == 2)`, and
+ * comparisons cannot be chained without parens.
+ * `**` binds tighter than unary operators (`-a ** b`
+ * is `-(a ** b)`) and is right associative.
+ * Indexing `xs[i]` binds tightest of all.
+ */
 %left 'OR'
 %left 'XOR'
+%left 'AND'
+%nonassoc 'EQ' 'NE' 'GT' 'LT' 'GE' 'LE'
+%left 'ADD' 'SUB'
+%left 'MUL' 'DIV' 'MOD'
+%right 'NOT'
+%right 'POW'
+%left 'LBRACKET'
 %%
 
 ProgramParser -> Result<Body,lrpar::Lexeme<u32>>:
-      SttmntsColl { Body::new($1?, Span::into($lexer,$span)) }; 
+      TopSttmnts Term { {let mut v = $1?; v.push($2?); Body::new(v, Span::into($lexer,$span))} }
+    | TopSttmnts      { Body::new($1?, Span::into($lexer,$span)) }
+    | Term            { Body::new(vec![$1?], Span::into($lexer,$span)) };
+
+/*
+ * Top level statements, these may declare outputs
+ *
+ */
+
+TopSttmnts -> Result<Vec<Statement>,lrpar::Lexeme<u32>>:
+      TopSttmnts TopSttmnt { {let mut v = $1?; v.push($2?); Ok(v) } }
+    | TopSttmnt            { Ok(vec![$1?]) };
+
+TopSttmnt -> Result<Statement,lrpar::Lexeme<u32>>:
+      Sttmnt                                     { $1 }
+    | 'ANALYZE' Name 'ASSIGN' Expr 'SEMI' { Statement::new(Analyze::new($2?,$4?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | 'IMPORT' 'STRING' 'SEMI'                  { Statement::new(Import::new(Span::new($lexer,$2,None)?,Span::into($lexer,$span))?, Span::into($lexer,$span)) };
 
 /*
  * Statement handling
@@ -31,9 +107,24 @@ Sttmnts -> Result<Vec<Statement>,lrpar::Lexeme<u32>>:
     | Sttmnt         { Ok(vec![$1?]) };
 
 Sttmnt -> Result<Statement,lrpar::Lexeme<u32>>:
-      Assignment 'SEMI' { Statement::new($1?, Span::into($lexer,$span)) }
-    | DecCmp 'SEMI'     { Statement::new($1?, Span::into($lexer,$span)) }
-    | DecFunc           { Statement::new($1?, Span::into($lexer,$span)) };
+      Assignment 'SEMI'      { Statement::new($1?, Span::into($lexer,$span)) }
+    | DecCmp 'SEMI'          { Statement::new($1?, Span::into($lexer,$span)) }
+    | DecFunc                { Statement::new($1?, Span::into($lexer,$span)) }
+    | Docs Assignment 'SEMI' { Statement::new($2?.with_doc($1?), Span::into($lexer,$span)) }
+    | Docs DecCmp 'SEMI'     { Statement::new($2?.with_doc($1?), Span::into($lexer,$span)) }
+    | Docs DecFunc           { Statement::new($2?.with_doc($1?), Span::into($lexer,$span)) };
+
+/*
+ * Doc comments, `///` lines kept for the declaration which follows
+ *
+ */
+
+Docs -> Result<Doc,lrpar::Lexeme<u32>>:
+    DocLines { Doc::new($1?, Span::into($lexer,$span)) };
+
+DocLines -> Result<Vec<Span>,lrpar::Lexeme<u32>>:
+      DocLines 'DOC' { let mut v = $1?; v.push(Span::new($lexer, $2, None)?); Ok(v) }
+    | 'DOC'          { Ok(vec![Span::new($lexer, $1, None)?]) };
 
 Term -> Result<Statement,lrpar::Lexeme<u32>>:
     Expr { Statement::new($1?, Span::into($lexer,$span)) };
@@ -48,6 +139,8 @@ Expr -> Result<Expression,lrpar::Lexeme<u32>>:
     | Expr 'SUB' Expr    { Expression::new(Operation::new($1?,Op::SUB,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'MUL' Expr    { Expression::new(Operation::new($1?,Op::MUL,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'DIV' Expr    { Expression::new(Operation::new($1?,Op::DIV,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | Expr 'MOD' Expr    { Expression::new(Operation::new($1?,Op::MOD,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | Expr 'POW' Expr    { Expression::new(Operation::new($1?,Op::POW,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'EQ' Expr     { Expression::new(Operation::new($1?,Op::EQ ,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'NE' Expr     { Expression::new(Operation::new($1?,Op::NE ,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'GT' Expr     { Expression::new(Operation::new($1?,Op::GT ,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
@@ -57,10 +150,18 @@ Expr -> Result<Expression,lrpar::Lexeme<u32>>:
     | Expr 'AND' Expr    { Expression::new(Operation::new($1?,Op::AND,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'OR' Expr     { Expression::new(Operation::new($1?,Op::OR ,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | Expr 'XOR' Expr    { Expression::new(Operation::new($1?,Op::XOR,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | 'NOT' Expr         { Expression::new(UnaryOperation::new(UnaryOp::Not,$2?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | 'SUB' Expr %prec 'NOT' { Expression::new(UnaryOperation::new(UnaryOp::Neg,$2?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | Expr 'LBRACKET' Expr 'RBRACKET' { Expression::new(Index::new($1?,$3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | 'LEN' 'LPAR' Expr 'RPAR'        { Expression::new(Length::new($3?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
+    | 'LBRACKET' ArgList 'RBRACKET'   { Expression::new(CollectionLiteral::new($2?,Span::into($lexer,$span))?, Span::into($lexer,$span)) }
     | 'LPAR' Expr 'RPAR' { Expression::new($2?, Span::into($lexer,$span)) }
     | Cond               { Expression::new($1?, Span::into($lexer,$span)) }
+    | Match              { Expression::new($1?, Span::into($lexer,$span)) }
+    | Block              { Expression::new($1?, Span::into($lexer,$span)) }
     | Func               { Expression::new($1?, Span::into($lexer,$span)) }
     | Num                { Expression::new($1?, Span::into($lexer,$span)) }
+    | Dice               { Expression::new($1?, Span::into($lexer,$span)) }
     | Identifier         { Expression::new($1?, Span::into($lexer,$span)) }
     | TemplateVar        { Expression::new($1?, Span::into($lexer,$span)) };
 
@@ -69,7 +170,42 @@ Expr -> Result<Expression,lrpar::Lexeme<u32>>:
  *
  */
 Cond -> Result<Conditional,lrpar::Lexeme<u32>>:
-    'IF' Expr 'RBRACE' Expr 'LBRACE' 'ELSE' 'RBRACE' Expr 'LBRACE' { Conditional::new($2?, $4?, $8?, Span::into($lexer, $span)) };
+      'IF' Expr Arm 'ELSE' Arm  { Conditional::new($2?, $3?, $5?, Span::into($lexer, $span)) }
+    | 'IF' Expr Arm 'ELSE' Cond { Conditional::new($2?, $3?, Conditional::into_expression($5?)?, Span::into($lexer, $span)) };
+
+Arm -> Result<Expression,lrpar::Lexeme<u32>>:
+    'RBRACE' SttmntsColl 'LBRACE' { Block::into_arm($2?, Span::into($lexer, $span)) };
+
+/*
+ * Blocks, `{ let a := 2; a * a }`
+ *
+ */
+Block -> Result<Block,lrpar::Lexeme<u32>>:
+    'RBRACE' SttmntsColl 'LBRACE' { Block::new($2?, Span::into($lexer, $span)) };
+
+/*
+ * Match, `match x { 1 => a, 2..=3 => b, _ => c }`
+ *
+ */
+Match -> Result<Match,lrpar::Lexeme<u32>>:
+      'MATCH' Expr 'RBRACE' MatchArms 'LBRACE'         { Match::new($2?, $4?, Span::into($lexer, $span)) }
+    | 'MATCH' Expr 'RBRACE' MatchArms 'COMMA' 'LBRACE' { Match::new($2?, $4?, Span::into($lexer, $span)) };
+
+MatchArms -> Result<Vec<MatchArm>,lrpar::Lexeme<u32>>:
+      MatchArms 'COMMA' MatchArm { let mut v = $1?; v.push($3?); Ok(v) }
+    | MatchArm                   { Ok(vec![$1?]) };
+
+MatchArm -> Result<MatchArm,lrpar::Lexeme<u32>>:
+    Pattern 'ARROW' Expr { MatchArm::new($1?, $3?, Span::into($lexer, $span)) };
+
+Pattern -> Result<Pattern,lrpar::Lexeme<u32>>:
+      'UNDERSCORE'                 { Pattern::new(PatternKind::Wildcard, Span::into($lexer, $span)) }
+    | PatNum                       { Pattern::new(PatternKind::Value(Box::new($1?)), Span::into($lexer, $span)) }
+    | PatNum 'RANGE_INCL' PatNum   { Pattern::new(PatternKind::Range(Box::new($1?), Box::new($3?)), Span::into($lexer, $span)) };
+
+PatNum -> Result<Span,lrpar::Lexeme<u32>>:
+      'NUM'       { Span::new($lexer, None, $span) }
+    | 'SUB' 'NUM' { Span::new($lexer, None, $span) };
 
 /*
  * Variable Assignments
@@ -77,10 +213,14 @@ Cond -> Result<Conditional,lrpar::Lexeme<u32>>:
  */
 
 Assignment -> Result<Assign,lrpar::Lexeme<u32>>:
-      'CONST' Identifier 'COLON' TypeInfo 'EQ' Expr { Assign::new($2?, true, $4?, $6?, Span::into($lexer,$span)) }
-    | 'CONST' Identifier 'ASSIGN' Expr              { Assign::new($2?, true, None, $4?, Span::into($lexer,$span)) } 
-    | 'LET' Identifier 'COLON' TypeInfo 'EQ' Expr   { Assign::new($2?, false, $4?, $6?, Span::into($lexer,$span)) }
-    | 'LET' Identifier 'ASSIGN' Expr                { Assign::new($2?, false, None, $4?, Span::into($lexer,$span)) };
+      'CONST' Name 'COLON' TypeInfo 'EQ' Expr { Assign::new($2?, true, $4?, $6?, Span::into($lexer,$span)) }
+    | 'CONST' Name 'ASSIGN' Expr              { Assign::new($2?, true, None, $4?, Span::into($lexer,$span)) } 
+    | 'LET' Name 'COLON' TypeInfo 'EQ' Expr   { Assign::new($2?, false, $4?, $6?, Span::into($lexer,$span)) }
+    | 'LET' Name 'ASSIGN' Expr                { Assign::new($2?, false, None, $4?, Span::into($lexer,$span)) }
+    | 'CONST' Name 'COLON' TypeInfo 'TEMPLATE_ASSIGN' Expr { Assign::new($2?, true, $4?, $6?, Span::into($lexer,$span)) }
+    | 'CONST' Name 'TEMPLATE_ASSIGN' Expr     { Assign::new($2?, true, None, $4?, Span::into($lexer,$span)) }
+    | 'LET' Name 'COLON' TypeInfo 'TEMPLATE_ASSIGN' Expr   { Assign::new($2?, false, $4?, $6?, Span::into($lexer,$span)) }
+    | 'LET' Name 'TEMPLATE_ASSIGN' Expr       { Assign::new($2?, false, None, $4?, Span::into($lexer,$span)) };
 
 /*
  * Declaring Functions
@@ -88,7 +228,7 @@ Assignment -> Result<Assign,lrpar::Lexeme<u32>>:
  */
 
 DecFuncArg -> Result<FunctionArg,lrpar::Lexeme<u32>>:
-    Identifier 'COLON' TypeInfo { FunctionArg::new($1?,$3?,Span::into($lexer,$span)) };
+    Name 'COLON' TypeInfo { FunctionArg::new($1?,$3?,Span::into($lexer,$span)) };
 
 FuncArgDecList -> Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>:
       FuncArgDecList 'COMMA' DecFuncArg { let mut v = $1?; v.push($3?); Ok(v) }
@@ -96,10 +236,10 @@ FuncArgDecList -> Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>:
 
 DecFuncArgs -> Result<Vec<FunctionArg>,lrpar::Lexeme<u32>>:
       'LPAR' 'RPAR' { Ok(Vec::new()) }
-    | 'LPAR' FuncArgDecList 'RPAR' { Ok($2?) };
+    | 'LPAR' FuncArgDecList 'RPAR' { $2 };
 
 DecFunc -> Result<FunctionDec,lrpar::Lexeme<u32>>:
-    'FN' Identifier DecFuncArgs TypeInfo 'RBRACE' SttmntsColl 'LBRACE' { FunctionDec::new($2?,$3?,$6?,$4?,Span::into($lexer,$span)) };
+    'FN' Name DecFuncArgs TypeInfo 'RBRACE' SttmntsColl 'LBRACE' { FunctionDec::new($2?,$3?,$6?,$4?,Span::into($lexer,$span)) };
 
 /*
  * Invoking Functions
@@ -111,7 +251,7 @@ Func -> Result<Invoke,lrpar::Lexeme<u32>>:
 
 FuncArgs -> Result<Vec<Expression>,lrpar::Lexeme<u32>>:
       'LPAR' 'RPAR' { Ok(Vec::new()) }
-    | 'LPAR' ArgList 'RPAR' { Ok($2?) };
+    | 'LPAR' ArgList 'RPAR' { $2 };
 
 ArgList -> Result<Vec<Expression>,lrpar::Lexeme<u32>>:
       ArgList 'COMMA' Expr { let mut v = $1?; v.push($3?); Ok(v) }
@@ -124,7 +264,7 @@ ArgList -> Result<Vec<Expression>,lrpar::Lexeme<u32>>:
  */
 
 DecCmp -> Result<CompositionalFunction,lrpar::Lexeme<u32>>:
-    'COMP' Identifier 'LPAR' CompArg 'COMMA' CompArg 'COMMA' CompArg 'RPAR' TypeInfo { CompositionalFunction::new($2?,$4?,$6?,$8?,$10?,Span::into($lexer,$span)) };
+    'COMP' Name 'LPAR' CompArg 'COMMA' CompArg 'COMMA' CompArg 'RPAR' TypeInfo { CompositionalFunction::new($2?,$4?,$6?,$8?,$10?,Span::into($lexer,$span)) };
 
 CompArg -> Result<CompositionalFunctionArg,lrpar::Lexeme<u32>>:
       Bool        { CompositionalFunctionArg::new($1?,Span::into($lexer,$span)) }
@@ -145,21 +285,37 @@ CompArg -> Result<CompositionalFunctionArg,lrpar::Lexeme<u32>>:
  */
 
 Num -> Result<Span,lrpar::Lexeme<u32>>:
-    'NUM' { Ok(Span::new($lexer, None, $span)?) };
+    'NUM' { Span::new($lexer, None, $span) };
+
+Dice -> Result<Dice,lrpar::Lexeme<u32>>:
+    'DICE' { Dice::new($lexer, $1, Span::into($lexer,$span)) };
 
 Bool -> Result<Span,lrpar::Lexeme<u32>>:
-      'TRUE'  { Ok(Span::new($lexer,None,$span)?) }
-    | 'FALSE' { Ok(Span::new($lexer,None,$span)?) };
+      'TRUE'  { Span::new($lexer, None, $span) }
+    | 'FALSE' { Span::new($lexer, None, $span) };
 
 Identifier -> Result<Ident,lrpar::Lexeme<u32>>:
       'IDENT' { Ok( Ident::new( Span::new($lexer, None, $span)? ) ) };
 
+/*
+ * A declared name. `d6` & `2d20` always lex as DICE (the rule precedes
+ * IDENT), so one in a name's place fails with its own lexeme, which
+ * `parse_source` reports as a dice literal used as a name.
+ */
+Name -> Result<Ident,lrpar::Lexeme<u32>>:
+      Identifier { $1 }
+    | 'DICE'     { Err($1.unwrap_or_else(|lexeme| lexeme)) };
+
+/*
+ * Templates, `${NAME}` closes on `}` (the 'LBRACE' token, see lexer.l)
+ *
+ */
 TemplateVar -> Result<Template,lrpar::Lexeme<u32>>:
-      'TEMPLATE_START' Identifier 'TEMPLATE_ASSIGN' TemplateVar 'RBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::assign($4?))) }
-    | 'TEMPLATE_START' Identifier 'TEMPLATE_ASSIGN' 'NUM' 'RBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::assign(Span::new($lexer, $4, None)?))) }
-    | 'TEMPLATE_START' Identifier 'TEMPLATE_FALLBACK' TemplateVar 'RBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::fallback($4?))) }
-    | 'TEMPLATE_START' Identifier 'TEMPLATE_FALLBACK' 'NUM' 'RBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::fallback(Span::new($lexer, $4, None)?))) }
-    | 'TEMPLATE_START' Identifier 'RBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, None)) };
+      'TEMPLATE_START' Identifier 'TEMPLATE_ASSIGN' TemplateVar 'LBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::assign($4?))) }
+    | 'TEMPLATE_START' Identifier 'TEMPLATE_ASSIGN' 'NUM' 'LBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::assign(Span::new($lexer, $4, None)?))) }
+    | 'TEMPLATE_START' Identifier 'TEMPLATE_FALLBACK' TemplateVar 'LBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::fallback($4?))) }
+    | 'TEMPLATE_START' Identifier 'TEMPLATE_FALLBACK' 'NUM' 'LBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, TemplateBehavior::fallback(Span::new($lexer, $4, None)?))) }
+    | 'TEMPLATE_START' Identifier 'LBRACE' { Ok(Template::new($2?, Span::new($lexer, None, $span)?, None)) };
 
 TypeInfo -> Result<Kind,lrpar::Lexeme<u32>>:
       'INT'      { Ok(Kind::Int) }
@@ -171,6 +327,7 @@ TypeInfo -> Result<Kind,lrpar::Lexeme<u32>>:
 
 use crate::internals::parser::span::{Span};
 use crate::internals::parser::ast::kind::{Kind};
+use crate::internals::parser::ast::analyze::{Analyze};
 use crate::internals::parser::ast::args::{FunctionArg};
 use crate::internals::parser::ast::func::{FunctionDec};
 use crate::internals::parser::ast::comparg::{CompositionalFunctionArg,CompositionalFunction};
@@ -179,6 +336,12 @@ use crate::internals::parser::ast::invoke::{Invoke};
 use crate::internals::parser::ast::expr::{Expression};
 use crate::internals::parser::ast::template::{Template,TemplateBehavior};
 use crate::internals::parser::ast::assign::{Assign};
-use crate::internals::parser::ast::op::{Op,Operation};
+use crate::internals::parser::ast::op::{Op,Operation,UnaryOp,UnaryOperation};
 use crate::internals::parser::ast::statement::{Statement,Body};
 use crate::internals::parser::ast::condition::{Conditional};
+use crate::internals::parser::ast::block::{Block};
+use crate::internals::parser::ast::matching::{Match,MatchArm,Pattern,PatternKind};
+use crate::internals::parser::ast::collection::{CollectionLiteral,Index,Length};
+use crate::internals::parser::ast::dice::{Dice};
+use crate::internals::parser::ast::doc::{Doc};
+use crate::internals::parser::ast::import::{Import};
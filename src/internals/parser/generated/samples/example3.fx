@@ -0,0 +1,4 @@
+#!/usr/bin/foxhole
+
+let attacks = 2d3+1;
+attacks * d6
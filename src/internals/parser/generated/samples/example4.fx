@@ -0,0 +1,5 @@
+#!/usr/bin/foxhole
+
+let miss = !(d6 > 3);
+let penalty = -2;
+-d3 - penalty
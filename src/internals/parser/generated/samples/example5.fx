@@ -0,0 +1,5 @@
+#!/usr/bin/foxhole
+
+let rolls = [d6, d6, 2d3];
+let best = rolls[0] + rolls[len(rolls) - 1];
+best
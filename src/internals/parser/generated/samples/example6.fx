@@ -0,0 +1,14 @@
+// flat bonus applied to every attack
+let bonus = 2;
+
+/// Rolls to hit.
+/// `roll` is the natural die result.
+fn attack(roll: int) int {
+    roll + bonus /* modifiers stack */
+}
+
+/*
+ * outputs
+ */
+analyze hit = attack(d20) >= 12;
+analyze damage = 2d6 + bonus;
@@ -0,0 +1,24 @@
+// swept with `foxhole sweep example7.fx --sweep STRENGTH=3..=8 --sweep AP=0..=3`
+const toughness = ${TOUGHNESS :- 4};
+
+/// the roll needed to wound
+fn wound_on(strength: int) int {
+    if strength >= toughness * 2 {
+        2
+    } else if strength > toughness {
+        3
+    } else if strength == toughness {
+        4
+    } else {
+        5
+    }
+}
+
+analyze wound = d6 >= wound_on(${STRENGTH});
+analyze save = {
+    let roll = d6 - ${AP};
+    match roll {
+        -3..=2 => 0,
+        _ => 1,
+    }
+};
@@ -0,0 +1,257 @@
+//! Loader
+//!
+//! Loads a program which spans several files. Starting from the root
+//! file every `import` is followed, each file is parsed once (no matter
+//! how many times it's imported), and import cycles are reported.
+
+use std::{
+    collections::HashSet,
+    fs::{canonicalize, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use crate::internals::parser::{
+    ast::{
+        import::Import,
+        statement::{Body, StateTrait, Statement},
+    },
+    generated::parse_named,
+    source_map::SourceName,
+    span::Spanner,
+    traits::{ImportError, SyntaxError},
+};
+
+/// SourceFile is a single parsed file of a program
+pub struct SourceFile {
+    /// the `SourceMap` file id carried by every span within `body`
+    pub id: u32,
+
+    pub name: SourceName,
+
+    pub body: Body,
+}
+
+/// Program is every file reachable from the root file through imports.
+pub struct Program {
+    /// ordered so every file follows the files it imports,
+    /// the root file is last.
+    pub files: Vec<SourceFile>,
+}
+
+impl Program {
+    /// the file the program was loaded from
+    pub fn root(&self) -> &SourceFile {
+        self.files.last().unwrap()
+    }
+
+    /// looks up a file by the id its spans carry
+    pub fn get_file(&self, id: u32) -> Option<&SourceFile> {
+        self.files.iter().find(|file| file.id == id)
+    }
+
+    /// The top level statements of the program, the functions &
+    /// compositional functions of every imported file, then every
+    /// statement of the root file.
+    ///
+    /// Nothing else an imported file declares is part of the program.
+    pub fn top_level(&self) -> impl Iterator<Item = &Statement> + Clone {
+        let root = self.root().id;
+        self.files.iter().flat_map(move |file| {
+            file.body.body.iter().filter(move |statement| {
+                file.id == root || statement.get_func().is_some() || statement.get_comp_func().is_some()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+impl Program {
+    /// a program of the single file `source`, shown as `<anon>`
+    pub(crate) fn from_str_for_test(source: &str) -> Self {
+        use crate::internals::{errors::term_errors::HumanReadable, parser::generated::parse_code};
+
+        let body = parse_code::<HumanReadable>(source).unwrap();
+        let id = body.get_file_id();
+        let name = SourceName::Memory("anon".to_string());
+        Program { files: vec![SourceFile { id, name, body }] }
+    }
+}
+
+/// Loads the program rooted at `root`.
+///
+/// Every error (syntax errors in any file, unreadable imports, and
+/// cycles) is collected and returned together.
+pub fn load_program<E>(root: &Path) -> Result<Program, Vec<E>>
+where
+    E: SyntaxError + ImportError,
+{
+    let mut loader = Loader::default();
+    match canonicalize(root) {
+        Ok(path) => loader.load(path, root.to_path_buf(), None),
+        Err(e) => loader
+            .errors
+            .push(E::unreadable_file(root, e.to_string(), None)),
+    };
+    loader.finish()
+}
+
+/// Loads a program whose root file was read from stdin,
+/// its imports are relative to the working directory.
+pub fn load_program_from_stdin<E>(source: &str) -> Result<Program, Vec<E>>
+where
+    E: SyntaxError + ImportError,
+{
+    let mut loader = Loader::default();
+    loader.add(SourceName::Stdin, source, Path::new(""), None);
+    loader.finish()
+}
+
+struct Loader<E> {
+    files: Vec<SourceFile>,
+    // canonical path of every file which was started
+    loaded: HashSet<PathBuf>,
+    // the chain of imports currently being loaded
+    stack: Vec<PathBuf>,
+    errors: Vec<E>,
+}
+
+impl<E> Default for Loader<E> {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            loaded: HashSet::new(),
+            stack: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<E> Loader<E>
+where
+    E: SyntaxError + ImportError,
+{
+    fn finish(self) -> Result<Program, Vec<E>> {
+        if self.errors.is_empty() {
+            Ok(Program { files: self.files })
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// `path` is canonical, used to identify the file. `shown` is the
+    /// path as the user would write it, used within diagnostics.
+    fn load(&mut self, path: PathBuf, shown: PathBuf, import: Option<&Import>) {
+        self.loaded.insert(path.clone());
+        let source = match read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                self.errors
+                    .push(E::unreadable_file(&shown, e.to_string(), import));
+                return;
+            }
+        };
+        let dir = shown.parent().map(Path::to_path_buf).unwrap_or_default();
+        self.add(SourceName::Path(shown), &source, &dir, Some(path));
+    }
+
+    /// parses a source, then loads everything it imports (relative to `dir`)
+    fn add(&mut self, name: SourceName, source: &str, dir: &Path, path: Option<PathBuf>) {
+        let body = match parse_named::<E>(name.clone(), source) {
+            Ok(body) => body,
+            Err(errors) => {
+                self.errors.extend(errors);
+                return;
+            }
+        };
+
+        let nested = path.is_some();
+        self.stack.extend(path);
+        for import in body.body.iter().filter_map(|s| s.get_import()) {
+            let shown = dir.join(import.get_path());
+            let target = match canonicalize(&shown) {
+                Ok(target) => target,
+                Err(e) => {
+                    self.errors
+                        .push(E::unreadable_file(&shown, e.to_string(), Some(import)));
+                    continue;
+                }
+            };
+            if let Option::Some(start) = self.stack.iter().position(|p| p == &target) {
+                let mut cycle = self.stack[start..].to_vec();
+                cycle.push(target);
+                self.errors.push(E::import_cycle(import, &cycle));
+                continue;
+            }
+            if self.loaded.contains(&target) {
+                continue;
+            }
+            self.load(target, shown, Some(import));
+        }
+        if nested {
+            self.stack.pop();
+        }
+
+        let id = body.get_file_id();
+        self.files.push(SourceFile { id, name, body });
+    }
+}
+
+/// an empty directory (with a `lib` directory) for a test to write files within
+#[cfg(test)]
+pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("foxhole-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("lib")).unwrap();
+    dir
+}
+
+#[test]
+fn imports_are_loaded_once_in_dependency_order() {
+    use crate::internals::{errors::term_errors::HumanReadable, parser::span::Spanner};
+
+    let dir = scratch_dir("imports");
+    std::fs::write(dir.join("lib/dice.fx"), "fn roll(x: int) int { x }").unwrap();
+    std::fs::write(dir.join("lib/rules.fx"), "import \"dice.fx\";\ncm total(0, x, +) int;").unwrap();
+    std::fs::write(
+        dir.join("main.fx"),
+        "import \"lib/rules.fx\";\nimport \"lib/dice.fx\";\nroll(d6)",
+    )
+    .unwrap();
+
+    let program = load_program::<HumanReadable>(&dir.join("main.fx")).ok().unwrap();
+    let names = program.files.iter()
+        .map(|f| f.name.to_string())
+        .collect::<Vec<_>>();
+    let shown = |path: &str| dir.join(path).display().to_string();
+    assert_eq!(names, vec![shown("lib/dice.fx"), shown("lib/rules.fx"), shown("main.fx")]);
+
+    // spans know which file they came from
+    for file in program.files.iter() {
+        assert_eq!(file.body.get_file_id(), file.id);
+        assert_eq!(file.body.body[0].get_file_id(), file.id);
+    }
+    assert_eq!(program.get_file(program.root().id).unwrap().name, program.root().name);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn import_cycles_are_reported() {
+    use crate::internals::errors::term_errors::HumanReadable;
+
+    let dir = scratch_dir("cycles");
+    std::fs::write(dir.join("a.fx"), "import \"lib/b.fx\";\n1").unwrap();
+    std::fs::write(dir.join("lib/b.fx"), "import \"../a.fx\";\nfn f() int { 1 }").unwrap();
+
+    let errors = load_program::<HumanReadable>(&dir.join("a.fx")).err().unwrap();
+    assert_eq!(errors.len(), 1);
+    let message = format!("{}", errors[0]);
+    assert!(message.contains("import cycle"), "{}", message);
+    // diagnostics name the file the import is within
+    let location = format!("{}:1:1", dir.join("lib/b.fx").display());
+    assert!(message.contains(&location), "{}", message);
+
+    std::fs::write(dir.join("a.fx"), "import \"missing.fx\";\n1").unwrap();
+    let errors = load_program::<HumanReadable>(&dir.join("a.fx")).err().unwrap();
+    assert!(format!("{}", errors[0]).contains("missing.fx"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -1,7 +1,11 @@
 pub mod ast;
 
+pub mod source_map;
+
 pub mod span;
 
 pub mod generated;
 
+pub mod loader;
+
 pub mod traits;
@@ -0,0 +1,182 @@
+//! SourceMap
+//!
+//! Every source text which is parsed is registered here once, and
+//! given a file id. Spans only record a file id & byte range, their
+//! text, lines, and columns are looked up from the map when needed.
+//!
+//! Registered sources live for the remainder of the process, this
+//! lets spans hand out `&str`s without holding a lock. Registering the
+//! same text under the same name again reuses its file id, so only
+//! distinct sources add to the memory a long running process holds.
+
+use std::{fmt, path::PathBuf, sync::RwLock};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+lazy_static! {
+    /// The source map shared by every span
+    pub static ref SOURCE_MAP: SourceMap = SourceMap::default();
+}
+
+/// SourceMap owns the text of every parsed source
+#[derive(Default)]
+pub struct SourceMap {
+    sources: RwLock<Vec<&'static Source>>,
+}
+
+impl SourceMap {
+    /// registers a source, returning its file id.
+    ///
+    /// a source is never freed, if `text` was already registered as
+    /// `name` its file id is returned instead of leaking a copy.
+    pub fn add(&self, name: SourceName, text: &str) -> u32 {
+        let mut sources = self.sources.write().unwrap();
+        if let Option::Some(file) = sources.iter().position(|s| s.name == name && s.text == text) {
+            return file as u32;
+        }
+        let source: &'static Source = Box::leak(Box::new(Source::new(name, text)));
+        sources.push(source);
+        (sources.len() - 1) as u32
+    }
+
+    /// the source for a file id.
+    ///
+    /// panics if `file` was never registered, spans are only ever
+    /// created while parsing a registered source, or deserialized
+    /// after checking `try_get`.
+    pub fn get(&self, file: u32) -> &'static Source {
+        match self.try_get(file) {
+            Option::Some(source) => source,
+            Option::None => panic!("file id {} was never registered", file),
+        }
+    }
+
+    /// the source for a file id, if it was registered
+    pub fn try_get(&self, file: u32) -> Option<&'static Source> {
+        self.sources.read().unwrap().get(file as usize).cloned()
+    }
+
+    /// the file id of the source most recently registered as `name`
+    pub fn find(&self, name: &SourceName) -> Option<u32> {
+        let sources = self.sources.read().unwrap();
+        sources.iter().rposition(|s| &s.name == name).map(|file| file as u32)
+    }
+}
+
+/// SourceName is where a source's text came from
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SourceName {
+    /// a file on disk
+    Path(PathBuf),
+    /// standard input
+    Stdin,
+    /// a string built within the program, named for diagnostics
+    Memory(String),
+}
+
+impl fmt::Display for SourceName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceName::Path(path) => write!(f, "{}", path.display()),
+            SourceName::Stdin => write!(f, "<stdin>"),
+            SourceName::Memory(name) => write!(f, "<{}>", name),
+        }
+    }
+}
+
+/// Source is the text of a single registered source
+pub struct Source {
+    name: SourceName,
+    text: &'static str,
+    // byte index each line starts at
+    line_starts: Vec<usize>,
+}
+
+impl Source {
+    fn new(name: SourceName, text: &str) -> Self {
+        let text: &'static str = Box::leak(text.to_owned().into_boxed_str());
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            name,
+            text,
+            line_starts,
+        }
+    }
+
+    /// where this source came from
+    pub fn get_name(&self) -> &SourceName {
+        &self.name
+    }
+
+    /// the full text of the source
+    pub fn get_text(&self) -> &'static str {
+        self.text
+    }
+
+    // index (0 based) of the line containing `offset`
+    fn line_index(&self, offset: usize) -> usize {
+        match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        }
+    }
+
+    /// the 1 based line & column of a byte offset, columns count chars.
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_index(offset);
+        let start = self.line_starts[line];
+        (line + 1, self.text[start..offset].chars().count() + 1)
+    }
+
+    /// the byte index of the start of the line containing `offset`
+    pub fn line_start(&self, offset: usize) -> usize {
+        self.line_starts[self.line_index(offset)]
+    }
+
+    /// the byte index of the end of the line containing `offset`,
+    /// excluding the newline (`\n` or `\r\n`)
+    pub fn line_end(&self, offset: usize) -> usize {
+        let end = match self.line_starts.get(self.line_index(offset) + 1) {
+            Option::Some(next) => next - 1,
+            Option::None => self.text.len(),
+        };
+        if end > offset && self.text[..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        }
+    }
+}
+
+#[test]
+fn line_and_column_lookup() {
+    let file = SOURCE_MAP.add(SourceName::Memory("test".to_string()), "ab\ncdé\n\nf");
+    let source = SOURCE_MAP.get(file);
+    assert_eq!(source.get_name().to_string(), "<test>");
+    assert_eq!(source.line_col(0), (1, 1));
+    assert_eq!(source.line_col(2), (1, 3));
+    assert_eq!(source.line_col(3), (2, 1));
+    assert_eq!(source.line_col(7), (2, 4));
+    assert_eq!(source.line_col(9), (4, 1));
+    assert_eq!(&source.get_text()[source.line_start(4)..source.line_end(4)], "cdé");
+    assert_eq!(source.line_end(8), 8);
+    assert_eq!(source.line_end(9), 10);
+
+    let crlf = SOURCE_MAP.get(SOURCE_MAP.add(SourceName::Memory("crlf".to_string()), "ab\r\ncd\r\n"));
+    assert_eq!(&crlf.get_text()[crlf.line_start(1)..crlf.line_end(1)], "ab");
+    assert_eq!(&crlf.get_text()[crlf.line_start(4)..crlf.line_end(4)], "cd");
+    assert_eq!(crlf.line_col(4), (2, 1));
+
+    // registering the same source again doesn't leak another copy
+    assert_eq!(SOURCE_MAP.add(SourceName::Memory("test".to_string()), "ab\ncdé\n\nf"), file);
+    assert_ne!(SOURCE_MAP.add(SourceName::Memory("other".to_string()), "ab\ncdé\n\nf"), file);
+
+    assert_eq!(SOURCE_MAP.find(&SourceName::Memory("test".to_string())), Some(file));
+    assert_eq!(SOURCE_MAP.find(&SourceName::Memory("never".to_string())), None);
+
+    assert!(SOURCE_MAP.try_get(file).is_some());
+    assert!(SOURCE_MAP.try_get(u32::MAX).is_none());
+}
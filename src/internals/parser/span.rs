@@ -1,4 +1,4 @@
-use std::{borrow::ToOwned, hash::Hash, str::from_utf8_unchecked};
+use std::{cell::Cell, hash::Hash};
 
 use serde::{Deserialize, Serialize};
 
@@ -8,29 +8,92 @@ use lrpar::{Lexeme, Lexer, NonStreamingLexer};
 use num_traits::{PrimInt, Unsigned};
 use try_from::TryFrom;
 
-/// Span contains information about where some text lies within the pre-parse structure
+use crate::internals::parser::source_map::{Source, SourceName, SOURCE_MAP};
+
+/// Span is a range of bytes within a source registered in the `SourceMap`.
+///
+/// The text, line, and column information are looked up from the
+/// source map on demand (see `Spanner`), so a span is only a few words.
+///
+/// A file id only means something within the process which parsed the
+/// source, so a span serializes its source's name instead. Deserializing
+/// finds the source most recently registered under that name, reading
+/// (& registering) a `SourceName::Path` which wasn't parsed yet. Sources
+/// from stdin or memory can't be read again, their spans only deserialize
+/// in the process which parsed them.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(into = "SerializedSpan", try_from = "SerializedSpan")]
 pub struct Span {
-    // the source code line the span starts on
-    start_line: u32,
-    // the source code line the span ends on
-    end_line: u32,
-    // the column the token starts on
-    start_column: u32,
-    // the column the token ends on
-    end_column: u32,
+    // which source file this span lies within
+    file: u32,
     // the byte index of the source this token starts on
-    start_byte: u32,
+    start: u32,
     // the byte index of the source this token ends on
-    end_byte: u32,
-    // how many bytes of 'surrounding_lines' precedes 'token'
-    prefix_length: u32,
-    // how many bytes of 'surrounding_lines' follows 'token'
-    suffix_length: u32,
-    // the parsed text itself
-    token: String,
-    // the line(s) (if it spans multiple lines) that contain this value.
-    surrounding_lines: String,
+    end: u32,
+}
+
+/// A span as it is serialized, naming its source rather than a file id.
+#[derive(Serialize, Deserialize)]
+struct SerializedSpan {
+    source: SourceName,
+    start: u32,
+    end: u32,
+}
+
+impl From<Span> for SerializedSpan {
+    fn from(span: Span) -> Self {
+        SerializedSpan {
+            source: SOURCE_MAP.get(span.file).get_name().clone(),
+            start: span.start,
+            end: span.end,
+        }
+    }
+}
+
+impl std::convert::TryFrom<SerializedSpan> for Span {
+    type Error = String;
+
+    fn try_from(span: SerializedSpan) -> Result<Self, Self::Error> {
+        let file = match (SOURCE_MAP.find(&span.source), &span.source) {
+            (Option::Some(file), _) => file,
+            (Option::None, SourceName::Path(path)) => match std::fs::read_to_string(path) {
+                Ok(text) => SOURCE_MAP.add(span.source.clone(), &text),
+                Err(e) => return Err(format!("span refers to {}, which can't be read: {}", span.source, e)),
+            },
+            (Option::None, _) => return Err(format!("span refers to {}, which was never parsed", span.source)),
+        };
+        let source = SOURCE_MAP.get(file);
+        match source.get_text().get(span.start as usize..span.end as usize) {
+            Option::Some(_) => Ok(Span {
+                file,
+                start: span.start,
+                end: span.end,
+            }),
+            Option::None => Err(format!(
+                "span {}..{} does not lie within {}",
+                span.start,
+                span.end,
+                source.get_name()
+            )),
+        }
+    }
+}
+
+thread_local! {
+    // the file id given to every span built while parsing
+    static CURRENT_FILE: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Runs `f` (which parses a source file), all spans created
+/// within `f` will report they're within file `file`.
+pub(in crate::internals::parser) fn with_file<T, F>(file: u32, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let previous = CURRENT_FILE.with(|c| c.replace(file));
+    let output = f();
+    CURRENT_FILE.with(|c| c.set(previous));
+    output
 }
 
 impl Span {
@@ -38,7 +101,7 @@ impl Span {
     ///
     /// Ensure `s` and `span` are never both `None`. As this will trigger a panic.
     pub(in crate::internals::parser) fn new<'a, 'input: 'a, U, G, S, L>(
-        l: &'a L,
+        _l: &'a L,
         s: G,
         span: S,
     ) -> Result<Span, lrpar::Lexeme<U>>
@@ -59,40 +122,10 @@ impl Span {
                 panic!("Span::new() invoked without a Lexeme or Span");
             }
         };
-        let token_borrowed = l.span_str(span.clone());
-        let token_borrowed_start_address: usize = token_borrowed.as_ptr() as usize;
-        let token_borrowed_end_address: usize = token_borrowed_start_address + token_borrowed.len();
-
-        let surrounding_lines_borrowed = l.span_lines_str(span.clone());
-        let surrounding_lines_borrowed_start_address: usize =
-            surrounding_lines_borrowed.as_ptr() as usize;
-        let surrounding_lines_borrowed_end_address: usize =
-            surrounding_lines_borrowed_start_address + surrounding_lines_borrowed.len();
-
-        let prefix_length = token_borrowed_start_address - surrounding_lines_borrowed_start_address;
-        let suffix_length = surrounding_lines_borrowed_end_address - token_borrowed_end_address;
-
-        debug_assert!(
-            (prefix_length + suffix_length + token_borrowed.len())
-                == surrounding_lines_borrowed.len()
-        );
-
-        let token = token_borrowed.to_owned();
-        let surrounding_lines = surrounding_lines_borrowed.to_owned();
-        let ((start_line, start_column), (end_line, end_column)) = l.line_col(span.clone());
-        let start_byte = span.start();
-        let end_byte = span.end();
         Ok(Span {
-            start_line: start_line as u32,
-            end_line: end_line as u32,
-            start_column: start_column as u32,
-            end_column: end_column as u32,
-            start_byte: start_byte as u32,
-            end_byte: end_byte as u32,
-            prefix_length: prefix_length as u32,
-            suffix_length: suffix_length as u32,
-            token,
-            surrounding_lines,
+            file: CURRENT_FILE.with(|c| c.get()),
+            start: span.start() as u32,
+            end: span.end() as u32,
         })
     }
 
@@ -159,7 +192,7 @@ where
     U: TryFrom<usize> + Eq + Copy + Unsigned + PrimInt + Hash + 'static,
 {
     fn from(arg: &'a lrpar::Lexeme<U>) -> Self {
-        Self::Lexeme(arg.clone())
+        Self::Lexeme(*arg)
     }
 }
 impl<'a, U> From<&'a lrpar::Span> for SpanBuilder<U>
@@ -167,7 +200,7 @@ where
     U: TryFrom<usize> + Eq + Copy + Unsigned + PrimInt + Hash + 'static,
 {
     fn from(arg: &'a lrpar::Span) -> Self {
-        Self::Span(arg.clone())
+        Self::Span(*arg)
     }
 }
 impl<U> From<lrpar::Span> for SpanBuilder<U>
@@ -189,24 +222,34 @@ pub trait Spanner: AsRef<Span> {
         self.as_ref().clone()
     }
 
+    /// returns the id of the source file this span lies within.
+    fn get_file_id(&self) -> u32 {
+        self.as_ref().file
+    }
+
+    /// returns the source this span lies within.
+    fn get_source(&self) -> &'static Source {
+        SOURCE_MAP.get(self.get_file_id())
+    }
+
     /// returns the byte index of the first byte of this span within the source file.
     fn get_start_byte_index(&self) -> usize {
-        self.as_ref().start_byte as usize
+        self.as_ref().start as usize
     }
 
     /// returns the byte index of the last byte of this span within the source file.
     fn get_end_byte_index(&self) -> usize {
-        self.as_ref().end_byte as usize
+        self.as_ref().end as usize
     }
 
     /// returns the line number that this span starts on.
     fn get_start_line(&self) -> usize {
-        self.as_ref().start_line as usize
+        self.get_source().line_col(self.get_start_byte_index()).0
     }
 
     /// returns the line number that this span ends on.
     fn get_end_line(&self) -> usize {
-        self.as_ref().end_line as usize
+        self.get_source().line_col(self.get_end_byte_index()).0
     }
 
     /// Is this span isolated to one line
@@ -216,46 +259,107 @@ pub trait Spanner: AsRef<Span> {
 
     /// returns the column number (within the line) that this span starts on.
     fn get_start_column(&self) -> usize {
-        self.as_ref().start_column as usize
+        self.get_source().line_col(self.get_start_byte_index()).1
     }
 
     /// returns the column number (within the line) that this span ends on.
     fn get_end_column(&self) -> usize {
-        self.as_ref().end_column as usize
+        self.get_source().line_col(self.get_end_byte_index()).1
     }
 
     /// returns the underlying `str` representation of the input.
-    fn get_span<'a>(&'a self) -> &'a str {
-        &self.as_ref().token
+    fn get_span(&self) -> &str {
+        &self.get_source().get_text()[self.get_start_byte_index()..self.get_end_byte_index()]
     }
 
     /// returns how many bytes of `get_surrounding_lines` are a prefix to `token`
     fn get_prefix_length(&self) -> usize {
-        self.as_ref().prefix_length as usize
+        let start = self.get_start_byte_index();
+        start - self.get_source().line_start(start)
     }
 
     /// returns how many bytes of `get_suffix_length` are a suffix to `token`
     fn get_suffix_length(&self) -> usize {
-        self.as_ref().suffix_length as usize
+        let end = self.get_end_byte_index();
+        self.get_source().line_end(end) - end
     }
 
     /// returns the 3 parts of the span
-    fn get_prefix_token_suffix<'a>(&'a self) -> (&'a str, &'a str, &'a str) {
-        let chunk = self.get_surrounding_lines().as_bytes();
-        // this is unsafe, but this information was given by the parser
-        // so we'll implicitly trust it.
-        let token = self.get_span();
-        unsafe {
-            let prefix = from_utf8_unchecked(&chunk[0..self.get_prefix_length()]);
-            let skip = prefix.len() + token.len();
-            let suffix = from_utf8_unchecked(&chunk[skip..]);
-            (prefix, token, suffix)
-        }
+    fn get_prefix_token_suffix(&self) -> (&str, &str, &str) {
+        let text = self.get_source().get_text();
+        let start = self.get_start_byte_index();
+        let end = self.get_end_byte_index();
+        let prefix = &text[self.get_source().line_start(start)..start];
+        let suffix = &text[end..self.get_source().line_end(end)];
+        (prefix, &text[start..end], suffix)
     }
 
     /// returns the raw line(s) (multiple if "this span" crosses multiple lines) which "this span"
     /// is contained within.
-    fn get_surrounding_lines<'a>(&'a self) -> &'a str {
-        &self.as_ref().surrounding_lines
+    fn get_surrounding_lines(&self) -> &str {
+        let source = self.get_source();
+        let start = source.line_start(self.get_start_byte_index());
+        let end = source.line_end(self.get_end_byte_index());
+        &source.get_text()[start..end]
     }
 }
+
+#[test]
+fn spans_are_compact_and_resolve_lazily() {
+    use crate::internals::{
+        errors::term_errors::HumanReadable,
+        parser::{ast::statement::StateTrait, generated::parse_code},
+    };
+
+    assert_eq!(std::mem::size_of::<Span>(), 12);
+
+    let body = parse_code::<HumanReadable>("let x = 1;\nlet long = d6 +\n  2;\nx").unwrap();
+    let long = body.body[1].get_dec().unwrap();
+    assert_eq!(long.get_span(), "let long = d6 +\n  2");
+    assert_eq!((long.get_start_line(), long.get_start_column()), (2, 1));
+    assert_eq!((long.get_end_line(), long.get_end_column()), (3, 4));
+    assert_eq!(long.get_surrounding_lines(), "let long = d6 +\n  2;");
+    assert_eq!(long.expr.get_prefix_token_suffix(), ("let long = ", "d6 +\n  2", ";"));
+}
+
+#[test]
+fn deserialized_spans_are_checked() {
+    let name = SourceName::Memory("spans".to_string());
+    let file = SOURCE_MAP.add(name.clone(), "let x = 1;\r\nx");
+    let span = |name: &SourceName, start: u32, end: u32| {
+        let source = serde_json::to_string(name).unwrap();
+        serde_json::from_str::<Span>(&format!(r#"{{"source":{},"start":{},"end":{}}}"#, source, start, end))
+    };
+
+    let x = span(&name, 4, 5).unwrap();
+    assert_eq!(x.get_span(), "x");
+    // the line excludes a `\r\n` line ending
+    assert_eq!(x.get_prefix_token_suffix(), ("let ", "x", " = 1;"));
+    assert_eq!(x.get_surrounding_lines(), "let x = 1;");
+    // spans round trip through the source's name, not its file id
+    let json = serde_json::to_string(&x).unwrap();
+    assert!(json.contains(r#""source":{"Memory":"spans"}"#), "{}", json);
+    assert_eq!(serde_json::from_str::<Span>(&json).unwrap(), x);
+    assert_eq!(x.file, file);
+
+    assert!(span(&SourceName::Memory("never parsed".to_string()), 0, 1).is_err());
+    assert!(span(&name, 4, 100).is_err());
+    assert!(span(&name, 5, 4).is_err());
+}
+
+#[test]
+fn deserialized_spans_read_unparsed_files() {
+    let path = crate::internals::parser::loader::scratch_dir("spans").join("unparsed.fx");
+    std::fs::write(&path, "let y = 2;\ny").unwrap();
+    let name = SourceName::Path(path.clone());
+    assert_eq!(SOURCE_MAP.find(&name), None);
+
+    let json = format!(r#"{{"source":{},"start":4,"end":5}}"#, serde_json::to_string(&name).unwrap());
+    let y = serde_json::from_str::<Span>(&json).unwrap();
+    assert_eq!(y.get_span(), "y");
+    assert_eq!(y.get_start_line(), 1);
+
+    let missing = SourceName::Path(path.with_file_name("missing.fx"));
+    let json = format!(r#"{{"source":{},"start":0,"end":1}}"#, serde_json::to_string(&missing).unwrap());
+    assert!(serde_json::from_str::<Span>(&json).is_err());
+}
@@ -1,10 +1,11 @@
 use std::fmt::Display;
 use std::hash::Hash;
+use std::path::{Path, PathBuf};
 
 use lrpar::{Lexer, NonStreamingLexer};
 use num_traits::{PrimInt, Unsigned};
 
-use crate::internals::parser::span::Span;
+use crate::internals::parser::{ast::import::Import, span::Span};
 
 /// SyntaxError is a general interface which can used to iterate upon how
 /// syntax errors are handled. The idea is if we need to change/adapt error
@@ -18,8 +19,31 @@ pub trait SyntaxError: Sized + Display {
         L: NonStreamingLexer<'a, U> + Lexer<U> + ?Sized;
 
     /// Parse error occured -within- a parser
-    fn parse_error<'a, U, L>(lexer: &L, source: &'a str, span: &Span) -> Self
+    ///
+    /// `repairs` are the human readable repair sequences the error recovery
+    /// found for this error (e.g. ``insert `;` ``), they may be empty.
+    fn parse_error<'a, U, L>(lexer: &L, source: &'a str, span: &Span, repairs: &[String]) -> Self
     where
         U: Unsigned + PrimInt + Hash,
         L: NonStreamingLexer<'a, U> + Lexer<U> + ?Sized;
+
+    /// A dice literal (such as `d6`) was used where a name is declared,
+    /// dice literals are never names.
+    fn dice_as_name<'a, U, L>(lexer: &L, source: &'a str, span: &Span) -> Self
+    where
+        U: Unsigned + PrimInt + Hash,
+        L: NonStreamingLexer<'a, U> + Lexer<U> + ?Sized;
+}
+
+/// ImportError covers failures while loading the files of a multi-file program.
+pub trait ImportError: Sized {
+    /// A source file could not be read.
+    ///
+    /// `import` is the statement which named the file, `None` for the
+    /// root file of the program.
+    fn unreadable_file(path: &Path, reason: String, import: Option<&Import>) -> Self;
+
+    /// `import` closes a cycle, `cycle` lists the files involved starting
+    /// (and ending) with the file which is imported again.
+    fn import_cycle(import: &Import, cycle: &[PathBuf]) -> Self;
 }